@@ -0,0 +1,114 @@
+//! Calling convention details for block return values.
+//!
+//! Rust handles returning structures through a hidden pointer argument
+//! automatically, but the runtime and the compiler additionally record this
+//! in the `BLOCK_USE_STRET` flag; so we need to know when it applies, in the
+//! same way that `objc2` needs to know when to use `objc_msgSend_stret`.
+use core::mem;
+
+use objc2::encode::__unstable::EncodeReturn;
+use objc2::encode::Encoding;
+
+/// Whether the type is an aggregate (as opposed to a scalar) in C.
+const fn is_aggregate(encoding: &Encoding) -> bool {
+    matches!(
+        encoding,
+        Encoding::Struct(_, _) | Encoding::Union(_, _) | Encoding::Array(_, _)
+    )
+}
+
+/// Whether a value with the given encoding and size is returned through a
+/// pointer passed in by the caller.
+///
+/// See the `MsgSendFn` impls in `objc2` for details and references. Like
+/// `objc_msgSend_stret`, this never applies on AArch64, since Clang only sets
+/// the flag when the return slot would interfere with the arguments.
+const fn use_stret(encoding: &Encoding, size: usize) -> bool {
+    if !is_aggregate(encoding) || size == 0 {
+        return false;
+    }
+
+    if cfg!(target_arch = "x86_64") {
+        if cfg!(windows) {
+            !matches!(size, 1 | 2 | 4 | 8)
+        } else {
+            size > 16
+        }
+    } else if cfg!(target_arch = "x86") {
+        if cfg!(any(target_vendor = "apple", windows)) {
+            !matches!(size, 1 | 2 | 4 | 8)
+        } else {
+            // The System V i386 ABI returns all aggregates in memory.
+            true
+        }
+    } else if cfg!(target_arch = "arm") {
+        size > 4
+    } else if cfg!(target_arch = "aarch64") {
+        false
+    } else {
+        size > 2 * mem::size_of::<usize>()
+    }
+}
+
+/// Extra information about block return types.
+pub(crate) trait BlockReturn: EncodeReturn {
+    /// Whether blocks returning this type have `BLOCK_USE_STRET` set.
+    const USE_STRET: bool;
+}
+
+impl<R: EncodeReturn> BlockReturn for R {
+    const USE_STRET: bool = use_stret(&R::ENCODING_RETURN, mem::size_of::<R>());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[repr(C)]
+    struct Large {
+        _x: [u8; 100],
+    }
+
+    unsafe impl objc2::Encode for Large {
+        const ENCODING: Encoding = Encoding::Struct("Large", &[<[u8; 100]>::ENCODING]);
+    }
+
+    #[repr(C)]
+    struct Small {
+        _x: u8,
+    }
+
+    unsafe impl objc2::Encode for Small {
+        const ENCODING: Encoding = Encoding::Struct("Small", &[u8::ENCODING]);
+    }
+
+    #[repr(C)]
+    struct Rect {
+        _origin: [f64; 2],
+        _size: [f64; 2],
+    }
+
+    unsafe impl objc2::Encode for Rect {
+        const ENCODING: Encoding =
+            Encoding::Struct("Rect", &[<[f64; 2]>::ENCODING, <[f64; 2]>::ENCODING]);
+    }
+
+    #[test]
+    #[allow(clippy::assertions_on_constants)]
+    fn test_use_stret() {
+        assert!(!<()>::USE_STRET);
+        assert!(!i32::USE_STRET);
+        assert!(!u64::USE_STRET);
+        assert!(!f64::USE_STRET);
+        assert!(!<*const u8>::USE_STRET);
+        assert_eq!(Large::USE_STRET, !cfg!(target_arch = "aarch64"));
+
+        let small_stret = cfg!(all(
+            target_arch = "x86",
+            not(any(target_vendor = "apple", windows))
+        ));
+        assert_eq!(Small::USE_STRET, small_stret);
+
+        assert_eq!(Rect::USE_STRET, !cfg!(target_arch = "aarch64"));
+    }
+}
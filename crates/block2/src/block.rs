@@ -1,14 +1,17 @@
 use core::marker::PhantomData;
 use core::mem;
 
-use objc2::encode::__unstable::EncodeReturn;
+use objc2::encode::__unstable::{EncodeArguments, EncodeReturn};
 use objc2::encode::{Encode, Encoding, RefEncode};
+#[cfg(not(feature = "compiler-rt"))]
+use objc2::Message;
 
+use crate::abi::BlockReturn;
 use crate::ffi;
 
 /// Types that may be used as the arguments of an Objective-C block.
 ///
-/// This is implemented for tuples of up to 12 arguments, where each argument
+/// This is implemented for tuples of up to 16 arguments, where each argument
 /// implements [`Encode`].
 ///
 ///
@@ -16,7 +19,7 @@ use crate::ffi;
 ///
 /// This is a sealed trait, and should not need to be implemented. Open an
 /// issue if you know a use-case where this restrition should be lifted!
-pub unsafe trait BlockArguments: Sized {
+pub unsafe trait BlockArguments: EncodeArguments + Sized {
     /// Calls the given method the block and arguments.
     #[doc(hidden)]
     unsafe fn __call_block<R: EncodeReturn>(
@@ -46,44 +49,7 @@ macro_rules! block_args_impl {
     );
 }
 
-block_args_impl!();
-block_args_impl!(a: A);
-block_args_impl!(a: A, b: B);
-block_args_impl!(a: A, b: B, c: C);
-block_args_impl!(a: A, b: B, c: C, d: D);
-block_args_impl!(a: A, b: B, c: C, d: D, e: E);
-block_args_impl!(a: A, b: B, c: C, d: D, e: E, f: F);
-block_args_impl!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
-block_args_impl!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
-block_args_impl!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I);
-block_args_impl!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J);
-block_args_impl!(
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H,
-    i: I,
-    j: J,
-    k: K
-);
-block_args_impl!(
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H,
-    i: I,
-    j: J,
-    k: K,
-    l: L
-);
+tuple_impls!(block_args_impl);
 
 /// An Objective-C block that takes arguments of `A` when called and
 /// returns a value of `R`.
@@ -102,6 +68,13 @@ unsafe impl<A: BlockArguments, R: EncodeReturn> RefEncode for Block<A, R> {
     const ENCODING_REF: Encoding = Encoding::Block;
 }
 
+// SAFETY: Blocks are Objective-C objects (instances of `NSBlock`), and
+// respond to the memory management messages. `retain` does not copy stack
+// blocks to the heap, but an `Id` can only be created from an existing `+1`
+// reference, which means the block is already on the heap (or is global).
+#[cfg(not(feature = "compiler-rt"))]
+unsafe impl<A: BlockArguments, R: EncodeReturn> Message for Block<A, R> {}
+
 impl<A: BlockArguments, R: EncodeReturn> Block<A, R> {
     /// Call self with the given arguments.
     ///
@@ -118,6 +91,18 @@ impl<A: BlockArguments, R: EncodeReturn> Block<A, R> {
         // TODO: Is `invoke` actually ever null?
         let invoke = layout.invoke.unwrap();
 
+        // The flag is only meaningful if the block has a signature.
+        if cfg!(debug_assertions) && layout.flags & ffi::BLOCK_HAS_SIGNATURE != 0 {
+            let use_stret = layout.flags & ffi::BLOCK_USE_STRET != 0;
+            if use_stret != R::USE_STRET {
+                panic!(
+                    "invalid block return type {:?}, the block {} a struct return",
+                    R::ENCODING_RETURN,
+                    if use_stret { "uses" } else { "does not use" },
+                );
+            }
+        }
+
         unsafe { A::__call_block(invoke, ptr as *mut Self, args) }
     }
 }
@@ -0,0 +1,265 @@
+use core::cell::UnsafeCell;
+use core::ffi::c_void;
+use core::marker::PhantomData;
+use core::mem::{self, ManuallyDrop};
+use core::ptr::{self, NonNull};
+
+use crate::ffi;
+
+/// The in-memory layout of a `__block` variable holding a `T`.
+///
+/// This is what Clang generates for a variable declared `__block T var;`,
+/// and what the runtime expects `BLOCK_FIELD_IS_BYREF` fields to point to.
+#[repr(C)]
+struct ByRefLayout<T> {
+    header: ffi::Block_byref,
+    value: UnsafeCell<T>,
+}
+
+impl<T> ByRefLayout<T> {
+    // We always provide copy and dispose helpers, since the layout of the
+    // structure changes depending on whether they are present or not.
+    //
+    // `BLOCK_BYREF_HAS_COPY_DISPOSE` has the same value as
+    // `BLOCK_HAS_COPY_DISPOSE`.
+    const FLAGS: ffi::block_flags = ffi::BLOCK_HAS_COPY_DISPOSE;
+}
+
+/// Move the value from the stack structure to the heap structure.
+///
+/// The runtime has already initialized the header of the destination.
+unsafe extern "C" fn byref_keep<T>(dst: *mut c_void, src: *mut c_void) {
+    let dst = dst.cast::<ByRefLayout<T>>();
+    let src = src.cast::<ByRefLayout<T>>();
+    // SAFETY: The runtime only calls this once, when moving the structure we
+    // created in `ByRef::new` to the heap, and we never touch the value in
+    // the source afterwards.
+    unsafe { ptr::copy_nonoverlapping((*src).value.get(), (*dst).value.get(), 1) };
+}
+
+/// Drop the value when the last reference to the heap structure goes away.
+unsafe extern "C" fn byref_destroy<T>(byref: *mut c_void) {
+    let byref = byref.cast::<ByRefLayout<T>>();
+    // SAFETY: The runtime only calls this on the heap structure, which
+    // contains a valid value that was moved there by `byref_keep`.
+    unsafe { ptr::drop_in_place((*byref).value.get()) };
+}
+
+/// A shared, mutable variable that can be captured by multiple blocks.
+///
+/// This is the equivalent of a variable declared with the `__block` storage
+/// qualifier in C. The variable lives on the heap in the structure that the
+/// blocks runtime expects, and is reference-counted using
+/// `_Block_object_assign` and `_Block_object_dispose`; so a `ByRef` can also
+/// be shared with blocks created by Objective-C code, and updates made from
+/// either side will be visible to the other.
+///
+/// All accesses go through the structure's `forwarding` pointer, as
+/// required by the [ABI specification][ABI].
+///
+/// Since the variable is shared, the API is similar to that of
+/// [`Cell`][core::cell::Cell]; no references to the inner value are handed
+/// out.
+///
+/// [ABI]: https://clang.llvm.org/docs/Block-ABI-Apple.html#importing-block-variables-into-blocks
+///
+///
+/// # Examples
+///
+/// Share a counter between two blocks.
+///
+/// ```
+/// use block2::{ByRef, ConcreteBlock};
+///
+/// let counter = ByRef::new(0);
+///
+/// let increment = ConcreteBlock::new({
+///     let counter = counter.clone();
+///     move || counter.set(counter.get() + 1)
+/// })
+/// .copy();
+///
+/// let read = ConcreteBlock::new({
+///     let counter = counter.clone();
+///     move || counter.get()
+/// })
+/// .copy();
+///
+/// unsafe { increment.call(()) };
+/// unsafe { increment.call(()) };
+/// assert_eq!(unsafe { read.call(()) }, 2);
+/// assert_eq!(counter.get(), 2);
+/// ```
+pub struct ByRef<T> {
+    /// Pointer to the `__block` structure on the heap, which we own a
+    /// reference to.
+    ptr: NonNull<ffi::Block_byref_header>,
+    /// The variable may be shared with blocks that are called from
+    /// Objective-C, so we're neither `Send` nor `Sync`.
+    p: PhantomData<*mut ByRefLayout<T>>,
+}
+
+impl<T> ByRef<T> {
+    /// Create a new shared variable with the given initial value.
+    pub fn new(value: T) -> Self {
+        // Construct the structure the same way the compiler would for a
+        // variable on the stack, and then let the runtime move it to the
+        // heap.
+        let mut stack = ManuallyDrop::new(ByRefLayout {
+            header: ffi::Block_byref {
+                header: ffi::Block_byref_header {
+                    isa: ptr::null(),
+                    forwarding: ptr::null_mut(),
+                    flags: ByRefLayout::<T>::FLAGS,
+                    size: mem::size_of::<ByRefLayout<T>>() as _,
+                },
+                keep: Some(byref_keep::<T>),
+                destroy: Some(byref_destroy::<T>),
+            },
+            value: UnsafeCell::new(value),
+        });
+        let stack: *mut ByRefLayout<T> = &mut *stack;
+        let stack: *mut ffi::Block_byref_header = stack.cast();
+        // SAFETY: The structure is not moved after this point.
+        unsafe { (*stack).forwarding = stack };
+
+        let mut heap: *mut ffi::Block_byref_header = ptr::null_mut();
+        let heap_ptr: *mut *mut ffi::Block_byref_header = &mut heap;
+        // SAFETY: The structure is valid, and has a zero reference count, so
+        // the runtime copies it to the heap (moving the value with
+        // `byref_keep`), and updates the `forwarding` pointer.
+        unsafe {
+            ffi::_Block_object_assign(heap_ptr.cast(), stack.cast(), ffi::BLOCK_FIELD_IS_BYREF)
+        };
+        // Release the reference that the stack structure holds to the heap
+        // structure, just like the compiler does when a `__block` variable
+        // goes out of scope.
+        //
+        // SAFETY: The stack structure is valid, and forwards to the heap.
+        unsafe { ffi::_Block_object_dispose(stack.cast(), ffi::BLOCK_FIELD_IS_BYREF) };
+
+        Self {
+            ptr: NonNull::new(heap).expect("failed copying __block variable to the heap"),
+            p: PhantomData,
+        }
+    }
+
+    /// Retain a `__block` variable that was created elsewhere, for example
+    /// one captured by a block from Objective-C.
+    ///
+    /// If the variable is still on the stack, it is moved to the heap.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must point to a valid `__block` structure, created either
+    /// by [`ByRef::new`] or by the compiler, and the variable stored in it
+    /// must be of type `T`.
+    ///
+    /// The structure must have copy and dispose helpers (which is the case
+    /// for all variables with non-trivial types, like Objective-C objects),
+    /// and must not use the extended layout, since otherwise the value is
+    /// stored at a different offset.
+    pub unsafe fn retain(ptr: NonNull<ffi::Block_byref_header>) -> Self {
+        let mut heap: *mut ffi::Block_byref_header = ptr::null_mut();
+        let heap_ptr: *mut *mut ffi::Block_byref_header = &mut heap;
+        // SAFETY: Upheld by the caller.
+        unsafe {
+            ffi::_Block_object_assign(
+                heap_ptr.cast(),
+                ptr.as_ptr().cast(),
+                ffi::BLOCK_FIELD_IS_BYREF,
+            )
+        };
+        Self {
+            ptr: NonNull::new(heap).expect("failed retaining __block variable"),
+            p: PhantomData,
+        }
+    }
+
+    /// Get a pointer to the underlying `__block` structure.
+    ///
+    /// This can be used to share the variable with Objective-C code, which
+    /// will see the same value as the Rust code.
+    #[inline]
+    pub fn as_byref_ptr(&self) -> NonNull<ffi::Block_byref_header> {
+        self.ptr
+    }
+
+    #[inline]
+    fn layout(&self) -> *mut ByRefLayout<T> {
+        // SAFETY: The pointer is valid while we hold a reference to it.
+        //
+        // Once on the heap, the structure forwards to itself, but we still
+        // follow the pointer to match what the compiler does.
+        unsafe { (*self.ptr.as_ptr()).forwarding.cast() }
+    }
+
+    /// Get a raw pointer to the shared value.
+    ///
+    /// The pointer is valid for as long as this (or another) reference to
+    /// the variable is alive.
+    #[inline]
+    pub fn as_ptr(&self) -> *mut T {
+        // SAFETY: The layout pointer is valid.
+        unsafe { UnsafeCell::raw_get(ptr::addr_of!((*self.layout()).value)) }
+    }
+
+    /// Set the shared value, dropping the previous value.
+    pub fn set(&self, value: T) {
+        drop(self.replace(value));
+    }
+
+    /// Replace the shared value, and return the previous value.
+    pub fn replace(&self, value: T) -> T {
+        // SAFETY: We never hand out references to the value, and the type is
+        // not `Sync`, so nobody else can be accessing it right now.
+        unsafe { ptr::replace(self.as_ptr(), value) }
+    }
+
+    /// Swap the values of two shared variables.
+    pub fn swap(&self, other: &Self) {
+        if self.as_ptr() != other.as_ptr() {
+            // SAFETY: Same as in `replace`, and the pointers do not overlap.
+            unsafe { ptr::swap_nonoverlapping(self.as_ptr(), other.as_ptr(), 1) }
+        }
+    }
+}
+
+impl<T: Copy> ByRef<T> {
+    /// Return a copy of the shared value.
+    pub fn get(&self) -> T {
+        // SAFETY: See `replace`.
+        unsafe { *self.as_ptr() }
+    }
+}
+
+impl<T: Default> ByRef<T> {
+    /// Take the shared value, leaving `Default::default()` in its place.
+    pub fn take(&self) -> T {
+        self.replace(T::default())
+    }
+}
+
+impl<T: Default> Default for ByRef<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> Clone for ByRef<T> {
+    /// Create a new reference to the same shared variable.
+    fn clone(&self) -> Self {
+        // SAFETY: The variable is valid, and is already on the heap, so this
+        // only increments the reference count.
+        unsafe { Self::retain(self.ptr) }
+    }
+}
+
+impl<T> Drop for ByRef<T> {
+    fn drop(&mut self) {
+        // SAFETY: We own a reference to the variable. If this was the last
+        // one, the value is dropped with `byref_destroy`.
+        unsafe { ffi::_Block_object_dispose(self.ptr.as_ptr().cast(), ffi::BLOCK_FIELD_IS_BYREF) };
+    }
+}
@@ -16,7 +16,7 @@ mod private {
 
 /// Types that may be converted into a [`ConcreteBlock`].
 ///
-/// This is implemented for [`Fn`] closures of up to 12 arguments, where each
+/// This is implemented for [`Fn`] closures of up to 16 arguments, where each
 /// argument and the return type implements [`Encode`].
 ///
 ///
@@ -33,10 +33,7 @@ pub unsafe trait IntoConcreteBlock<A: BlockArguments>: private::Sealed<A> + Size
 }
 
 macro_rules! concrete_block_impl {
-    ($f:ident) => (
-        concrete_block_impl!($f,);
-    );
-    ($f:ident, $($a:ident : $t:ident),*) => (
+    ($($a:ident: $t:ident),*) => (
         impl<$($t: Encode,)* R: EncodeReturn, X> private::Sealed<($($t,)*)> for X
         where
             X: Fn($($t,)*) -> R,
@@ -49,7 +46,7 @@ macro_rules! concrete_block_impl {
             type Output = R;
 
             fn __into_concrete_block(self) -> ConcreteBlock<($($t,)*), R, X> {
-                extern "C" fn $f<$($t,)* R, X>(
+                extern "C" fn invoke<$($t,)* R, X>(
                     block: &ConcreteBlock<($($t,)*), R, X>,
                     $($a: $t,)*
                 ) -> R
@@ -59,7 +56,7 @@ macro_rules! concrete_block_impl {
                     (block.closure)($($a),*)
                 }
 
-                let f: extern "C" fn(&ConcreteBlock<($($t,)*), R, X>, $($a: $t,)*) -> R = $f;
+                let f: extern "C" fn(&ConcreteBlock<($($t,)*), R, X>, $($a: $t,)*) -> R = invoke;
                 let f: unsafe extern "C" fn() = unsafe { mem::transmute(f) };
                 unsafe { ConcreteBlock::with_invoke(f, self) }
             }
@@ -67,96 +64,7 @@ macro_rules! concrete_block_impl {
     );
 }
 
-concrete_block_impl!(concrete_block_invoke_args0);
-concrete_block_impl!(concrete_block_invoke_args1, a: A);
-concrete_block_impl!(concrete_block_invoke_args2, a: A, b: B);
-concrete_block_impl!(concrete_block_invoke_args3, a: A, b: B, c: C);
-concrete_block_impl!(concrete_block_invoke_args4, a: A, b: B, c: C, d: D);
-concrete_block_impl!(concrete_block_invoke_args5, a: A, b: B, c: C, d: D, e: E);
-concrete_block_impl!(
-    concrete_block_invoke_args6,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F
-);
-concrete_block_impl!(
-    concrete_block_invoke_args7,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G
-);
-concrete_block_impl!(
-    concrete_block_invoke_args8,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H
-);
-concrete_block_impl!(
-    concrete_block_invoke_args9,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H,
-    i: I
-);
-concrete_block_impl!(
-    concrete_block_invoke_args10,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H,
-    i: I,
-    j: J
-);
-concrete_block_impl!(
-    concrete_block_invoke_args11,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H,
-    i: I,
-    j: J,
-    k: K
-);
-concrete_block_impl!(
-    concrete_block_invoke_args12,
-    a: A,
-    b: B,
-    c: C,
-    d: D,
-    e: E,
-    f: F,
-    g: G,
-    h: H,
-    i: I,
-    j: J,
-    k: K,
-    l: L
-);
+tuple_impls!(concrete_block_impl);
 
 /// An Objective-C block whose size is known at compile time and may be
 /// constructed on the stack.
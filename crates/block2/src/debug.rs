@@ -4,10 +4,10 @@ use core::fmt::{Debug, DebugStruct, Error, Formatter};
 use core::ptr;
 use std::ffi::CStr;
 
-use crate::{ffi, Block, ConcreteBlock, GlobalBlock, RcBlock};
+use crate::{ffi, Block, ByRef, ConcreteBlock, GlobalBlock, RcBlock};
 
 #[derive(Clone, Copy, PartialEq, Eq)]
-struct Isa(*const ffi::Class);
+pub(crate) struct Isa(pub(crate) *const ffi::Class);
 
 impl Isa {
     fn is_global(self) -> bool {
@@ -21,16 +21,25 @@ impl Isa {
     fn is_malloc(self) -> bool {
         ptr::eq(unsafe { &ffi::_NSConcreteMallocBlock }, self.0)
     }
-}
 
-impl Debug for Isa {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+    /// The name of the `isa`, if it is one of the known block classes.
+    pub(crate) fn name(self) -> Option<&'static str> {
         if self.is_global() {
-            f.write_str("_NSConcreteGlobalBlock")
+            Some("_NSConcreteGlobalBlock")
         } else if self.is_stack() {
-            f.write_str("_NSConcreteStackBlock")
+            Some("_NSConcreteStackBlock")
         } else if self.is_malloc() {
-            f.write_str("_NSConcreteMallocBlock")
+            Some("_NSConcreteMallocBlock")
+        } else {
+            None
+        }
+    }
+}
+
+impl Debug for Isa {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        if let Some(name) = self.name() {
+            f.write_str(name)
         } else {
             write!(f, "{:?}", self.0)
         }
@@ -88,6 +97,15 @@ impl<A, R> Debug for GlobalBlock<A, R> {
     }
 }
 
+impl<T: Copy + Debug> Debug for ByRef<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("ByRef")
+            .field("ptr", &self.as_byref_ptr())
+            .field("value", &self.get())
+            .finish()
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 struct BlockFlags(ffi::block_flags);
 
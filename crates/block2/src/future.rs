@@ -0,0 +1,303 @@
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::error::Error;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use objc2::encode::Encode;
+
+use crate::{BlockArguments, ConcreteBlock, RcBlock};
+
+/// The error returned by [`CompletionFuture`] when the completion handler
+/// was dropped without being called.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct HandlerDropped;
+
+impl fmt::Display for HandlerDropped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("completion handler was dropped without being called")
+    }
+}
+
+impl Error for HandlerDropped {}
+
+enum State<T> {
+    Pending(Option<Waker>),
+    Ready(T),
+    Dropped,
+    Done,
+}
+
+struct Shared<T> {
+    state: Mutex<State<T>>,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        // The lock is never held while running user code, so poisoning
+        // shouldn't happen; but even if it does, the state is still valid.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Resolve the future, if it is still pending.
+    fn complete(&self, new: State<T>) {
+        let mut state = self.lock();
+        if let State::Pending(waker) = &mut *state {
+            let waker = waker.take();
+            *state = new;
+            drop(state);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+/// The sending half, which lives inside the block.
+#[doc(hidden)]
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Sender<T> {
+    fn send(self, value: T) {
+        self.shared.complete(State::Ready(value));
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        // No-op if the value was already sent.
+        self.shared.complete(State::Dropped);
+    }
+}
+
+/// A future that resolves when a completion handler block is called.
+///
+/// Created with [`completion_handler`] or [`completion_handler_with`].
+///
+/// If the block is dropped without being called, this resolves to
+/// [`HandlerDropped`].
+///
+/// This does not depend on any specific executor.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct CompletionFuture<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Future for CompletionFuture<T> {
+    type Output = Result<T, HandlerDropped>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock();
+        match &mut *state {
+            State::Pending(waker) => {
+                match waker {
+                    Some(waker) if waker.will_wake(cx.waker()) => {}
+                    _ => *waker = Some(cx.waker().clone()),
+                }
+                Poll::Pending
+            }
+            State::Ready(_) => match core::mem::replace(&mut *state, State::Done) {
+                State::Ready(value) => Poll::Ready(Ok(value)),
+                _ => unreachable!(),
+            },
+            State::Dropped => Poll::Ready(Err(HandlerDropped)),
+            State::Done => panic!("CompletionFuture polled after completion"),
+        }
+    }
+}
+
+impl<T> fmt::Debug for CompletionFuture<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompletionFuture").finish_non_exhaustive()
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Types that may be used as the arguments of a completion handler block.
+///
+/// This is implemented for the same tuples as [`BlockArguments`], where each
+/// argument is `'static`.
+///
+///
+/// # Safety
+///
+/// This is a sealed trait, and should not need to be implemented.
+pub unsafe trait CompletionArguments: BlockArguments + private::Sealed + 'static {
+    #[doc(hidden)]
+    fn __completion_block<T, X>(map: X, sender: Sender<T>) -> RcBlock<Self, ()>
+    where
+        X: FnOnce(Self) -> T + Send + 'static,
+        T: Send + 'static;
+}
+
+macro_rules! completion_args_impl {
+    ($($a:ident: $t:ident),*) => (
+        impl<$($t: Encode + 'static),*> private::Sealed for ($($t,)*) {}
+
+        unsafe impl<$($t: Encode + 'static),*> CompletionArguments for ($($t,)*) {
+            fn __completion_block<T, X>(map: X, sender: Sender<T>) -> RcBlock<Self, ()>
+            where
+                X: FnOnce(Self) -> T + Send + 'static,
+                T: Send + 'static,
+            {
+                // Named `X` and `map`, since `F` and `f` are taken by the
+                // tuple arguments.
+                let inner = Mutex::new(Some((map, sender)));
+                ConcreteBlock::new(move |$($a: $t),*| {
+                    let inner = inner.lock().unwrap_or_else(PoisonError::into_inner).take();
+                    // Completion handlers should only be called once, so we
+                    // ignore subsequent calls.
+                    if let Some((map, sender)) = inner {
+                        sender.send(map(($($a,)*)));
+                    }
+                })
+                .copy()
+            }
+        }
+    );
+}
+
+tuple_impls!(completion_args_impl);
+
+/// Create a completion handler block, and a future that resolves with the
+/// arguments that the block is called with.
+///
+/// The block can be passed to Objective-C methods that take a
+/// `completionHandler:`, and will usually be called once on some other
+/// thread or dispatch queue, at which point the future resolves.
+///
+/// If the arguments are not [`Send`] (such as raw pointers to objects),
+/// use [`completion_handler_with`] to convert them inside the block.
+///
+///
+/// # Examples
+///
+/// ```
+/// use block2::{completion_handler, HandlerDropped};
+///
+/// async fn example() -> Result<i32, HandlerDropped> {
+///     let (block, future) = completion_handler::<(i32,)>();
+///     // Usually: `msg_send![obj, doSomethingWithCompletionHandler: &*block]`
+///     unsafe { block.call((42,)) };
+///     drop(block);
+///     let (x,) = future.await?;
+///     Ok(x)
+/// }
+/// ```
+pub fn completion_handler<A>() -> (RcBlock<A, ()>, CompletionFuture<A>)
+where
+    A: CompletionArguments + Send,
+{
+    completion_handler_with(|args| args)
+}
+
+/// Create a completion handler block, and a future that resolves with the
+/// result of calling `f` with the arguments that the block is called with.
+///
+/// `f` is run inside the block, and can be used to e.g. retain the objects
+/// that the block receives, to convert them to types that are [`Send`].
+///
+/// See [`completion_handler`] for details.
+///
+///
+/// # Examples
+///
+/// ```no_run
+/// use block2::completion_handler_with;
+/// use objc2::rc::Id;
+/// use objc2::runtime::NSObject;
+///
+/// let (block, future) = completion_handler_with(|(obj,): (*mut NSObject,)| {
+///     // SAFETY: The object is valid for the duration of the block.
+///     let obj = unsafe { Id::retain(obj) };
+///     // Do something with the object, and return something `Send`.
+///     obj.is_some()
+/// });
+/// ```
+pub fn completion_handler_with<A, T, F>(f: F) -> (RcBlock<A, ()>, CompletionFuture<T>)
+where
+    A: CompletionArguments,
+    F: FnOnce(A) -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State::Pending(None)),
+    });
+    let sender = Sender {
+        shared: Arc::clone(&shared),
+    };
+    let block = A::__completion_block(f, sender);
+    (block, CompletionFuture { shared })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::String;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::task::Wake;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn poll<F: Future + Unpin>(future: &mut F, waker: &Arc<CountingWaker>) -> Poll<F::Output> {
+        let waker = Waker::from(Arc::clone(waker));
+        let mut cx = Context::from_waker(&waker);
+        Pin::new(future).poll(&mut cx)
+    }
+
+    #[test]
+    fn test_called() {
+        let waker = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let (block, mut future) = completion_handler::<(i32, u8)>();
+        assert!(poll(&mut future, &waker).is_pending());
+
+        unsafe { block.call((42, 1)) };
+        assert_eq!(waker.0.load(Ordering::SeqCst), 1);
+        // Calling it again is ignored
+        unsafe { block.call((43, 0)) };
+        assert_eq!(waker.0.load(Ordering::SeqCst), 1);
+
+        assert_eq!(poll(&mut future, &waker), Poll::Ready(Ok((42, 1))));
+    }
+
+    #[test]
+    fn test_called_before_poll() {
+        let waker = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let (block, mut future) = completion_handler_with(|(x,): (*const u8,)| {
+            String::from(if x.is_null() { "null" } else { "ptr" })
+        });
+        unsafe { block.call((core::ptr::null(),)) };
+        drop(block);
+        assert_eq!(
+            poll(&mut future, &waker),
+            Poll::Ready(Ok(String::from("null")))
+        );
+        assert_eq!(waker.0.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_dropped() {
+        let waker = Arc::new(CountingWaker(AtomicUsize::new(0)));
+        let (block, mut future) = completion_handler::<()>();
+        assert!(poll(&mut future, &waker).is_pending());
+        let block2 = block.clone();
+        drop(block);
+        assert!(poll(&mut future, &waker).is_pending());
+        drop(block2);
+        assert_eq!(waker.0.load(Ordering::SeqCst), 1);
+        assert_eq!(poll(&mut future, &waker), Poll::Ready(Err(HandlerDropped)));
+    }
+}
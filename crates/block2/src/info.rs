@@ -0,0 +1,415 @@
+use alloc::vec::Vec;
+#[cfg(feature = "apple")]
+use core::ffi::c_void;
+use core::fmt;
+#[cfg(feature = "apple")]
+use core::ptr;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use objc2::encode::{EncodingBox, ParseError};
+
+use crate::debug::Isa;
+use crate::{ffi, Block};
+
+/// Information about a block, such as its flags, size and signature.
+///
+/// This is mostly useful for diagnosing blocks handed to you by frameworks,
+/// for example to print the signature of a block in a crash report.
+///
+/// See [`Block::info`].
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct BlockInfo<'a> {
+    class_name: Option<&'static str>,
+    flags: ffi::block_flags,
+    size: usize,
+    signature: Option<&'a CStr>,
+    #[cfg(feature = "apple")]
+    extended_layout: Option<ExtendedLayout<'a>>,
+}
+
+/// The extended layout of a block, describing how its captured variables
+/// are laid out.
+///
+/// See the comments in Apple's [`Block_private.h`][private] for details on
+/// the format.
+///
+/// [private]: https://github.com/apple-oss-distributions/libclosure/blob/libclosure-79/Block_private.h
+#[cfg(feature = "apple")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtendedLayout<'a> {
+    /// The inline layout, in the form `0xXYZ`, where `X` is the number of
+    /// strong references, `Y` the number of `__block` variables, and `Z` the
+    /// number of weak references.
+    Inline(usize),
+    /// A layout string, consisting of a series of opcodes.
+    String(&'a CStr),
+}
+
+/// The parsed signature of a block.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct BlockSignature {
+    /// The return type of the block.
+    pub return_type: EncodingBox,
+    /// The types of the arguments of the block.
+    ///
+    /// This does not include the implicit first argument, the block itself.
+    pub arguments: Vec<EncodingBox>,
+}
+
+unsafe fn read_c_str<'a>(ptr: *const c_char) -> Option<&'a CStr> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(ptr) })
+    }
+}
+
+impl<'a> BlockInfo<'a> {
+    /// # Safety
+    ///
+    /// The layout must be a valid block.
+    pub(crate) unsafe fn from_layout(layout: &'a ffi::Block_layout) -> Self {
+        let flags = layout.flags;
+
+        let mut this = Self {
+            class_name: Isa(layout.isa).name(),
+            flags,
+            size: 0,
+            signature: None,
+            #[cfg(feature = "apple")]
+            extended_layout: None,
+        };
+
+        if layout.descriptor.is_null() {
+            return this;
+        }
+
+        #[cfg(feature = "apple")]
+        if flags & ffi::BLOCK_SMALL_DESCRIPTOR != 0 {
+            // SAFETY: The flag says that this is a small descriptor.
+            unsafe { this.read_small_descriptor(layout.descriptor) };
+            return this;
+        }
+
+        // SAFETY: All descriptors start with the header.
+        let header = unsafe { &*layout.descriptor.cast::<ffi::Block_descriptor_header>() };
+        this.size = header.size as usize;
+
+        if flags & ffi::BLOCK_HAS_SIGNATURE != 0 {
+            // The signature is placed after the copy and dispose helpers, if
+            // they are present; and on Apple platforms, the layout is placed
+            // directly after the signature.
+            let (signature, _layout): (*const c_char, *const *const c_char) =
+                if flags & ffi::BLOCK_HAS_COPY_DISPOSE != 0 {
+                    // SAFETY: Checked the flags.
+                    let desc = unsafe {
+                        &*layout
+                            .descriptor
+                            .cast::<ffi::Block_descriptor_with_signature>()
+                    };
+                    let ptr: *const ffi::Block_descriptor_with_signature = desc;
+                    (desc.encoding, unsafe { ptr.add(1) }.cast())
+                } else {
+                    // SAFETY: Checked the flags.
+                    let desc = unsafe { &*layout.descriptor.cast::<ffi::Block_descriptor_basic>() };
+                    let ptr: *const ffi::Block_descriptor_basic = desc;
+                    (desc.encoding, unsafe { ptr.add(1) }.cast())
+                };
+            // SAFETY: The signature is a C string, or NULL.
+            this.signature = unsafe { read_c_str(signature) };
+
+            #[cfg(feature = "apple")]
+            if flags & ffi::BLOCK_HAS_EXTENDED_LAYOUT != 0 {
+                // SAFETY: The flag says that the layout field is present.
+                let layout = unsafe { *_layout };
+                this.extended_layout = Some(unsafe { ExtendedLayout::from_ptr(layout) });
+            }
+        }
+
+        this
+    }
+
+    /// Read `Block_descriptor_small`, which uses relative offsets.
+    #[cfg(feature = "apple")]
+    unsafe fn read_small_descriptor(&mut self, descriptor: *const c_void) {
+        #[repr(C)]
+        struct BlockDescriptorSmall {
+            size: u32,
+            signature: i32,
+            layout: i32,
+            copy: i32,
+            dispose: i32,
+        }
+
+        let desc = descriptor.cast::<BlockDescriptorSmall>();
+        // SAFETY: Upheld by caller.
+        let (size, signature, layout) =
+            unsafe { ((*desc).size, (*desc).signature, (*desc).layout) };
+        self.size = size as usize;
+
+        // The offsets are relative to the address of the field itself.
+        let relative = |field: *const i32, offset: i32| -> *const c_char {
+            field.cast::<c_char>().wrapping_offset(offset as isize)
+        };
+
+        if self.flags & ffi::BLOCK_HAS_SIGNATURE != 0 && signature != 0 {
+            let field = unsafe { ptr::addr_of!((*desc).signature) };
+            // SAFETY: The offset points to a C string.
+            self.signature = unsafe { read_c_str(relative(field, signature)) };
+        }
+
+        if self.flags & ffi::BLOCK_HAS_EXTENDED_LAYOUT != 0 {
+            let layout = if (0..0x1000).contains(&layout) {
+                // Inline layouts are stored directly.
+                layout as usize as *const c_char
+            } else {
+                relative(unsafe { ptr::addr_of!((*desc).layout) }, layout)
+            };
+            // SAFETY: The layout is either inline, or a valid C string.
+            self.extended_layout = Some(unsafe { ExtendedLayout::from_ptr(layout) });
+        }
+    }
+
+    /// The raw flags of the block.
+    #[inline]
+    pub fn flags(&self) -> ffi::block_flags {
+        self.flags
+    }
+
+    /// Whether the block is stored in global memory.
+    #[inline]
+    pub fn is_global(&self) -> bool {
+        self.flags & ffi::BLOCK_IS_GLOBAL != 0
+    }
+
+    /// Whether the block was created with the `noescape` attribute.
+    ///
+    /// This is only recorded by Apple's runtime, and is always `false` on
+    /// other runtimes.
+    #[inline]
+    pub fn is_noescape(&self) -> bool {
+        #[cfg(feature = "apple")]
+        {
+            self.flags & ffi::BLOCK_IS_NOESCAPE != 0
+        }
+        #[cfg(not(feature = "apple"))]
+        {
+            false
+        }
+    }
+
+    /// Whether the block has copy and dispose helpers, meaning that it
+    /// captures variables that need to be retained or dropped.
+    #[inline]
+    pub fn has_copy_dispose(&self) -> bool {
+        self.flags & ffi::BLOCK_HAS_COPY_DISPOSE != 0
+    }
+
+    /// Whether the block has an Objective-C type-encoding signature.
+    #[inline]
+    pub fn has_signature(&self) -> bool {
+        self.flags & ffi::BLOCK_HAS_SIGNATURE != 0
+    }
+
+    /// Whether the block returns its value through a pointer passed in by
+    /// the caller.
+    ///
+    /// This is only meaningful when the block has a signature.
+    #[inline]
+    pub fn use_stret(&self) -> bool {
+        self.flags & ffi::BLOCK_USE_STRET != 0
+    }
+
+    /// The size of the block structure, including captured variables, as
+    /// recorded in the descriptor.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The name of the class of the block, for example
+    /// `_NSConcreteStackBlock`.
+    ///
+    /// This is `None` if the class is not one of `_NSConcreteGlobalBlock`,
+    /// `_NSConcreteStackBlock` or `_NSConcreteMallocBlock`. The class is
+    /// compared against these symbols instead of being asked for its name,
+    /// since the `isa` of a block is not necessarily an Objective-C class
+    /// (for example when using `compiler-rt`).
+    #[inline]
+    pub fn class_name(&self) -> Option<&'static str> {
+        self.class_name
+    }
+
+    /// The raw Objective-C type-encoding signature of the block, if present.
+    #[inline]
+    pub fn signature_str(&self) -> Option<&'a CStr> {
+        self.signature
+    }
+
+    /// Parse the signature of the block, if present.
+    ///
+    /// Returns an error if the signature could not be parsed, including if
+    /// it is not valid UTF-8.
+    pub fn signature(&self) -> Option<Result<BlockSignature, ParseError>> {
+        let signature = self.signature?;
+        // Type-encodings are always ASCII, so invalid UTF-8 is replaced with
+        // a character that fails to parse.
+        Some(parse_signature(&signature.to_string_lossy()))
+    }
+
+    /// The extended layout of the block, if present.
+    #[cfg(feature = "apple")]
+    #[inline]
+    pub fn extended_layout(&self) -> Option<ExtendedLayout<'a>> {
+        self.extended_layout
+    }
+}
+
+#[cfg(feature = "apple")]
+impl<'a> ExtendedLayout<'a> {
+    unsafe fn from_ptr(ptr: *const c_char) -> Self {
+        if (ptr as usize) < 0x1000 {
+            Self::Inline(ptr as usize)
+        } else {
+            // SAFETY: Caller ensures that this is a valid C string.
+            Self::String(unsafe { CStr::from_ptr(ptr) })
+        }
+    }
+}
+
+fn parse_signature(mut s: &str) -> Result<BlockSignature, ParseError> {
+    // Skip the stack layout information after each encoding.
+    fn skip_stack_layout(s: &mut &str) {
+        *s = s.trim_start_matches(|c: char| c.is_ascii_digit() || c == '-' || c == '+');
+    }
+
+    let return_type = EncodingBox::from_start_of_str(&mut s)?;
+    skip_stack_layout(&mut s);
+
+    let mut arguments = Vec::new();
+    while !s.is_empty() {
+        arguments.push(EncodingBox::from_start_of_str(&mut s)?);
+        skip_stack_layout(&mut s);
+    }
+
+    // Remove the block itself (`@?`)
+    if !arguments.is_empty() {
+        arguments.remove(0);
+    }
+
+    Ok(BlockSignature {
+        return_type,
+        arguments,
+    })
+}
+
+impl fmt::Debug for BlockInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut f = f.debug_struct("BlockInfo");
+        f.field("class", &self.class_name());
+        f.field("flags", &format_args!("{:#034b}", self.flags));
+        f.field("is_global", &self.is_global());
+        f.field("is_noescape", &self.is_noescape());
+        f.field("has_copy_dispose", &self.has_copy_dispose());
+        f.field("has_signature", &self.has_signature());
+        f.field("size", &self.size);
+        f.field("signature", &self.signature);
+        #[cfg(feature = "apple")]
+        f.field("extended_layout", &self.extended_layout);
+        f.finish()
+    }
+}
+
+impl<A, R> Block<A, R> {
+    /// Get information about the block, such as its flags, size and
+    /// signature.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use block2::ConcreteBlock;
+    ///
+    /// let block = ConcreteBlock::new(|a: i32| a + 1);
+    /// let info = block.info();
+    /// assert!(!info.is_global());
+    /// println!("{info:?}");
+    /// ```
+    pub fn info(&self) -> BlockInfo<'_> {
+        let ptr: *const Self = self;
+        // SAFETY: The block is valid.
+        let layout = unsafe { ptr.cast::<ffi::Block_layout>().as_ref().unwrap_unchecked() };
+        unsafe { BlockInfo::from_layout(layout) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use core::mem;
+    use objc2::Encoding;
+
+    use crate::{global_block, ConcreteBlock};
+
+    #[test]
+    fn test_parse_signature() {
+        let signature = parse_signature("i16@?0i8").unwrap();
+        assert_eq!(signature.return_type, Encoding::Int);
+        assert_eq!(signature.arguments, vec![EncodingBox::Int]);
+
+        let signature = parse_signature("v8@?0").unwrap();
+        assert_eq!(signature.return_type, Encoding::Void);
+        assert!(signature.arguments.is_empty());
+
+        let signature = parse_signature("{CGPoint=dd}32@?0r*8@\"NSString\"16q24").unwrap();
+        assert_eq!(
+            signature.return_type,
+            Encoding::Struct("CGPoint", &[Encoding::Double, Encoding::Double])
+        );
+        assert_eq!(
+            signature.arguments,
+            vec![
+                EncodingBox::String,
+                EncodingBox::Object,
+                EncodingBox::LongLong
+            ]
+        );
+
+        assert!(parse_signature("i16@?0!").is_err());
+    }
+
+    #[test]
+    fn test_global_info() {
+        global_block! {
+            static BLOCK = |_x: i32| {};
+        }
+        let info = BLOCK.info();
+        assert!(info.is_global());
+        assert!(!info.is_noescape());
+        assert!(!info.has_copy_dispose());
+        assert!(!info.has_signature());
+        assert_eq!(info.size(), mem::size_of::<ffi::Block_layout>());
+        assert_eq!(info.signature_str(), None);
+        assert_eq!(info.signature(), None);
+        assert_eq!(info.class_name(), Some("_NSConcreteGlobalBlock"));
+    }
+
+    #[test]
+    fn test_concrete_info() {
+        let s = alloc::string::String::from("foo");
+        let block = ConcreteBlock::new(move || s.len());
+        let info = block.info();
+        assert!(!info.is_global());
+        assert!(info.has_copy_dispose());
+        assert_eq!(info.size(), mem::size_of_val(&block));
+        assert_eq!(info.class_name(), Some("_NSConcreteStackBlock"));
+
+        let copied = block.copy();
+        let info = copied.info();
+        assert!(!info.is_global());
+        assert!(info.has_copy_dispose());
+        assert_eq!(info.class_name(), Some("_NSConcreteMallocBlock"));
+    }
+}
@@ -71,6 +71,23 @@
 //! assert_eq!(unsafe { MY_BLOCK.call(()) }, 10.0);
 //! ```
 //!
+//! ## Sharing variables between blocks
+//!
+//! Variables declared with the `__block` storage qualifier in C can be
+//! shared and mutated by several blocks. The equivalent in Rust is the
+//! [`ByRef`] type:
+//!
+//! ```
+//! use block2::{ByRef, ConcreteBlock};
+//! let counter = ByRef::new(0);
+//! let block = ConcreteBlock::new({
+//!     let counter = counter.clone();
+//!     move || counter.set(counter.get() + 1)
+//! });
+//! unsafe { block.call(()) };
+//! assert_eq!(counter.get(), 1);
+//! ```
+//!
 //! [lang]: https://clang.llvm.org/docs/BlockLanguageSpec.html
 //! [ABI]: http://clang.llvm.org/docs/Block-ABI-Apple.html
 
@@ -97,13 +114,55 @@ extern "C" {}
 
 pub use block_sys as ffi;
 
+/// Invoke the given macro for tuples of up to 16 elements, the same number
+/// of arguments that `objc2::MessageArguments` supports.
+///
+/// We can't reuse `MessageArguments` itself, since its `__invoke` is
+/// specific to message sends (it passes a receiver and a selector before
+/// the arguments, and converts out parameters, which blocks don't support).
+macro_rules! tuple_impls {
+    ($m:ident) => {
+        $m!();
+        $m!(a: A);
+        $m!(a: A, b: B);
+        $m!(a: A, b: B, c: C);
+        $m!(a: A, b: B, c: C, d: D);
+        $m!(a: A, b: B, c: C, d: D, e: E);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L, m: M);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L, m: M, n: N);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L, m: M, n: N, o: O);
+        $m!(a: A, b: B, c: C, d: D, e: E, f: F, g: G, h: H, i: I, j: J, k: K, l: L, m: M, n: N, o: O, p: P);
+    };
+}
+
+mod abi;
 mod block;
+mod byref;
 mod concrete_block;
 mod debug;
+#[cfg(feature = "future")]
+mod future;
 mod global;
+mod info;
 mod rc_block;
 
 pub use block::{Block, BlockArguments};
+pub use byref::ByRef;
 pub use concrete_block::{ConcreteBlock, IntoConcreteBlock};
+#[cfg(feature = "future")]
+pub use future::{
+    completion_handler, completion_handler_with, CompletionArguments, CompletionFuture,
+    HandlerDropped,
+};
 pub use global::GlobalBlock;
+#[cfg(feature = "apple")]
+pub use info::ExtendedLayout;
+pub use info::{BlockInfo, BlockSignature};
 pub use rc_block::RcBlock;
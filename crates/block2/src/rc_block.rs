@@ -1,8 +1,23 @@
+#[cfg(not(feature = "compiler-rt"))]
+use core::mem::ManuallyDrop;
 use core::ops::Deref;
 
+#[cfg(not(feature = "compiler-rt"))]
+use objc2::encode::__unstable::EncodeReturn;
+#[cfg(not(feature = "compiler-rt"))]
+use objc2::rc::Id;
+#[cfg(not(feature = "compiler-rt"))]
+use objc2::runtime::AnyObject;
+
+#[cfg(not(feature = "compiler-rt"))]
+use crate::BlockArguments;
 use crate::{ffi, Block};
 
 /// A reference-counted Objective-C block.
+///
+/// Heap-allocated blocks are Objective-C objects (instances of `NSBlock`),
+/// so this can be converted to and from [`Id<Block<A, R>>`][Id], for example
+/// to store it in an `NSArray` or as an associated object.
 pub struct RcBlock<A, R> {
     pub(crate) ptr: *mut Block<A, R>,
 }
@@ -35,6 +50,66 @@ impl<A, R> RcBlock<A, R> {
     }
 }
 
+#[cfg(not(feature = "compiler-rt"))]
+impl<A: BlockArguments, R: EncodeReturn> RcBlock<A, R> {
+    /// Constructs an `RcBlock` by retaining the given Objective-C object.
+    ///
+    /// This uses `objc_retainBlock`, which copies the block to the heap if
+    /// it was on the stack, like ARC does when a block is converted to `id`.
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid block that takes arguments `A` and returns
+    /// `R`.
+    pub unsafe fn retain_object(obj: &AnyObject) -> Self {
+        let obj: *const AnyObject = obj;
+        // SAFETY: The caller ensures that the object is a block.
+        let ptr = unsafe { objc2::ffi::objc_retainBlock((obj as *mut AnyObject).cast()) };
+        // SAFETY: `objc_retainBlock` returns a +1 reference count.
+        unsafe { Self::new(ptr.cast()) }
+    }
+
+    /// Construct an `RcBlock` from an Objective-C object, taking over its
+    /// reference count.
+    ///
+    /// This is the inverse of [`RcBlock::into_id`].
+    pub fn from_id(obj: Id<Block<A, R>>) -> Self {
+        let obj = ManuallyDrop::new(obj);
+        let ptr: *const Block<A, R> = Id::as_ptr(&obj);
+        // SAFETY: `Id` holds a +1 reference count to a block on the heap
+        // (or a global block), and retaining and releasing blocks as objects
+        // uses the same reference count as `_Block_copy` and
+        // `_Block_release`.
+        unsafe { Self::new(ptr as *mut Block<A, R>) }
+    }
+
+    /// Convert the block into an Objective-C object.
+    ///
+    /// The reference count is handed over to the returned [`Id`], which
+    /// releases the block when dropped.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RcBlock::into_id(block)`.
+    pub fn into_id(this: Self) -> Id<Block<A, R>> {
+        let this = ManuallyDrop::new(this);
+        // SAFETY: The block is on the heap (or is global), and we own a +1
+        // reference count to it, so it is a valid object.
+        unsafe { Id::new(this.ptr) }.expect("RcBlock pointer was NULL")
+    }
+
+    /// View the block as an Objective-C object.
+    ///
+    /// This allows passing it where `id` is expected.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RcBlock::as_object(&block)`.
+    pub fn as_object(this: &Self) -> &AnyObject {
+        // SAFETY: The block is on the heap (or is global), so it is a valid
+        // object for as long as we hold a reference to it.
+        unsafe { this.ptr.cast::<AnyObject>().as_ref().unwrap_unchecked() }
+    }
+}
+
 impl<A, R> Clone for RcBlock<A, R> {
     fn clone(&self) -> RcBlock<A, R> {
         // SAFETY: The pointer is valid, since the only way to get an RcBlock
@@ -52,6 +127,27 @@ impl<A, R> Deref for RcBlock<A, R> {
     }
 }
 
+#[cfg(not(feature = "compiler-rt"))]
+impl<A: BlockArguments, R: EncodeReturn> AsRef<AnyObject> for RcBlock<A, R> {
+    fn as_ref(&self) -> &AnyObject {
+        RcBlock::as_object(self)
+    }
+}
+
+#[cfg(not(feature = "compiler-rt"))]
+impl<A: BlockArguments, R: EncodeReturn> From<RcBlock<A, R>> for Id<Block<A, R>> {
+    fn from(block: RcBlock<A, R>) -> Self {
+        RcBlock::into_id(block)
+    }
+}
+
+#[cfg(not(feature = "compiler-rt"))]
+impl<A: BlockArguments, R: EncodeReturn> From<Id<Block<A, R>>> for RcBlock<A, R> {
+    fn from(obj: Id<Block<A, R>>) -> Self {
+        RcBlock::from_id(obj)
+    }
+}
+
 impl<A, R> Drop for RcBlock<A, R> {
     fn drop(&mut self) {
         unsafe { ffi::_Block_release(self.ptr.cast()) };
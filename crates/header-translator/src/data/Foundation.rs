@@ -139,6 +139,13 @@ data! {
         unsafe -setName;
     }
 
+    // SAFETY: Run loops are per-thread, and are not thread-safe.
+    class NSRunLoop: ThreadConfined {}
+
+    // SAFETY: Autorelease pools must be drained on the thread that created
+    // them.
+    class NSAutoreleasePool: ThreadConfined {}
+
     class NSValue {
         unsafe -objCType;
         unsafe -isEqualToValue;
@@ -20,6 +20,22 @@ impl ToOptionString for Option<String> {
     }
 }
 
+/// CoreFoundation types that are defined manually in `icrate`'s Foundation.
+///
+/// TODO: Translate CoreFoundation itself.
+const CF_TYPES: &[&str] = &[
+    "CFTypeRef",
+    "CFStringRef",
+    "CFMutableStringRef",
+    "CFDataRef",
+    "CFMutableDataRef",
+    "CFArrayRef",
+    "CFMutableArrayRef",
+    "CFDictionaryRef",
+    "CFMutableDictionaryRef",
+    "CFRunLoopRef",
+];
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct ItemIdentifier<N = String> {
     /// Names in Objective-C are global, so this is always enough to uniquely
@@ -55,6 +71,14 @@ impl<N: ToOptionString> ItemIdentifier<N> {
                 file_name = Some("NSGeometry".to_string());
             }
         }
+        if library_name == "CoreFoundation" {
+            if let Some(name) = name.to_option() {
+                if CF_TYPES.contains(&name) {
+                    library_name = "Foundation".to_string();
+                    file_name = None;
+                }
+            }
+        }
 
         Self {
             name,
@@ -115,6 +139,10 @@ impl ItemIdentifier {
         }
     }
 
+    pub fn is_cf_type(&self) -> bool {
+        self.library == "Foundation" && CF_TYPES.contains(&&*self.name)
+    }
+
     pub fn is_nsstring(&self) -> bool {
         self.library == "Foundation" && self.name == "NSString"
     }
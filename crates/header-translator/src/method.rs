@@ -206,6 +206,15 @@ impl MemoryManagement {
                     Self::IdOther
                 }
             }
+        } else if result_type.is_cf_type() {
+            // CoreFoundation objects are returned as raw pointers, and
+            // `CF_RETURNS_RETAINED` / `CF_RETURNS_NOT_RETAINED` only tell the
+            // user whether to wrap them with `CFRetained::new` or
+            // `CFRetained::retain`.
+            if modifiers.consumes_self || modifiers.designated_initializer {
+                error!(?modifiers, "invalid MemoryManagement CF attributes");
+            }
+            Self::Normal
         } else if let MethodModifiers {
             designated_initializer: false,
             // TODO: Maybe we can use this to emit things with lifetime of:
@@ -1484,6 +1484,11 @@ impl Ty {
         )
     }
 
+    /// Whether the type is a pointer to a CoreFoundation object.
+    pub fn is_cf_type(&self) -> bool {
+        matches!(&self.ty, Inner::TypeDef { id } if id.is_cf_type())
+    }
+
     pub fn is_typedef_to(&self, s: &str) -> bool {
         matches!(&self.ty, Inner::TypeDef { id } if id.name == s)
     }
@@ -286,6 +286,7 @@ pub enum Mutability {
     #[default]
     InteriorMutable,
     // MainThreadOnly,
+    ThreadConfined,
 }
 
 impl Mutability {
@@ -309,6 +310,7 @@ impl fmt::Display for Mutability {
                 write!(f, "MutableWithImmutableSuperclass<{}>", superclass.path())
             }
             Self::InteriorMutable => write!(f, "InteriorMutable"),
+            Self::ThreadConfined => write!(f, "ThreadConfined"),
         }
     }
 }
@@ -1263,7 +1265,8 @@ impl fmt::Display for Stmt {
                     Mutability::Immutable
                     | Mutability::Mutable
                     | Mutability::ImmutableWithMutableSubclass(_)
-                    | Mutability::InteriorMutable => id.feature(),
+                    | Mutability::InteriorMutable
+                    | Mutability::ThreadConfined => id.feature(),
                 };
 
                 let (superclass, superclasses_rest) = superclasses.split_at(1);
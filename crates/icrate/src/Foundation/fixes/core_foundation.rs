@@ -0,0 +1,119 @@
+//! CoreFoundation types that are used by Foundation.
+//!
+//! We don't translate CoreFoundation itself yet, so `header-translator` maps
+//! the types referenced from Foundation to the definitions here.
+//!
+//! The pointers are returned as-is from methods and functions, so they should
+//! be wrapped in [`CFRetained`] with [`CFRetained::new`] if the method
+//! follows the "Create Rule" (or is marked `CF_RETURNS_RETAINED`), and with
+//! [`CFRetained::retain`] otherwise.
+//!
+//! [`CFRetained`]: objc2::rc::CFRetained
+//! [`CFRetained::new`]: objc2::rc::CFRetained::new
+//! [`CFRetained::retain`]: objc2::rc::CFRetained::retain
+use crate::common::*;
+#[cfg(feature = "Foundation_NSArray")]
+use crate::Foundation::NSArray;
+#[cfg(feature = "Foundation_NSData")]
+use crate::Foundation::NSData;
+#[cfg(feature = "Foundation_NSDictionary")]
+use crate::Foundation::NSDictionary;
+#[cfg(feature = "Foundation_NSString")]
+use crate::Foundation::NSString;
+use objc2::rc::{CFThreadSafe, CFType, TollFreeBridged};
+
+// On GNUstep, the types are provided by `gnustep-corebase`, which is
+// optional, and hence not linked here.
+#[cfg_attr(feature = "apple", link(name = "CoreFoundation", kind = "framework"))]
+extern "C" {}
+
+macro_rules! cf_type {
+    (
+        $(#[$m:meta])*
+        pub struct $name:ident;
+        $(
+            #[cfg(feature = $feature:literal)]
+            bridged = $bridged:ty;
+        )?
+    ) => {
+        $(#[$m])*
+        #[repr(C)]
+        pub struct $name {
+            _priv: [u8; 0],
+        }
+
+        unsafe impl CFType for $name {}
+
+        $(
+            #[cfg(feature = $feature)]
+            unsafe impl TollFreeBridged for $name {
+                type Bridged = $bridged;
+            }
+        )?
+    };
+}
+
+/// A pointer to any CoreFoundation object.
+pub type CFTypeRef = *const c_void;
+
+cf_type! {
+    /// The opaque type that [`CFStringRef`] points to.
+    ///
+    /// This is toll-free bridged with `NSString`.
+    pub struct __CFString;
+    #[cfg(feature = "Foundation_NSString")]
+    bridged = NSString;
+}
+
+pub type CFStringRef = *const __CFString;
+pub type CFMutableStringRef = *mut __CFString;
+
+cf_type! {
+    /// The opaque type that [`CFDataRef`] points to.
+    ///
+    /// This is toll-free bridged with `NSData`.
+    pub struct __CFData;
+    #[cfg(feature = "Foundation_NSData")]
+    bridged = NSData;
+}
+
+pub type CFDataRef = *const __CFData;
+pub type CFMutableDataRef = *mut __CFData;
+
+cf_type! {
+    /// The opaque type that [`CFArrayRef`] points to.
+    ///
+    /// This is toll-free bridged with `NSArray`.
+    pub struct __CFArray;
+    #[cfg(feature = "Foundation_NSArray")]
+    bridged = NSArray;
+}
+
+pub type CFArrayRef = *const __CFArray;
+pub type CFMutableArrayRef = *mut __CFArray;
+
+cf_type! {
+    /// The opaque type that [`CFDictionaryRef`] points to.
+    ///
+    /// This is toll-free bridged with `NSDictionary`.
+    pub struct __CFDictionary;
+    #[cfg(feature = "Foundation_NSDictionary")]
+    bridged = NSDictionary;
+}
+
+pub type CFDictionaryRef = *const __CFDictionary;
+pub type CFMutableDictionaryRef = *mut __CFDictionary;
+
+cf_type! {
+    /// The opaque type that [`CFRunLoopRef`] points to.
+    ///
+    /// This is not toll-free bridged; use `NSRunLoop::getCFRunLoop` to get
+    /// the run loop that a `NSRunLoop` wraps.
+    pub struct __CFRunLoop;
+}
+
+// SAFETY: Unlike `NSRunLoop`, `CFRunLoop` is documented to be thread-safe:
+// https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/Multithreading/RunLoopManagement/RunLoopManagement.html
+unsafe impl CFThreadSafe for __CFRunLoop {}
+
+pub type CFRunLoopRef = *mut __CFRunLoop;
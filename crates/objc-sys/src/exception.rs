@@ -93,6 +93,24 @@ extern_c! {
     // pub fn objc_set_apple_compatible_objcxx_exceptions(newValue: c_int) -> c_int;
 }
 
+/// Remember that this is nullable!
+#[cfg(any(doc, gnustep))]
+pub type objc_unexpected_exception_handler =
+    Option<unsafe extern "C" fn(exception: *mut objc_object)>;
+
+extern "C" {
+    /// The hook that is called when an exception is not caught, before the
+    /// process is terminated.
+    ///
+    /// This is GNUStep's equivalent of `objc_setUncaughtExceptionHandler`.
+    ///
+    /// See [`objc/hooks.h`].
+    ///
+    /// [`objc/hooks.h`]: https://github.com/gnustep/libobjc2/blob/v2.1/objc/hooks.h
+    #[cfg(any(doc, gnustep))]
+    pub static mut _objc_unexpected_exception: objc_unexpected_exception_handler;
+}
+
 extern "C" {
     /// Call the given function inside an Objective-C `@try/@catch` block.
     ///
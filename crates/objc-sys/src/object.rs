@@ -2,9 +2,9 @@
 use core::ffi::c_void;
 use std::os::raw::c_char;
 
-#[cfg(any(doc, not(objfw)))]
-use crate::objc_ivar;
 use crate::{objc_class, OpaqueData};
+#[cfg(any(doc, not(objfw)))]
+use crate::{objc_ivar, objc_selector, BOOL};
 
 /// An opaque type that represents an object / an instance of a class.
 #[repr(C)]
@@ -70,30 +70,32 @@ extern_c! {
     #[cfg(any(doc, apple))]
     pub fn objc_destructInstance(obj: *mut objc_object) -> *mut c_void;
 
-    // TODO: Unsure if we should expose these; are they useful, and stable?
-    // Defined in objc-abi.h
-    // pub fn objc_getProperty(
-    //     obj: *const objc_object,
-    //     sel: *const objc_selector,
-    //     offset: isize,
-    //     atomic: BOOL,
-    // ) -> *mut c_void;
-    // pub fn objc_setProperty(
-    //     obj: *const objc_object,
-    //     sel: *const objc_selector,
-    //     offset: isize,
-    //     newValue: *const c_void,
-    //     atomic: BOOL,
-    //     shouldCopy: i8,
-    // );
-    // This is generated in setters to struct properties.
-    // pub fn objc_copyStruct(
-    //     dest: *mut c_void,
-    //     src: *const c_void,
-    //     size: isize,
-    //     atomic: BOOL,
-    //     hasStrong: BOOL,
-    // );
+    // Defined in objc-abi.h, these are what the compiler emits in
+    // synthesized property accessors.
+    #[cfg(any(doc, not(objfw)))]
+    pub fn objc_getProperty(
+        obj: *mut objc_object,
+        sel: *const objc_selector,
+        offset: isize,
+        atomic: BOOL,
+    ) -> *mut objc_object;
+    #[cfg(any(doc, not(objfw)))]
+    pub fn objc_setProperty(
+        obj: *mut objc_object,
+        sel: *const objc_selector,
+        offset: isize,
+        newValue: *mut objc_object,
+        atomic: BOOL,
+        shouldCopy: i8,
+    );
+    #[cfg(any(doc, not(objfw)))]
+    pub fn objc_copyStruct(
+        dest: *mut c_void,
+        src: *const c_void,
+        size: isize,
+        atomic: BOOL,
+        hasStrong: BOOL,
+    );
 
     // #[deprecated = "use object_copy instead"]
     // #[cfg(any(doc, all(apple, target_os = "macos")))]
@@ -72,3 +72,33 @@ pub fn __hash_idents(input: TokenStream) -> TokenStream {
     let s = format!("{:016x}", hasher.finish());
     TokenTree::Literal(Literal::string(&s)).into()
 }
+
+/// Creates a hash from the given class name literal and its source code
+/// location, along with the name and version of the crate that is being
+/// compiled.
+///
+/// This is kept separate from `__hash_idents!`, such that the symbol names
+/// created by that do not depend on the crate version.
+///
+/// This hash is not guaranteed to be stable across compiler versions.
+///
+/// Tests are in [`objc2::__macro_helpers`].
+#[proc_macro]
+#[doc(hidden)]
+pub fn __hash_class_name(input: TokenStream) -> TokenStream {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+
+    // Hash the crate name and version (set by Cargo), such that different
+    // versions of the same crate produce different hashes.
+    std::env::var("CARGO_PKG_NAME").ok().hash(&mut hasher);
+    std::env::var("CARGO_PKG_VERSION").ok().hash(&mut hasher);
+
+    for token in input {
+        token.to_string().hash(&mut hasher);
+        // Same hack as in `__hash_idents`
+        format!("{:?}", token.span()).hash(&mut hasher);
+    }
+
+    let s = format!("{:016x}", hasher.finish());
+    TokenTree::Literal(Literal::string(&s)).into()
+}
@@ -3,6 +3,7 @@ use core::ptr;
 
 use crate::declare::__IdReturnValue;
 use crate::rc::{Allocated, Id};
+use crate::runtime::Bool;
 use crate::{ClassType, Message, MessageReceiver};
 
 use super::{CopyOrMutCopy, Init, MaybeUnwrap, New, Other};
@@ -112,6 +113,49 @@ impl<T: Message> MaybeOptionId for Option<Id<T>> {
     }
 }
 
+/// Store the error in the out parameter (if the caller gave one), following
+/// the Cocoa convention of returning `NO` on failure.
+///
+/// The error is autoreleased, since out parameters are `__autoreleasing`.
+#[inline]
+pub fn bool_error_return<E: Message>(
+    result: Result<(), Id<E>>,
+    error: Option<&mut *mut E>,
+) -> Bool {
+    match result {
+        Ok(()) => Bool::YES,
+        Err(err) => {
+            write_error(err, error);
+            Bool::NO
+        }
+    }
+}
+
+/// Store the error in the out parameter (if the caller gave one), following
+/// the Cocoa convention of returning `nil` on failure.
+#[inline]
+pub fn id_error_return<T: ?Sized, E: Message>(
+    result: Result<Id<T>, Id<E>>,
+    error: Option<&mut *mut E>,
+) -> Option<Id<T>> {
+    match result {
+        Ok(obj) => Some(obj),
+        Err(err) => {
+            write_error(err, error);
+            None
+        }
+    }
+}
+
+#[inline]
+fn write_error<E: Message>(err: Id<E>, error: Option<&mut *mut E>) {
+    match error {
+        Some(error) => *error = Id::autorelease_inner(err),
+        // The caller doesn't care about the error.
+        None => drop(err),
+    }
+}
+
 /// Helper for ensuring that `ClassType::Mutability` is implemented correctly
 /// for subclasses.
 pub trait ValidSubclassMutability<T: mutability::Mutability> {}
@@ -128,6 +172,7 @@ where
 }
 impl ValidSubclassMutability<mutability::InteriorMutable> for mutability::Root {}
 impl ValidSubclassMutability<mutability::MainThreadOnly> for mutability::Root {}
+impl ValidSubclassMutability<mutability::ThreadConfined> for mutability::Root {}
 
 // Immutable
 impl ValidSubclassMutability<mutability::Immutable> for mutability::Immutable {}
@@ -159,10 +204,14 @@ impl<IS: ?Sized + ClassType> ValidSubclassMutability<mutability::Mutable>
 // InteriorMutable
 impl ValidSubclassMutability<mutability::InteriorMutable> for mutability::InteriorMutable {}
 impl ValidSubclassMutability<mutability::MainThreadOnly> for mutability::InteriorMutable {}
+impl ValidSubclassMutability<mutability::ThreadConfined> for mutability::InteriorMutable {}
 
 // MainThreadOnly
 impl ValidSubclassMutability<mutability::MainThreadOnly> for mutability::MainThreadOnly {}
 
+// ThreadConfined
+impl ValidSubclassMutability<mutability::ThreadConfined> for mutability::ThreadConfined {}
+
 /// Ensure that:
 /// 1. The type is not a root class (it's superclass implements `ClassType`,
 ///    and it's mutability is not `Root`), and therefore also implements basic
@@ -178,3 +227,77 @@ where
 {
     // Noop
 }
+
+/// Implemented for a type if and only if it is the same as `T`.
+pub trait IsSameType<T: ?Sized> {}
+
+impl<T: ?Sized> IsSameType<T> for T {}
+
+/// Ensure that the type given in `impl DeclaredClass for ...` is the same as
+/// the one in `unsafe impl ClassType for ...`.
+#[inline]
+pub fn assert_declared_class_is_class_type<ClassTypeImpl, DeclaredClassImpl>()
+where
+    ClassTypeImpl: ?Sized,
+    DeclaredClassImpl: ?Sized + IsSameType<ClassTypeImpl>,
+{
+    // Noop
+}
+
+/// Run the body of a method declared in `declare_class!`.
+///
+/// With the `"panic-to-exception"` feature, a panic in the method is caught,
+/// since unwinding a Rust panic into Objective-C frames is undefined
+/// behaviour. If `"unstable-c-unwind"` is also enabled, the method uses the
+/// `"C-unwind"` ABI, and the panic is rethrown as an `NSException` with the
+/// panic message as its reason; otherwise, the process is aborted.
+///
+/// So this must only be used in functions declared with
+/// `__declare_class_method_abi!`.
+#[inline]
+pub fn method_boundary<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "panic-to-exception")]
+    {
+        // Any broken invariants are exposed to Objective-C either way, just
+        // as they would be if the panic unwound through it.
+        match std::panic::catch_unwind(core::panic::AssertUnwindSafe(f)) {
+            Ok(res) => res,
+            #[cfg(feature = "unstable-c-unwind")]
+            Err(payload) => match crate::exception::from_panic(&*payload) {
+                // SAFETY: The exception is thrown from a method with the
+                // `"C-unwind"` ABI, which was called from Objective-C (or is
+                // caught by the caller, if they called the method from Rust).
+                Some(exception) => unsafe { crate::exception::throw(exception) },
+                None => abort_after_panic("in a method declared with `declare_class!`"),
+            },
+            #[cfg(not(feature = "unstable-c-unwind"))]
+            Err(_) => abort_after_panic("in a method declared with `declare_class!`"),
+        }
+    }
+    #[cfg(not(feature = "panic-to-exception"))]
+    {
+        f()
+    }
+}
+
+/// Run the `Drop` implementation of a class declared in `declare_class!`.
+///
+/// With the `"panic-to-exception"` feature, a panic in `dealloc` aborts the
+/// process, since the object would be left in a half-deallocated state if
+/// an exception was thrown.
+#[inline]
+pub fn dealloc_boundary(f: impl FnOnce()) {
+    #[cfg(feature = "panic-to-exception")]
+    if std::panic::catch_unwind(core::panic::AssertUnwindSafe(f)).is_err() {
+        abort_after_panic("while deallocating an object declared with `declare_class!`");
+    }
+    #[cfg(not(feature = "panic-to-exception"))]
+    f()
+}
+
+#[cfg(feature = "panic-to-exception")]
+#[cold]
+fn abort_after_panic(location: &str) -> ! {
+    std::eprintln!("objc2: panicked {location}, aborting");
+    std::process::abort()
+}
@@ -0,0 +1,78 @@
+use alloc::vec::Vec;
+
+use crate::declare::ProtocolBuilder;
+use crate::encode::__unstable::{EncodeArguments, EncodeConvertReturn, EncodeReturn};
+use crate::encode::{Encode, Encoding};
+use crate::rc::Id;
+use crate::runtime::{Bool, Sel};
+use crate::{Message, ProtocolType};
+
+/// The encoding of the error out parameter that is added to methods
+/// returning `Result`.
+const ERROR_OUT_PARAMETER: Encoding = Encoding::Pointer(&Encoding::Object);
+
+/// Return types of `#[method(...)]` in `declare_protocol!`.
+pub trait ProtocolMethodReturn {
+    const ENCODING_RETURN: Encoding;
+    /// Whether the method takes an `NSError **` as its last argument.
+    const HAS_ERROR_PARAMETER: bool;
+}
+
+impl<T: EncodeConvertReturn> ProtocolMethodReturn for T {
+    const ENCODING_RETURN: Encoding = <T::__Inner as EncodeReturn>::ENCODING_RETURN;
+    const HAS_ERROR_PARAMETER: bool = false;
+}
+
+impl<E: Message> ProtocolMethodReturn for Result<(), Id<E>> {
+    const ENCODING_RETURN: Encoding = Bool::ENCODING;
+    const HAS_ERROR_PARAMETER: bool = true;
+}
+
+/// Return types of `#[method_id(...)]` in `declare_protocol!`.
+pub trait ProtocolMethodIdReturn {
+    const ENCODING_RETURN: Encoding = Encoding::Object;
+    /// Whether the method takes an `NSError **` as its last argument.
+    const HAS_ERROR_PARAMETER: bool;
+}
+
+impl<T: ?Sized + Message> ProtocolMethodIdReturn for Id<T> {
+    const HAS_ERROR_PARAMETER: bool = false;
+}
+
+impl<T: ?Sized + Message> ProtocolMethodIdReturn for Option<Id<T>> {
+    const HAS_ERROR_PARAMETER: bool = false;
+}
+
+impl<T: ?Sized + Message, E: Message> ProtocolMethodIdReturn for Result<Id<T>, Id<E>> {
+    const HAS_ERROR_PARAMETER: bool = true;
+}
+
+impl ProtocolBuilder {
+    #[doc(hidden)]
+    pub fn __add_method_description<Args: EncodeArguments>(
+        &mut self,
+        sel: Sel,
+        enc_ret: Encoding,
+        has_error_parameter: bool,
+        required: bool,
+        instance_method: bool,
+    ) {
+        let mut enc_args: Vec<Encoding> = Args::ENCODINGS.to_vec();
+        if has_error_parameter {
+            enc_args.push(ERROR_OUT_PARAMETER);
+        }
+        self.add_method_description_inner(sel, &enc_args, enc_ret, required, instance_method);
+    }
+}
+
+/// Add a protocol that a protocol declared with `declare_protocol!` inherits
+/// from.
+pub fn add_inherited_protocol<P: ?Sized + ProtocolType>(builder: &mut ProtocolBuilder) {
+    let protocol = P::protocol().unwrap_or_else(|| {
+        panic!(
+            "could not find protocol {}, which is required to declare a protocol inheriting from it",
+            P::NAME
+        )
+    });
+    builder.add_protocol(protocol);
+}
@@ -0,0 +1,157 @@
+use alloc::format;
+use alloc::string::String;
+use core::mem::{self, MaybeUninit};
+use core::ptr::{self, NonNull};
+use core::sync::atomic::{AtomicIsize, Ordering};
+
+use crate::declare::ClassBuilder;
+use crate::encode::{Encode, Encoding};
+use crate::runtime::{ivar_offset, AnyObject};
+use crate::DeclaredClass;
+
+/// The hidden instance variable that classes with a single `Ivars` struct
+/// store their state in.
+///
+/// Objects are zero-initialized when allocated, so `initialized` starts out
+/// as `false`, and is only set once `value` has been written.
+#[repr(C)]
+pub(crate) struct IvarsStorage<T> {
+    value: MaybeUninit<T>,
+    initialized: bool,
+}
+
+impl<T> IvarsStorage<T> {
+    /// The instance variable is opaque to Objective-C, so we just describe
+    /// it as an array of bytes.
+    const ENCODING: Encoding = Encoding::Array(mem::size_of::<Self>() as u64, &u8::ENCODING);
+}
+
+/// Marks classes whose instance variables are stored in a single struct
+/// (`DeclaredClass::Ivars`) in a hidden instance variable.
+///
+/// This is implemented by `declare_class!` when the class is declared with
+/// `impl DeclaredClass for T { type Ivars = ...; }`.
+///
+///
+/// # Safety
+///
+/// `__ivars_offset` must return the offset of the instance variable that was
+/// added with `register_ivars::<Self::Ivars>` when the class was created.
+pub unsafe trait StoredIvars: DeclaredClass {
+    fn __ivars_offset() -> isize;
+}
+
+/// Mangle the name of the hidden instance variable with the name of the
+/// class, such that it doesn't conflict with the one on a superclass that
+/// was also declared with `declare_class!`.
+fn ivars_name(class_name: &str) -> String {
+    format!("_objc2_ivars_{class_name}")
+}
+
+/// Add the hidden instance variable that `T` is stored in to the class.
+pub fn register_ivars<T>(builder: &mut ClassBuilder, class_name: &str) {
+    // SAFETY: The encoding is correct for the storage.
+    unsafe {
+        builder.add_ivar_inner::<IvarsStorage<T>>(
+            &ivars_name(class_name),
+            &IvarsStorage::<T>::ENCODING,
+        )
+    }
+}
+
+/// Look up the offset of the hidden instance variable, and cache it in
+/// the given static.
+#[inline]
+pub fn ivars_offset<T: DeclaredClass>(cache: &AtomicIsize) -> isize {
+    let offset = cache.load(Ordering::Relaxed);
+    // The offset can never be zero, since the `isa` pointer is stored there.
+    if offset != 0 {
+        offset
+    } else {
+        ivars_offset_slow::<T>(cache)
+    }
+}
+
+#[cold]
+fn ivars_offset_slow<T: DeclaredClass>(cache: &AtomicIsize) -> isize {
+    let offset = ivar_offset(
+        T::class(),
+        &ivars_name(T::NAME),
+        &IvarsStorage::<T::Ivars>::ENCODING,
+    );
+    cache.store(offset, Ordering::Relaxed);
+    offset
+}
+
+#[inline]
+pub(crate) fn storage<T: StoredIvars>(obj: NonNull<T>) -> NonNull<IvarsStorage<T::Ivars>> {
+    let offset = T::__ivars_offset();
+    // SAFETY: The offset is valid for instances of `T` and its subclasses.
+    unsafe { AnyObject::ivar_at_offset::<IvarsStorage<T::Ivars>>(obj.cast(), offset) }
+}
+
+/// Get a pointer to the (initialized) instance variables of the object.
+///
+///
+/// # Panics
+///
+/// If the instance variables have not been initialized with
+/// `Allocated::set_ivars`.
+#[inline]
+#[track_caller]
+pub(crate) fn ivars_ptr<T: StoredIvars>(obj: NonNull<T>) -> NonNull<T::Ivars> {
+    let storage = storage(obj).as_ptr();
+    // SAFETY: The storage is valid for reads, and `initialized` is only ever
+    // modified while the object is being initialized or deallocated.
+    if !unsafe { ptr::addr_of!((*storage).initialized).read() } {
+        panic!(
+            "tried to access the instance variables of {}, but they were not initialized (use `Allocated::set_ivars` in every `init` method)",
+            T::NAME,
+        );
+    }
+    // SAFETY: The pointer came from a `NonNull`.
+    unsafe { NonNull::new_unchecked(ptr::addr_of_mut!((*storage).value).cast()) }
+}
+
+/// Write the initial value of the instance variables.
+///
+///
+/// # Safety
+///
+/// The object must be an allocated, but not yet initialized, instance of `T`
+/// or one of its subclasses.
+#[inline]
+pub unsafe fn write_stored_ivars<T: StoredIvars>(obj: NonNull<T>, ivars: T::Ivars) {
+    let storage = storage(obj).as_ptr();
+    // SAFETY: The storage is valid for writes, and is not aliased since the
+    // object is not yet initialized.
+    unsafe {
+        ptr::addr_of_mut!((*storage).value).write(MaybeUninit::new(ivars));
+        ptr::addr_of_mut!((*storage).initialized).write(true);
+    }
+}
+
+/// Drop the instance variables, if they were initialized.
+///
+///
+/// # Safety
+///
+/// Must only be called from `dealloc`, after which the instance variables
+/// are never accessed again.
+#[inline]
+pub unsafe fn drop_stored_ivars<T: StoredIvars>(obj: NonNull<T>) {
+    if !mem::needs_drop::<T::Ivars>() {
+        return;
+    }
+    let storage = storage(obj).as_ptr();
+    // SAFETY: The storage is valid, and the object is being deallocated.
+    //
+    // If the object was never initialized, or failed before it called
+    // `set_ivars`, there is nothing to drop.
+    unsafe {
+        if ptr::addr_of!((*storage).initialized).read() {
+            ptr::addr_of_mut!((*storage).initialized).write(false);
+            ptr::drop_in_place(ptr::addr_of_mut!((*storage).value).cast::<T::Ivars>());
+        }
+    }
+}
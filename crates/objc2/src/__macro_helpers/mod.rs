@@ -4,15 +4,15 @@ use core::ptr;
 #[cfg(all(debug_assertions, feature = "verify"))]
 use std::collections::HashSet;
 
-use crate::declare::ClassBuilder;
 use crate::declare::MethodImplementation;
+use crate::declare::{ClassBuilder, InnerIvarType, IvarType};
 use crate::encode::Encode;
 use crate::message::__TupleExtender;
-use crate::rc::{Allocated, Id};
+use crate::rc::{Allocated, Id, PartialInit};
 #[cfg(all(debug_assertions, feature = "verify"))]
 use crate::runtime::MethodDescription;
 use crate::runtime::{AnyClass, AnyObject, AnyProtocol, Sel};
-use crate::{Message, MessageArguments, MessageReceiver};
+use crate::{ClassType, Message, MessageArguments, MessageReceiver};
 
 pub use core::borrow::{Borrow, BorrowMut};
 pub use core::cell::UnsafeCell;
@@ -21,8 +21,10 @@ pub use core::marker::{PhantomData, Sized};
 pub use core::mem::{needs_drop, size_of, ManuallyDrop};
 pub use core::ops::{Deref, DerefMut};
 pub use core::option::Option::{self, None, Some};
-pub use core::primitive::{bool, str, u8};
-pub use core::ptr::drop_in_place;
+pub use core::primitive::{bool, isize, str, u8};
+pub use core::ptr::{drop_in_place, eq as ptr_eq, NonNull};
+pub use core::result::Result;
+pub use core::sync::atomic::AtomicIsize;
 pub use core::{compile_error, concat, panic, stringify};
 // TODO: Use `core::cell::LazyCell`
 pub use std::sync::Once;
@@ -30,13 +32,39 @@ pub use std::sync::Once;
 mod cache;
 mod common_selectors;
 mod declare_class;
+mod declare_protocol;
+mod declared_ivars;
+mod property;
+#[cfg(all(
+    feature = "gnustep-2-0",
+    not(feature = "apple"),
+    not(target_os = "windows"),
+    any(feature = "unstable-static-sel", feature = "unstable-static-class"),
+))]
+mod static_gnustep;
 
 pub use self::cache::{CachedClass, CachedSel};
 pub use self::common_selectors::{alloc_sel, dealloc_sel, init_sel, new_sel};
 pub use self::declare_class::{
-    assert_mutability_matches_superclass_mutability, MaybeOptionId, MessageRecieveId,
-    ValidSubclassMutability,
+    assert_declared_class_is_class_type, assert_mutability_matches_superclass_mutability,
+    bool_error_return, dealloc_boundary, id_error_return, method_boundary, IsSameType,
+    MaybeOptionId, MessageRecieveId, ValidSubclassMutability,
 };
+pub use self::declare_protocol::{
+    add_inherited_protocol, ProtocolMethodIdReturn, ProtocolMethodReturn,
+};
+pub(crate) use self::declared_ivars::ivars_ptr;
+pub use self::declared_ivars::{
+    drop_stored_ivars, ivars_offset, register_ivars, write_stored_ivars, StoredIvars,
+};
+pub use self::property::{register_property, DeclaredProperty, PropertyIvar};
+#[cfg(all(
+    feature = "gnustep-2-0",
+    not(feature = "apple"),
+    not(target_os = "windows"),
+    any(feature = "unstable-static-sel", feature = "unstable-static-class"),
+))]
+pub use self::static_gnustep::{GNUstepLoad, GNUstepSelector, GNUstepSelectorRef, __OBJC_LOAD};
 
 /// Helper for specifying the retain semantics for a given selector family.
 ///
@@ -238,6 +266,176 @@ impl<T: MessageReceiver, U: Message> MsgSendId<T, Id<U>> for Other {
     }
 }
 
+/// Like [`MsgSendId`], but for sending messages to the superclass.
+///
+/// Implemented for the same retain semantics, except for `alloc`. Methods in
+/// the `init` family take a [`PartialInit`], to ensure that the instance
+/// variables have been initialized before the superclass' initializer runs.
+pub trait MsgSendSuperId<T, U> {
+    #[track_caller]
+    unsafe fn send_super_message_id<A: MessageArguments, R: MaybeUnwrap<Input = U>>(
+        obj: T,
+        superclass: &AnyClass,
+        sel: Sel,
+        args: A,
+    ) -> R;
+
+    /// Same as [`MsgSendId::send_message_id_error`], but for the superclass.
+    #[inline]
+    #[track_caller]
+    unsafe fn send_super_message_id_error<A, E>(
+        obj: T,
+        superclass: &AnyClass,
+        sel: Sel,
+        args: A,
+    ) -> Result<U, Id<E>>
+    where
+        *mut *mut E: Encode,
+        A: __TupleExtender<*mut *mut E>,
+        <A as __TupleExtender<*mut *mut E>>::PlusOneArgument: MessageArguments,
+        E: Message,
+        Option<U>: MaybeUnwrap<Input = U>,
+    {
+        let mut err: *mut E = ptr::null_mut();
+        let args = args.add_argument(&mut err);
+        let res: Option<U> = unsafe { Self::send_super_message_id(obj, superclass, sel, args) };
+        if let Some(res) = res {
+            Ok(res)
+        } else {
+            // SAFETY: See `MsgSendId::send_message_id_error`.
+            Err(unsafe { encountered_error(err) })
+        }
+    }
+}
+
+impl<T: MessageReceiver, U: ?Sized + Message> MsgSendSuperId<T, Id<U>> for New {
+    #[inline]
+    unsafe fn send_super_message_id<A: MessageArguments, R: MaybeUnwrap<Input = Id<U>>>(
+        obj: T,
+        superclass: &AnyClass,
+        sel: Sel,
+        args: A,
+    ) -> R {
+        let ptr = obj.__as_raw_receiver();
+        // SAFETY: Checked by caller
+        let obj = unsafe { MessageReceiver::send_super_message(ptr, superclass, sel, args) };
+        // SAFETY: The selector is `new`, so this has +1 retain count
+        let obj = unsafe { Id::new(obj) };
+        R::maybe_unwrap::<Self>(obj, (unsafe { ptr.as_ref() }, sel))
+    }
+}
+
+impl<T: ?Sized + Message> MsgSendSuperId<PartialInit<T>, Id<T>> for Init {
+    #[inline]
+    unsafe fn send_super_message_id<A: MessageArguments, R: MaybeUnwrap<Input = Id<T>>>(
+        obj: PartialInit<T>,
+        superclass: &AnyClass,
+        sel: Sel,
+        args: A,
+    ) -> R {
+        let ptr = PartialInit::into_ptr(obj);
+        // SAFETY: Checked by caller, and the object is non-null.
+        let obj = unsafe { MessageReceiver::send_super_message(ptr, superclass, sel, args) };
+        // SAFETY: The selector is `init`, so this has +1 retain count
+        let obj = unsafe { Id::new(obj) };
+        R::maybe_unwrap::<Self>(obj, (ptr.cast(), sel))
+    }
+}
+
+impl<T: MessageReceiver, U: ?Sized + Message> MsgSendSuperId<T, Id<U>> for CopyOrMutCopy {
+    #[inline]
+    unsafe fn send_super_message_id<A: MessageArguments, R: MaybeUnwrap<Input = Id<U>>>(
+        obj: T,
+        superclass: &AnyClass,
+        sel: Sel,
+        args: A,
+    ) -> R {
+        // SAFETY: Checked by caller
+        let obj = unsafe { MessageReceiver::send_super_message(obj, superclass, sel, args) };
+        // SAFETY: The selector is `copy` or `mutableCopy`, so this has +1
+        // retain count
+        let obj = unsafe { Id::new(obj) };
+        R::maybe_unwrap::<Self>(obj, ())
+    }
+}
+
+impl<T: MessageReceiver, U: Message> MsgSendSuperId<T, Id<U>> for Other {
+    #[inline]
+    unsafe fn send_super_message_id<A: MessageArguments, R: MaybeUnwrap<Input = Id<U>>>(
+        obj: T,
+        superclass: &AnyClass,
+        sel: Sel,
+        args: A,
+    ) -> R {
+        let ptr = obj.__as_raw_receiver();
+        // SAFETY: Checked by caller
+        let obj = unsafe { MessageReceiver::send_super_message(ptr, superclass, sel, args) };
+        // SAFETY: The selector is not `new`, `alloc`, `init`, `copy` nor
+        // `mutableCopy`, so the object must be manually retained.
+        let obj = unsafe { Id::retain_autoreleased(obj) };
+        R::maybe_unwrap::<Self>(obj, (unsafe { ptr.as_ref() }, sel))
+    }
+}
+
+/// Helper for getting the statically known superclass of a receiver in
+/// `msg_send_id![super(obj), ...]`.
+pub trait SuperReceiver {
+    type __Inner: ?Sized + ClassType;
+}
+
+impl<T: MessageReceiver> SuperReceiver for T
+where
+    T::__Inner: ClassType,
+{
+    type __Inner = T::__Inner;
+}
+
+impl<T: ?Sized + ClassType> SuperReceiver for PartialInit<T> {
+    type __Inner = T;
+}
+
+#[inline]
+pub fn static_superclass<R: SuperReceiver>(_obj: &R) -> &'static AnyClass
+where
+    <R::__Inner as ClassType>::Super: ClassType,
+{
+    <R::__Inner as ClassType>::Super::class()
+}
+
+/// The metaclass of the superclass of `T`, used for sending messages to the
+/// superclass' implementation of a class method.
+#[inline]
+pub fn static_super_metaclass<T: ?Sized + ClassType>() -> &'static AnyClass
+where
+    T::Super: ClassType,
+{
+    T::Super::class().metaclass()
+}
+
+/// Write the initial value of an instance variable.
+///
+/// # Safety
+///
+/// The object must be an instance of the class that the instance variable
+/// was declared on (or a subclass), and the instance variable must not have
+/// been initialized already.
+#[inline]
+pub unsafe fn write_ivar<I: IvarType>(
+    obj: NonNull<AnyObject>,
+    value: <I::Type as InnerIvarType>::Output,
+) {
+    // SAFETY: Upheld by caller
+    let offset = unsafe { I::__offset(obj) };
+    // SAFETY: The offset is valid
+    let ptr = unsafe { AnyObject::ivar_at_offset::<I::Type>(obj, offset) };
+    // SAFETY: The inner ivar type has the same layout as the output type.
+    unsafe {
+        ptr.as_ptr()
+            .cast::<<I::Type as InnerIvarType>::Output>()
+            .write(value)
+    };
+}
+
 pub trait MaybeUnwrap {
     type Input;
     #[track_caller]
@@ -609,10 +807,10 @@ mod tests {
     use alloc::string::ToString;
     use core::ptr;
 
-    #[cfg(feature = "objc2-proc-macros")]
-    use crate::__hash_idents;
     use crate::rc::{__RcTestObject, __ThreadTestData};
     use crate::runtime::{AnyObject, NSObject, NSZone};
+    #[cfg(feature = "objc2-proc-macros")]
+    use crate::{__hash_class_name, __hash_idents};
     use crate::{class, msg_send_id, ClassType};
 
     #[test]
@@ -957,6 +1155,13 @@ mod tests {
         assert_ne!(__hash_idents!(abc def ghi), __hash_idents!(abc def ghi));
     }
 
+    #[test]
+    #[cfg(feature = "objc2-proc-macros")]
+    fn hash_class_name() {
+        assert_ne!(__hash_class_name!("abc"), __hash_class_name!("def"));
+        assert_ne!(__hash_class_name!("abc"), __hash_class_name!("abc"));
+    }
+
     #[test]
     #[cfg(feature = "objc2-proc-macros")]
     fn hash_idents_exact_same_ident() {
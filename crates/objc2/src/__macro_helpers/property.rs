@@ -0,0 +1,577 @@
+use alloc::format;
+use alloc::string::String;
+use core::mem::{self, MaybeUninit};
+use core::ptr::{self, NonNull};
+
+use crate::declare::{
+    ClassBuilder, InnerIvarType, IvarBool, IvarDrop, IvarEncode, IvarType, PropertyAttributes,
+    PropertyOwnership,
+};
+use crate::encode::Encode;
+use crate::ffi;
+use crate::rc::{Id, Unretained, WeakId};
+use crate::runtime::__nsstring::nsstring_from_str;
+use crate::runtime::{AnyClass, AnyObject, Bool, NSObject, Sel};
+use crate::{msg_send, sel, Message};
+
+use super::method_boundary;
+
+/// An instance variable that has been declared as a property with
+/// `#[property(...)]` in `declare_class!`.
+///
+///
+/// # Safety
+///
+/// The ivar must be of a type that is valid for the given attributes; in
+/// particular, if the ownership is `copy`, copying the value must return an
+/// object of the same type.
+pub unsafe trait DeclaredProperty: IvarType {
+    /// The name of the property, which is also the name of the getter.
+    const PROPERTY_NAME: &'static str;
+    /// The ownership of the property, or `None` to use the default for the
+    /// type of the instance variable.
+    const OWNERSHIP: Option<PropertyOwnership>;
+    /// Whether the property is `readonly`.
+    const READONLY: bool;
+    /// Whether the property is `atomic`.
+    const ATOMIC: bool;
+}
+
+/// Instance variable types that can back a declared property.
+///
+///
+/// # Safety
+///
+/// `Value` must be the type that the property has in Objective-C, and the
+/// accessors must uphold the memory management semantics given to them.
+pub unsafe trait PropertyIvar: InnerIvarType {
+    /// The type that the getter returns, and the setter takes.
+    type Value: Encode;
+
+    /// The ownership that is used if none is specified.
+    const DEFAULT_OWNERSHIP: PropertyOwnership;
+
+    /// Whether the instance variable can be accessed atomically.
+    const SUPPORTS_ATOMIC: bool = true;
+
+    /// Whether the given ownership can be used with this type.
+    fn supports_ownership(ownership: PropertyOwnership) -> bool {
+        ownership == Self::DEFAULT_OWNERSHIP
+    }
+
+    /// # Safety
+    ///
+    /// `ivar` must point to the instance variable on `obj`, at `offset`.
+    unsafe fn get(
+        obj: NonNull<AnyObject>,
+        cmd: Sel,
+        ivar: NonNull<Self>,
+        offset: isize,
+        atomic: bool,
+    ) -> Self::Value;
+
+    /// # Safety
+    ///
+    /// Same as [`get`][Self::get], and additionally, no references to the
+    /// instance variable may be alive.
+    unsafe fn set(
+        obj: NonNull<AnyObject>,
+        cmd: Sel,
+        ivar: NonNull<Self>,
+        offset: isize,
+        value: Self::Value,
+        ownership: PropertyOwnership,
+        atomic: bool,
+    );
+}
+
+#[inline]
+unsafe fn load<T: Copy>(src: *const T, atomic: bool) -> T {
+    if atomic {
+        let mut value = MaybeUninit::<T>::uninit();
+        // SAFETY: Both pointers are valid for `size_of::<T>()` bytes.
+        unsafe {
+            ffi::objc_copyStruct(
+                value.as_mut_ptr().cast(),
+                src.cast(),
+                mem::size_of::<T>() as isize,
+                Bool::YES.as_raw(),
+                Bool::NO.as_raw(),
+            )
+        };
+        // SAFETY: The value was initialized by `objc_copyStruct`.
+        unsafe { value.assume_init() }
+    } else {
+        // SAFETY: Checked by caller
+        unsafe { ptr::read(src) }
+    }
+}
+
+#[inline]
+unsafe fn store<T: Copy>(dst: *mut T, value: T, atomic: bool) {
+    if atomic {
+        // SAFETY: Both pointers are valid for `size_of::<T>()` bytes.
+        unsafe {
+            ffi::objc_copyStruct(
+                dst.cast(),
+                ptr::addr_of!(value).cast(),
+                mem::size_of::<T>() as isize,
+                Bool::YES.as_raw(),
+                Bool::NO.as_raw(),
+            )
+        };
+    } else {
+        // SAFETY: Checked by caller
+        unsafe { ptr::write(dst, value) };
+    }
+}
+
+// SAFETY: `IvarEncode<T>` is `#[repr(transparent)]` over `T`.
+unsafe impl<T: Encode + Copy> PropertyIvar for IvarEncode<T> {
+    type Value = T;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Assign;
+
+    #[inline]
+    unsafe fn get(_: NonNull<AnyObject>, _: Sel, ivar: NonNull<Self>, _: isize, atomic: bool) -> T {
+        unsafe { load(ivar.as_ptr().cast::<T>(), atomic) }
+    }
+
+    #[inline]
+    unsafe fn set(
+        _: NonNull<AnyObject>,
+        _: Sel,
+        ivar: NonNull<Self>,
+        _: isize,
+        value: T,
+        _: PropertyOwnership,
+        atomic: bool,
+    ) {
+        unsafe { store(ivar.as_ptr().cast::<T>(), value, atomic) }
+    }
+}
+
+// SAFETY: `IvarBool` is `#[repr(transparent)]` over `bool`, and is exposed
+// to Objective-C as `BOOL`.
+unsafe impl PropertyIvar for IvarBool {
+    type Value = Bool;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Assign;
+
+    #[inline]
+    unsafe fn get(
+        _: NonNull<AnyObject>,
+        _: Sel,
+        ivar: NonNull<Self>,
+        _: isize,
+        atomic: bool,
+    ) -> Bool {
+        Bool::new(unsafe { load(ivar.as_ptr().cast::<bool>(), atomic) })
+    }
+
+    #[inline]
+    unsafe fn set(
+        _: NonNull<AnyObject>,
+        _: Sel,
+        ivar: NonNull<Self>,
+        _: isize,
+        value: Bool,
+        _: PropertyOwnership,
+        atomic: bool,
+    ) {
+        unsafe { store(ivar.as_ptr().cast::<bool>(), value.as_bool(), atomic) }
+    }
+}
+
+#[inline]
+unsafe fn get_object<T: Message>(
+    obj: NonNull<AnyObject>,
+    cmd: Sel,
+    offset: isize,
+    atomic: bool,
+) -> *mut T {
+    // SAFETY: The instance variable at `offset` is a (possibly null) strong
+    // object pointer, as `Option<Id<T>>` guarantees the null-pointer
+    // optimization.
+    let ptr = unsafe {
+        ffi::objc_getProperty(
+            obj.as_ptr().cast(),
+            cmd.as_ptr(),
+            offset,
+            Bool::new(atomic).as_raw(),
+        )
+    };
+    ptr.cast()
+}
+
+#[inline]
+unsafe fn set_object<T: Message>(
+    obj: NonNull<AnyObject>,
+    cmd: Sel,
+    offset: isize,
+    value: *mut T,
+    ownership: PropertyOwnership,
+    atomic: bool,
+) {
+    let should_copy = match ownership {
+        PropertyOwnership::Copy => 1,
+        _ => 0,
+    };
+    // SAFETY: Same as in `get_object`; `objc_setProperty` retains (or
+    // copies) the new value, and releases the old value.
+    unsafe {
+        ffi::objc_setProperty(
+            obj.as_ptr().cast(),
+            cmd.as_ptr(),
+            offset,
+            value.cast(),
+            Bool::new(atomic).as_raw(),
+            should_copy,
+        )
+    }
+}
+
+fn strong_or_copy(ownership: PropertyOwnership) -> bool {
+    matches!(
+        ownership,
+        PropertyOwnership::Strong | PropertyOwnership::Copy
+    )
+}
+
+// SAFETY: The getter returns the object at +0, and the setter retains or
+// copies the new object, just like a synthesized Objective-C property.
+unsafe impl<T: Message> PropertyIvar for IvarDrop<Id<T>> {
+    type Value = *mut T;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Strong;
+
+    fn supports_ownership(ownership: PropertyOwnership) -> bool {
+        strong_or_copy(ownership)
+    }
+
+    #[inline]
+    unsafe fn get(
+        obj: NonNull<AnyObject>,
+        cmd: Sel,
+        _: NonNull<Self>,
+        offset: isize,
+        atomic: bool,
+    ) -> *mut T {
+        unsafe { get_object(obj, cmd, offset, atomic) }
+    }
+
+    #[inline]
+    unsafe fn set(
+        obj: NonNull<AnyObject>,
+        cmd: Sel,
+        _: NonNull<Self>,
+        offset: isize,
+        value: *mut T,
+        ownership: PropertyOwnership,
+        atomic: bool,
+    ) {
+        debug_assert!(
+            !value.is_null(),
+            "tried to set non-optional property with selector {cmd} to nil",
+        );
+        if value.is_null() {
+            return;
+        }
+        unsafe { set_object(obj, cmd, offset, value, ownership, atomic) }
+    }
+}
+
+// SAFETY: Same as for `IvarDrop<Id<T>>`.
+unsafe impl<T: Message> PropertyIvar for IvarDrop<Option<Id<T>>> {
+    type Value = *mut T;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Strong;
+
+    fn supports_ownership(ownership: PropertyOwnership) -> bool {
+        strong_or_copy(ownership)
+    }
+
+    #[inline]
+    unsafe fn get(
+        obj: NonNull<AnyObject>,
+        cmd: Sel,
+        _: NonNull<Self>,
+        offset: isize,
+        atomic: bool,
+    ) -> *mut T {
+        unsafe { get_object(obj, cmd, offset, atomic) }
+    }
+
+    #[inline]
+    unsafe fn set(
+        obj: NonNull<AnyObject>,
+        cmd: Sel,
+        _: NonNull<Self>,
+        offset: isize,
+        value: *mut T,
+        ownership: PropertyOwnership,
+        atomic: bool,
+    ) {
+        unsafe { set_object(obj, cmd, offset, value, ownership, atomic) }
+    }
+}
+
+// SAFETY: `IvarDrop<WeakId<T>>` is `#[repr(transparent)]` over
+// `Option<WeakId<T>>`. The getter returns the object autoreleased, and the
+// setter stores a weak reference to the new object.
+unsafe impl<T: Message> PropertyIvar for IvarDrop<WeakId<T>> {
+    type Value = *mut T;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Weak;
+    // Replacing the `WeakId` is not atomic.
+    const SUPPORTS_ATOMIC: bool = false;
+
+    #[inline]
+    unsafe fn get(_: NonNull<AnyObject>, _: Sel, ivar: NonNull<Self>, _: isize, _: bool) -> *mut T {
+        let ivar: &Option<WeakId<T>> = unsafe { ivar.cast().as_ref() };
+        Id::autorelease_return_option(ivar.as_ref().and_then(WeakId::load))
+    }
+
+    #[inline]
+    unsafe fn set(
+        _: NonNull<AnyObject>,
+        _: Sel,
+        ivar: NonNull<Self>,
+        _: isize,
+        value: *mut T,
+        _: PropertyOwnership,
+        _: bool,
+    ) {
+        let ivar: &mut Option<WeakId<T>> = unsafe { ivar.cast().as_mut() };
+        // SAFETY: The object is valid or null.
+        *ivar = Some(unsafe { WeakId::new_inner(value) });
+    }
+}
+
+// SAFETY: `IvarDrop<Unretained<T>>` is `#[repr(transparent)]` over
+// `Option<Unretained<T>>`, and neither accessor retains the object.
+unsafe impl<T: Message> PropertyIvar for IvarDrop<Unretained<T>> {
+    type Value = *mut T;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Assign;
+    // `Unretained` may contain a `WeakId` when verifying.
+    const SUPPORTS_ATOMIC: bool = false;
+
+    #[inline]
+    unsafe fn get(_: NonNull<AnyObject>, _: Sel, ivar: NonNull<Self>, _: isize, _: bool) -> *mut T {
+        let ivar: &Option<Unretained<T>> = unsafe { ivar.cast().as_ref() };
+        ivar.as_ref()
+            .map_or(ptr::null_mut(), |obj| Unretained::as_ptr(obj) as *mut T)
+    }
+
+    #[inline]
+    unsafe fn set(
+        _: NonNull<AnyObject>,
+        cmd: Sel,
+        ivar: NonNull<Self>,
+        _: isize,
+        value: *mut T,
+        _: PropertyOwnership,
+        _: bool,
+    ) {
+        debug_assert!(
+            !value.is_null(),
+            "tried to set non-optional property with selector {cmd} to nil",
+        );
+        // SAFETY: The object is valid or null.
+        if let Some(value) = unsafe { value.as_ref() } {
+            let ivar: &mut Option<Unretained<T>> = unsafe { ivar.cast().as_mut() };
+            *ivar = Some(Unretained::new(value));
+        }
+    }
+}
+
+// SAFETY: Same as for `IvarDrop<Unretained<T>>`.
+unsafe impl<T: Message> PropertyIvar for IvarDrop<Option<Unretained<T>>> {
+    type Value = *mut T;
+    const DEFAULT_OWNERSHIP: PropertyOwnership = PropertyOwnership::Assign;
+    const SUPPORTS_ATOMIC: bool = false;
+
+    #[inline]
+    unsafe fn get(_: NonNull<AnyObject>, _: Sel, ivar: NonNull<Self>, _: isize, _: bool) -> *mut T {
+        let ivar: &Option<Unretained<T>> = unsafe { ivar.cast().as_ref() };
+        ivar.as_ref()
+            .map_or(ptr::null_mut(), |obj| Unretained::as_ptr(obj) as *mut T)
+    }
+
+    #[inline]
+    unsafe fn set(
+        _: NonNull<AnyObject>,
+        _: Sel,
+        ivar: NonNull<Self>,
+        _: isize,
+        value: *mut T,
+        _: PropertyOwnership,
+        _: bool,
+    ) {
+        let ivar: &mut Option<Unretained<T>> = unsafe { ivar.cast().as_mut() };
+        // SAFETY: The object is valid or null.
+        *ivar = unsafe { value.as_ref() }.map(Unretained::new);
+    }
+}
+
+fn ownership<P: DeclaredProperty>() -> PropertyOwnership
+where
+    P::Type: PropertyIvar,
+{
+    P::OWNERSHIP.unwrap_or(<P::Type as PropertyIvar>::DEFAULT_OWNERSHIP)
+}
+
+/// Get the instance variable that backs the property.
+unsafe fn ivar<P: DeclaredProperty>(obj: NonNull<AnyObject>) -> (NonNull<P::Type>, isize) {
+    // SAFETY: The object is an instance of the class that the ivar was
+    // declared on (or a subclass).
+    let offset = unsafe { P::__offset(obj) };
+    // SAFETY: The offset is valid
+    let ivar = unsafe { AnyObject::ivar_at_offset::<P::Type>(obj, offset) };
+    (ivar, offset)
+}
+
+// Uses the same ABI as methods in `declare_class!`, since `method_boundary`
+// may throw an exception.
+crate::__declare_class_method_abi! {
+    (unsafe)
+    fn getter<P: DeclaredProperty>(
+        this: *mut AnyObject,
+        cmd: Sel,
+    ) -> <P::Type as PropertyIvar>::Value
+    where
+        P::Type: PropertyIvar,
+    {
+        method_boundary(|| {
+            // SAFETY: The receiver of a method is never NULL.
+            let obj = unsafe { NonNull::new_unchecked(this) };
+            let (ivar, offset) = unsafe { ivar::<P>(obj) };
+            unsafe { <P::Type as PropertyIvar>::get(obj, cmd, ivar, offset, P::ATOMIC) }
+        })
+    }
+}
+
+crate::__declare_class_method_abi! {
+    (unsafe)
+    fn setter<P: DeclaredProperty>(
+        this: *mut AnyObject,
+        cmd: Sel,
+        value: <P::Type as PropertyIvar>::Value,
+    ) where
+        P::Type: PropertyIvar,
+    {
+        method_boundary(|| {
+            // SAFETY: The receiver of a method is never NULL.
+            let obj = unsafe { NonNull::new_unchecked(this) };
+            let (ivar, offset) = unsafe { ivar::<P>(obj) };
+
+            // Only create the key if someone is actually observing the object.
+            let key = if is_observed(unsafe { obj.as_ref() }) {
+                nsstring_from_str(P::PROPERTY_NAME)
+            } else {
+                None
+            };
+
+            if let Some(key) = &key {
+                let key: &NSObject = key;
+                let _: () = unsafe { msg_send![this, willChangeValueForKey: key] };
+            }
+            unsafe {
+                <P::Type as PropertyIvar>::set(
+                    obj,
+                    cmd,
+                    ivar,
+                    offset,
+                    value,
+                    ownership::<P>(),
+                    P::ATOMIC,
+                )
+            };
+            if let Some(key) = &key {
+                let key: &NSObject = key;
+                let _: () = unsafe { msg_send![this, didChangeValueForKey: key] };
+            }
+        })
+    }
+}
+
+/// Whether any key-value observers are registered on the object.
+fn is_observed(obj: &AnyObject) -> bool {
+    let sel = sel!(observationInfo);
+    if !obj.class().responds_to(sel) {
+        return false;
+    }
+    let info: *mut core::ffi::c_void = unsafe { msg_send![obj, observationInfo] };
+    !info.is_null()
+}
+
+/// The synthesized setter emits key-value observing notifications itself,
+/// so automatic notifications must be disabled to avoid duplicates.
+extern "C" fn no_automatic_notifications(_cls: &AnyClass, _cmd: Sel) -> Bool {
+    Bool::NO
+}
+
+fn capitalize(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => {
+            let mut res = String::with_capacity(name.len());
+            res.push(first.to_ascii_uppercase());
+            res.push_str(chars.as_str());
+            res
+        }
+        None => String::new(),
+    }
+}
+
+/// Register the property, along with its accessor methods.
+pub fn register_property<P: DeclaredProperty>(builder: &mut ClassBuilder)
+where
+    P::Type: PropertyIvar,
+{
+    let name = P::PROPERTY_NAME;
+    let ownership = ownership::<P>();
+    if !<P::Type as PropertyIvar>::supports_ownership(ownership) {
+        panic!(
+            "property {name} cannot be {ownership:?}, the type of its instance variable only supports {:?}",
+            <P::Type as PropertyIvar>::DEFAULT_OWNERSHIP,
+        );
+    }
+    if P::ATOMIC && !<P::Type as PropertyIvar>::SUPPORTS_ATOMIC {
+        panic!("property {name} cannot be atomic, the type of its instance variable does not support it");
+    }
+
+    let attributes = PropertyAttributes::new()
+        .ownership(ownership)
+        .readonly(P::READONLY)
+        .atomic(P::ATOMIC);
+    builder.add_property(
+        name,
+        &<<P::Type as PropertyIvar>::Value as Encode>::ENCODING,
+        Some(P::NAME),
+        attributes,
+    );
+
+    // SAFETY: The getter takes no arguments, and returns the type of the
+    // property.
+    unsafe {
+        builder.add_method(
+            Sel::register(name),
+            getter::<P> as crate::__fn_ptr!((unsafe) (_, _,)),
+        );
+    }
+
+    if !P::READONLY {
+        let capitalized = capitalize(name);
+        // SAFETY: The setter takes the type of the property, and returns
+        // nothing.
+        unsafe {
+            builder.add_method(
+                Sel::register(&format!("set{capitalized}:")),
+                setter::<P> as crate::__fn_ptr!((unsafe) (_, _, _,)),
+            );
+        }
+        // SAFETY: Correct signature.
+        unsafe {
+            builder.add_class_method(
+                Sel::register(&format!("automaticallyNotifiesObserversOf{capitalized}")),
+                no_automatic_notifications as extern "C" fn(_, _) -> _,
+            );
+        }
+    }
+}
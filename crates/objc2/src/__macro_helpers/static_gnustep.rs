@@ -0,0 +1,197 @@
+//! Support for `unstable-static-sel` and `unstable-static-class` on the
+//! GNUstep 2.x (libobjc2 v2) ABI.
+//!
+//! On this ABI, selectors are emitted as `{ name, types }` structs in the
+//! `__objc_selectors` section, and the address of such a struct _is_ the
+//! selector. The runtime registers them (and rewrites their contents) when
+//! `__objc_load` is called with the bounds of the Objective-C sections in
+//! the image, which Clang does from an `.init_array` function in every
+//! object file (deduplicated with COMDATs).
+//!
+//! We can't emit COMDATs from Rust, so instead we emit the load function
+//! once here, and let the statics emitted by the macros reference it, so
+//! that it is always linked when they are. This means that Objective-C code
+//! compiled by Clang must not be linked into the same image, since the
+//! sections would then be loaded twice. We can't detect that in general,
+//! but we can forbid the `"exception"` feature, which links `objc-sys`'
+//! `exception.m`.
+//!
+//! Class references don't need registering; we use the `._OBJC_REF_CLASS_`
+//! symbol that Clang emits alongside the class' implementation, which the
+//! runtime keeps up to date.
+//!
+//! See `CGObjCGNUstep2` in Clang's `lib/CodeGen/CGObjCGNU.cpp`.
+use core::cell::UnsafeCell;
+use core::ptr;
+
+#[cfg(feature = "exception")]
+compile_error!(concat!(
+    "`unstable-static-sel` and `unstable-static-class` cannot be used together ",
+    "with the `exception` feature on GNUStep, since `exception.m` would load ",
+    "the image's Objective-C sections a second time",
+));
+
+use crate::ffi;
+use crate::runtime::Sel;
+
+/// A selector in the `__objc_selectors` section.
+///
+/// The runtime writes to this when registering it, so it must be placed in
+/// a writable section, which `UnsafeCell` ensures.
+#[repr(C)]
+pub struct GNUstepSelector {
+    name: UnsafeCell<*const u8>,
+    types: UnsafeCell<*const u8>,
+}
+
+// SAFETY: The selector is only modified by the runtime while loading the
+// image, before any Rust code can access it.
+unsafe impl Sync for GNUstepSelector {}
+
+impl GNUstepSelector {
+    /// Create an untyped selector with the given NUL-terminated name.
+    pub const fn new(name: *const u8) -> Self {
+        Self {
+            name: UnsafeCell::new(name),
+            types: UnsafeCell::new(ptr::null()),
+        }
+    }
+}
+
+/// Gives static selectors on GNUstep the same interface as the selector
+/// references on Apple (`REF.get()`).
+///
+/// Since the selector is just the address of the `GNUstepSelector`, it is
+/// known at link time, and does not need to be loaded from a mutable
+/// static.
+pub struct GNUstepSelectorRef(Sel);
+
+impl GNUstepSelectorRef {
+    pub const fn new(sel: &'static GNUstepSelector) -> Self {
+        let ptr: *const GNUstepSelector = sel;
+        // SAFETY: The selector is registered by `__objc_load` before `main`.
+        Self(unsafe { Sel::__internal_from_ptr(ptr.cast::<ffi::objc_selector>()) })
+    }
+
+    #[inline]
+    pub const fn get(&self) -> *const Sel {
+        &self.0
+    }
+}
+
+/// A zeroed entry in one of the Objective-C sections.
+///
+/// Clang emits one of these in each section, to make sure that the sections
+/// (and thus their `__start_` and `__stop_` symbols) always exist. The
+/// runtime skips entries whose first field is NULL.
+#[repr(transparent)]
+struct NullEntry<T>(UnsafeCell<T>);
+
+// SAFETY: The entries are never accessed from Rust.
+unsafe impl<T> Sync for NullEntry<T> {}
+
+/// `NSConstantString`-compatible layout, used by the
+/// `__objc_constant_string` section.
+#[repr(C)]
+struct NullConstantString {
+    isa: *const u8,
+    flags: u32,
+    length: u32,
+    size: u32,
+    hash: u32,
+    data: *const u8,
+}
+
+/// The argument to `__objc_load`.
+#[repr(C)]
+struct ObjcInit {
+    version: u64,
+    sections: [[*const u8; 2]; 8],
+}
+
+#[repr(transparent)]
+struct InitCell(UnsafeCell<ObjcInit>);
+
+// SAFETY: Only accessed by the runtime, which synchronizes loading.
+unsafe impl Sync for InitCell {}
+
+extern "C" {
+    fn __objc_load(init: *mut ObjcInit);
+}
+
+macro_rules! sections {
+    ($(
+        $section:literal, $start:ident, $stop:ident,
+        $null:ident: $null_ty:ty = $null_value:expr;
+    )*) => {
+        extern "C" {
+            $(
+                #[link_name = concat!("__start_", $section)]
+                static $start: u8;
+                #[link_name = concat!("__stop_", $section)]
+                static $stop: u8;
+            )*
+        }
+
+        $(
+            #[link_section = $section]
+            #[used]
+            static $null: NullEntry<$null_ty> = NullEntry(UnsafeCell::new($null_value));
+        )*
+
+        static INIT: InitCell = InitCell(UnsafeCell::new(ObjcInit {
+            version: 0,
+            sections: [$(
+                // SAFETY: We only take the address of the symbols.
+                #[allow(unused_unsafe)]
+                unsafe { [ptr::addr_of!($start), ptr::addr_of!($stop)] },
+            )*],
+        }));
+    };
+}
+
+const NULL: *const u8 = ptr::null();
+
+// The order of the sections is significant, it must match `struct
+// objc_init` in libobjc2's `loader.c`.
+sections! {
+    "__objc_selectors", SEL_START, SEL_STOP,
+    NULL_SELECTOR: [*const u8; 2] = [NULL; 2];
+    "__objc_classes", CLS_START, CLS_STOP,
+    NULL_CLASS: [*const u8; 1] = [NULL; 1];
+    "__objc_class_refs", CLS_REF_START, CLS_REF_STOP,
+    NULL_CLASS_REF: [*const u8; 2] = [NULL; 2];
+    "__objc_cats", CAT_START, CAT_STOP,
+    NULL_CATEGORY: [*const u8; 7] = [NULL; 7];
+    "__objc_protocols", PROTO_START, PROTO_STOP,
+    NULL_PROTOCOL: [*const u8; 11] = [NULL; 11];
+    "__objc_protocol_refs", PROTO_REF_START, PROTO_REF_STOP,
+    NULL_PROTOCOL_REF: [*const u8; 1] = [NULL; 1];
+    "__objc_class_aliases", ALIAS_START, ALIAS_STOP,
+    NULL_CLASS_ALIAS: [*const u8; 2] = [NULL; 2];
+    "__objc_constant_string", STRING_START, STRING_STOP,
+    NULL_CONSTANT_STRING: NullConstantString = NullConstantString {
+        isa: NULL,
+        flags: 0,
+        length: 0,
+        size: 0,
+        hash: 0,
+        data: NULL,
+    };
+}
+
+extern "C" fn load() {
+    // SAFETY: The init struct contains the bounds of the sections in this
+    // image, and is only passed to the runtime once.
+    unsafe { __objc_load(INIT.0.get()) }
+}
+
+/// The function that registers the image's selectors with the runtime.
+///
+/// Statics emitted by `sel!` and `class!` reference this, to make sure that
+/// it is linked into the final binary.
+pub type GNUstepLoad = extern "C" fn();
+
+#[link_section = ".init_array"]
+#[used]
+pub static __OBJC_LOAD: GNUstepLoad = load;
@@ -0,0 +1,183 @@
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::mem::MaybeUninit;
+use std::sync::Once;
+
+/// Lazily initialized class-level state.
+///
+/// Objective-C doesn't have class variables; instead, a `static` variable in
+/// the class' implementation file is used, which is usually initialized in
+/// the `+initialize` method. This type is the Rust equivalent of that, and
+/// is intended to be put in a `static` next to a class declared with
+/// [`declare_class!`], where it can be used from the class' methods.
+///
+/// The value is created by the given function the first time it is
+/// accessed, which is guaranteed to happen only once, even if multiple
+/// threads access it at the same time. To initialize it eagerly when the
+/// class is first used, access it in an `#[initialize]` hook.
+///
+/// Note that just like in Objective-C, the state is shared with any
+/// subclasses of the class.
+///
+/// [`declare_class!`]: crate::declare_class
+///
+///
+/// # Examples
+///
+/// Count the number of instances that has been created of a class.
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// use objc2::declare::ClassState;
+/// use objc2::rc::{Allocated, Id};
+/// use objc2::runtime::NSObject;
+/// use objc2::{declare_class, msg_send_id, mutability, ClassType};
+///
+/// static INSTANCES: ClassState<AtomicUsize> = ClassState::new(|| AtomicUsize::new(0));
+///
+/// declare_class!(
+///     struct Counted;
+///
+///     unsafe impl ClassType for Counted {
+///         type Super = NSObject;
+///         type Mutability = mutability::InteriorMutable;
+///         const NAME: &'static str = "ClassStateExampleCounted";
+///     }
+///
+///     unsafe impl Counted {
+///         #[method_id(init)]
+///         fn init(this: Allocated<Self>) -> Option<Id<Self>> {
+///             INSTANCES.get().fetch_add(1, Ordering::Relaxed);
+///             let this = this.set_ivars(());
+///             unsafe { msg_send_id![super(this), init] }
+///         }
+///     }
+/// );
+///
+/// let _obj1: Id<Counted> = unsafe { msg_send_id![Counted::class(), new] };
+/// let _obj2: Id<Counted> = unsafe { msg_send_id![Counted::class(), new] };
+/// assert_eq!(INSTANCES.get().load(Ordering::Relaxed), 2);
+/// ```
+pub struct ClassState<T, F = fn() -> T> {
+    once: Once,
+    init: F,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+// SAFETY: The value is only written once, while synchronized by `Once`, and
+// can then be accessed by reference from any thread (which requires `Sync`).
+// It may be created on one thread and dropped on another (which requires
+// `Send`). The initializer is called by reference from any thread (which
+// requires `Sync`).
+unsafe impl<T: Send + Sync, F: Sync> Sync for ClassState<T, F> {}
+
+impl<T, F> ClassState<T, F> {
+    /// Create new class-level state, which will be initialized with the
+    /// given function on first access.
+    #[inline]
+    pub const fn new(init: F) -> Self {
+        Self {
+            once: Once::new(),
+            init,
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Get a reference to the state, if it has been initialized.
+    #[inline]
+    pub fn try_get(&self) -> Option<&T> {
+        if self.once.is_completed() {
+            // SAFETY: The value has been initialized, and is never mutated
+            // again.
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, F: Fn() -> T> ClassState<T, F> {
+    /// Get a reference to the state, initializing it if necessary.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// If the initializer panics, the panic is propagated, and the state is
+    /// poisoned, which means that future accesses will panic as well.
+    #[inline]
+    pub fn get(&self) -> &T {
+        self.once.call_once(|| {
+            let value = (self.init)();
+            // SAFETY: We have exclusive access to the value, since this is
+            // only run once, and no references to it have been handed out
+            // yet.
+            unsafe { (*self.value.get()).write(value) };
+        });
+        // SAFETY: `call_once` has completed, so the value is initialized.
+        unsafe { (*self.value.get()).assume_init_ref() }
+    }
+}
+
+impl<T, F> Drop for ClassState<T, F> {
+    fn drop(&mut self) {
+        if self.once.is_completed() {
+            // SAFETY: The value is initialized, and we have exclusive access.
+            unsafe { self.value.get_mut().assume_init_drop() };
+        }
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for ClassState<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ClassState");
+        match self.try_get() {
+            Some(value) => debug.field("value", value),
+            None => debug.field("value", &format_args!("<uninit>")),
+        };
+        debug.finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::format;
+    use alloc::rc::Rc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_lazy_init() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static STATE: ClassState<usize> = ClassState::new(|| {
+            CALLS.fetch_add(1, Ordering::Relaxed);
+            42
+        });
+
+        assert_eq!(STATE.try_get(), None);
+        assert_eq!(*STATE.get(), 42);
+        assert_eq!(*STATE.get(), 42);
+        assert_eq!(STATE.try_get(), Some(&42));
+        assert_eq!(CALLS.load(Ordering::Relaxed), 1);
+    }
+
+    #[test]
+    fn test_debug() {
+        let state: ClassState<i32> = ClassState::new(|| 3);
+        assert_eq!(format!("{state:?}"), "ClassState { value: <uninit>, .. }");
+        state.get();
+        assert_eq!(format!("{state:?}"), "ClassState { value: 3, .. }");
+    }
+
+    #[test]
+    fn test_drop() {
+        let rc = Rc::new(());
+        let rc_clone = rc.clone();
+        let state = ClassState::new(move || rc_clone.clone());
+        state.get();
+        assert_eq!(Rc::strong_count(&rc), 3);
+        drop(state);
+        assert_eq!(Rc::strong_count(&rc), 1);
+    }
+}
@@ -1,11 +1,24 @@
 #![deny(deprecated, unreachable_code)]
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
 use core::ptr;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::ffi::{CStr, CString};
 
-use crate::declare::IvarEncode;
+use crate::declare::{ClassState, IvarBool, IvarDrop, IvarEncode};
+use crate::encode::Encode;
+use crate::ffi;
 use crate::mutability::{Immutable, Mutable};
-use crate::rc::Id;
-use crate::runtime::NSObject;
-use crate::{declare_class, extern_methods, sel, ClassType};
+use crate::rc::{__RcTestObject, __ThreadTestData, autoreleasepool, Allocated, Id, WeakId};
+#[cfg(feature = "malloc")]
+use crate::runtime::MethodDescription;
+use crate::runtime::{AnyClass, AnyProtocol, Bool, NSObject, NSObjectProtocol, ProtocolObject};
+use crate::{
+    declare_class, declare_protocol, extern_methods, msg_send, msg_send_id, sel, ClassType,
+    DeclaredClass, ProtocolType,
+};
 
 // Test that adding the `deprecated` attribute does not mean that warnings
 // when using the method internally are output.
@@ -464,17 +477,54 @@ declare_class!(
     }
 
     unsafe impl OutParam {
-        #[method(unsupported1:)]
-        fn _unsupported1(_param: &mut Id<Self>) {}
+        #[method(replace:)]
+        fn _replace(param: &mut Id<__RcTestObject>) {
+            *param = __RcTestObject::new();
+        }
+
+        #[method(replaceIfSome:)]
+        fn _replace_if_some(param: Option<&mut Id<__RcTestObject>>) {
+            if let Some(param) = param {
+                *param = __RcTestObject::new();
+            }
+        }
+
+        #[method(take:)]
+        fn _take(param: &mut Option<Id<__RcTestObject>>) {
+            let _ = param.take();
+        }
 
-        #[method(unsupported2:)]
-        fn _unsupported2(_param: Option<&mut Id<Self>>) {}
+        #[method(setIfSome:)]
+        fn _set_if_some(param: Option<&mut Option<Id<__RcTestObject>>>) {
+            if let Some(param) = param {
+                *param = Some(__RcTestObject::new());
+            }
+        }
+
+        #[method(keep:)]
+        fn _keep(mut param: &mut Id<__RcTestObject>) {
+            let _ = &mut param;
+        }
 
-        #[method(unsupported3:)]
-        fn _unsupported3(_param: &mut Option<Id<Self>>) {}
+        #[method(succeed:error:)]
+        fn _succeed(should_succeed: bool) -> Result<(), Id<__RcTestObject>> {
+            if should_succeed {
+                Ok(())
+            } else {
+                Err(__RcTestObject::new())
+            }
+        }
 
-        #[method(unsupported4:)]
-        fn _unsupported4(_param: Option<&mut Option<Id<Self>>>) {}
+        #[method_id(newAndSucceed:error:)]
+        fn _new_and_succeed(
+            should_succeed: bool,
+        ) -> Result<Id<__RcTestObject>, Id<__RcTestObject>> {
+            if should_succeed {
+                Ok(__RcTestObject::new())
+            } else {
+                Err(__RcTestObject::new())
+            }
+        }
     }
 );
 
@@ -484,46 +534,1163 @@ mod out_param {
 
     extern_methods!(
         unsafe impl OutParam {
-            #[method_id(new)]
-            fn new() -> Id<Self>;
+            #[method(replace:)]
+            fn replace(param: &mut Id<__RcTestObject>);
 
-            #[method(unsupported1:)]
-            fn unsupported1(_param: &mut Id<Self>);
+            #[method(replaceIfSome:)]
+            fn replace_if_some(param: Option<&mut Id<__RcTestObject>>);
 
-            #[method(unsupported2:)]
-            fn unsupported2(_param: Option<&mut Id<Self>>);
+            #[method(take:)]
+            fn take(param: &mut Option<Id<__RcTestObject>>);
 
-            #[method(unsupported3:)]
-            fn unsupported3(_param: &mut Option<Id<Self>>);
+            #[method(setIfSome:)]
+            fn set_if_some(param: Option<&mut Option<Id<__RcTestObject>>>);
 
-            #[method(unsupported4:)]
-            fn unsupported4(_param: Option<&mut Option<Id<Self>>>);
+            #[method(keep:)]
+            fn keep(param: &mut Id<__RcTestObject>);
         }
     );
 
     #[test]
-    #[should_panic = "`&mut Id<_>` is not supported in `declare_class!` yet"]
-    fn out_param1() {
-        let mut param = OutParam::new();
-        OutParam::unsupported1(&mut param);
+    fn out_param_replace() {
+        let mut param = __RcTestObject::new();
+        let old = Id::as_ptr(&param);
+        let mut expected = __ThreadTestData::current();
+
+        autoreleasepool(|_| {
+            OutParam::replace(&mut param);
+            // The method retains the old value, which is released when it
+            // is replaced, and autoreleases the new value, which we then
+            // retain, before releasing the old value.
+            expected.retain += 2;
+            expected.release += 2;
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+            expected.dealloc += 1;
+            expected.assert_current();
+        });
+        expected.release += 1;
+        expected.assert_current();
+        assert_ne!(Id::as_ptr(&param), old);
+
+        drop(param);
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
     }
 
     #[test]
-    #[should_panic = "`Option<&mut Id<_>>` is not supported in `declare_class!` yet"]
-    fn out_param2() {
-        OutParam::unsupported2(None);
+    fn out_param_keep() {
+        let mut param = __RcTestObject::new();
+        let old = Id::as_ptr(&param);
+        let mut expected = __ThreadTestData::current();
+
+        autoreleasepool(|_| {
+            OutParam::keep(&mut param);
+            expected.retain += 2;
+            expected.release += 1;
+            expected.autorelease += 1;
+            expected.assert_current();
+        });
+        expected.release += 1;
+        expected.assert_current();
+        assert_eq!(Id::as_ptr(&param), old);
     }
 
     #[test]
-    #[should_panic = "`&mut Option<Id<_>>` is not supported in `declare_class!` yet"]
-    fn out_param3() {
-        let mut param = Some(OutParam::new());
-        OutParam::unsupported3(&mut param);
+    fn out_param_optional() {
+        let mut expected = __ThreadTestData::current();
+
+        OutParam::replace_if_some(None);
+        OutParam::set_if_some(None);
+        expected.assert_current();
+
+        let mut param = Some(__RcTestObject::new());
+        expected.alloc += 1;
+        expected.init += 1;
+        autoreleasepool(|_| {
+            OutParam::take(&mut param);
+            expected.retain += 1;
+            expected.release += 2;
+            expected.dealloc += 1;
+            expected.assert_current();
+        });
+        assert!(param.is_none());
+
+        autoreleasepool(|_| {
+            OutParam::set_if_some(Some(&mut param));
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+            expected.retain += 1;
+            expected.assert_current();
+        });
+        expected.release += 1;
+        expected.assert_current();
+        assert!(param.is_some());
     }
 
     #[test]
-    #[should_panic = "`Option<&mut Option<Id<_>>>` is not supported in `declare_class!` yet"]
-    fn out_param4() {
-        OutParam::unsupported4(None);
+    fn result_bool() {
+        let cls = OutParam::class();
+        let mut expected = __ThreadTestData::current();
+
+        let res: Result<(), Id<__RcTestObject>> =
+            unsafe { msg_send![cls, succeed: true, error: _] };
+        assert!(res.is_ok());
+        expected.assert_current();
+
+        autoreleasepool(|_| {
+            let res: Result<(), Id<__RcTestObject>> =
+                unsafe { msg_send![cls, succeed: false, error: _] };
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+            expected.retain += 1;
+            expected.assert_current();
+            drop(res.unwrap_err());
+            expected.release += 1;
+            expected.assert_current();
+        });
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+
+        // Without an out parameter, the error is just released.
+        let did_succeed: bool = unsafe {
+            msg_send![cls, succeed: false, error: ptr::null_mut::<*mut __RcTestObject>()]
+        };
+        assert!(!did_succeed);
+        expected.alloc += 1;
+        expected.init += 1;
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+    }
+
+    #[test]
+    fn result_id() {
+        let cls = OutParam::class();
+        let mut expected = __ThreadTestData::current();
+
+        let res: Result<Id<__RcTestObject>, Id<__RcTestObject>> =
+            unsafe { msg_send_id![cls, newAndSucceed: true, error: _] };
+        expected.alloc += 1;
+        expected.init += 1;
+        expected.assert_current();
+        drop(res.unwrap());
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+
+        autoreleasepool(|_| {
+            let res: Result<Id<__RcTestObject>, Id<__RcTestObject>> =
+                unsafe { msg_send_id![cls, newAndSucceed: false, error: _] };
+            expected.alloc += 1;
+            expected.init += 1;
+            expected.autorelease += 1;
+            expected.retain += 1;
+            expected.assert_current();
+            drop(res.unwrap_err());
+            expected.release += 1;
+            expected.assert_current();
+        });
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+    }
+}
+
+declare_class!(
+    struct DeclareClassProperties {
+        #[property(count)]
+        count: IvarEncode<i32, "_count">,
+        #[property(atomicCount, atomic)]
+        atomic_count: IvarEncode<usize, "_atomicCount">,
+        #[property(enabled, readonly)]
+        enabled: IvarBool<"_enabled">,
+        #[property(object)]
+        object: IvarDrop<Option<Id<__RcTestObject>>, "_object">,
+        #[property(copiedObject, copy)]
+        copied_object: IvarDrop<Option<Id<__RcTestObject>>, "_copiedObject">,
+        #[property(weakObject, weak)]
+        weak_object: IvarDrop<WeakId<__RcTestObject>, "_weakObject">,
+    }
+
+    mod ivars_properties;
+
+    unsafe impl ClassType for DeclareClassProperties {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassProperties";
+    }
+);
+
+extern_methods!(
+    unsafe impl DeclareClassProperties {
+        #[method_id(new)]
+        fn new() -> Id<Self>;
+    }
+);
+
+fn property_attributes(cls: &AnyClass, name: &str) -> Option<String> {
+    let name = CString::new(name).unwrap();
+    let property = unsafe { ffi::class_getProperty(cls.as_ptr(), name.as_ptr()) };
+    if property.is_null() {
+        return None;
+    }
+    let attributes = unsafe { CStr::from_ptr(ffi::property_getAttributes(property)) };
+    Some(attributes.to_str().unwrap().to_string())
+}
+
+#[test]
+fn test_property_attributes() {
+    let cls = DeclareClassProperties::class();
+
+    assert_eq!(
+        property_attributes(cls, "count").as_deref(),
+        Some("Ti,N,V_count"),
+    );
+    assert_eq!(
+        property_attributes(cls, "object").as_deref(),
+        Some("T@,&,N,V_object"),
+    );
+    assert_eq!(
+        property_attributes(cls, "copiedObject").as_deref(),
+        Some("T@,C,N,V_copiedObject"),
+    );
+    assert_eq!(
+        property_attributes(cls, "weakObject").as_deref(),
+        Some("T@,W,N,V_weakObject"),
+    );
+    let enabled = property_attributes(cls, "enabled").unwrap();
+    assert!(enabled.contains(",R,"), "{enabled}");
+    let atomic_count = property_attributes(cls, "atomicCount").unwrap();
+    assert!(!atomic_count.contains(",N,"), "{atomic_count}");
+    assert_eq!(property_attributes(cls, "_count"), None);
+
+    assert!(cls.instance_method(sel!(count)).is_some());
+    assert!(cls.instance_method(sel!(setCount:)).is_some());
+    assert!(cls.instance_method(sel!(enabled)).is_some());
+    assert!(cls.instance_method(sel!(setEnabled:)).is_none());
+}
+
+#[test]
+fn test_property_accessors() {
+    let obj = DeclareClassProperties::new();
+
+    let _: () = unsafe { msg_send![&obj, setCount: 42i32] };
+    assert_eq!(*obj.count, 42);
+    let count: i32 = unsafe { msg_send![&obj, count] };
+    assert_eq!(count, 42);
+
+    let _: () = unsafe { msg_send![&obj, setAtomicCount: 7usize] };
+    let atomic_count: usize = unsafe { msg_send![&obj, atomicCount] };
+    assert_eq!(atomic_count, 7);
+
+    let enabled: bool = unsafe { msg_send![&obj, enabled] };
+    assert!(!enabled);
+}
+
+#[test]
+fn test_property_strong_and_copy() {
+    let obj = DeclareClassProperties::new();
+    let inner = __RcTestObject::new();
+    let mut expected = __ThreadTestData::current();
+
+    let _: () = unsafe { msg_send![&obj, setObject: &*inner] };
+    expected.retain += 1;
+    expected.assert_current();
+
+    let ptr: *mut __RcTestObject = unsafe { msg_send![&obj, object] };
+    assert_eq!(ptr, Id::as_ptr(&inner) as *mut _);
+    assert!(obj.object.is_some());
+
+    let _: () = unsafe { msg_send![&obj, setObject: ptr::null_mut::<__RcTestObject>()] };
+    expected.release += 1;
+    expected.assert_current();
+    assert!(obj.object.is_none());
+
+    let copies = __ThreadTestData::current().copy;
+    let _: () = unsafe { msg_send![&obj, setCopiedObject: &*inner] };
+    assert_eq!(__ThreadTestData::current().copy, copies + 1);
+    let ptr: *mut __RcTestObject = unsafe { msg_send![&obj, copiedObject] };
+    assert!(!ptr.is_null());
+    assert_ne!(ptr, Id::as_ptr(&inner) as *mut _);
+}
+
+#[test]
+fn test_property_weak() {
+    let obj = DeclareClassProperties::new();
+
+    autoreleasepool(|_| {
+        let ptr: *mut __RcTestObject = unsafe { msg_send![&obj, weakObject] };
+        assert!(ptr.is_null());
+    });
+
+    let inner = __RcTestObject::new();
+    let _: () = unsafe { msg_send![&obj, setWeakObject: &*inner] };
+    autoreleasepool(|_| {
+        let ptr: *mut __RcTestObject = unsafe { msg_send![&obj, weakObject] };
+        assert_eq!(ptr, Id::as_ptr(&inner) as *mut _);
+    });
+
+    drop(inner);
+    autoreleasepool(|_| {
+        let ptr: *mut __RcTestObject = unsafe { msg_send![&obj, weakObject] };
+        assert!(ptr.is_null());
+    });
+}
+
+declare_class!(
+    struct DeclareClassIvarInit {
+        number: IvarEncode<i32, "_number">,
+        flag: IvarBool<"_flag">,
+        #[property(object)]
+        object: IvarDrop<Id<__RcTestObject>, "_object">,
+        optional: IvarDrop<Option<Id<__RcTestObject>>, "_optional">,
+    }
+
+    mod ivars_init;
+
+    unsafe impl ClassType for DeclareClassIvarInit {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassIvarInit";
+    }
+
+    unsafe impl DeclareClassIvarInit {
+        #[method_id(initWithObject:)]
+        fn init_with_object(this: Allocated<Self>, object: &__RcTestObject) -> Option<Id<Self>> {
+            let this = this.set_ivars(ivars_init::Ivars {
+                number: 42,
+                flag: true,
+                object: object.retain(),
+                optional: None,
+            });
+            unsafe { msg_send_id![super(this), init] }
+        }
+
+        #[method_id(initFailingWithObject:)]
+        fn init_failing(this: Allocated<Self>, object: &__RcTestObject) -> Option<Id<Self>> {
+            let _this = this.set_ivars(ivars_init::Ivars {
+                number: 42,
+                flag: true,
+                object: object.retain(),
+                optional: Some(object.retain()),
+            });
+            None
+        }
+    }
+);
+
+extern_methods!(
+    unsafe impl DeclareClassIvarInit {
+        #[method_id(initWithObject:)]
+        fn new_with_object(this: Option<Allocated<Self>>, object: &__RcTestObject) -> Id<Self>;
+
+        #[method_id(initFailingWithObject:)]
+        fn new_failing(
+            this: Option<Allocated<Self>>,
+            object: &__RcTestObject,
+        ) -> Option<Id<Self>>;
+    }
+);
+
+#[test]
+fn test_set_ivars() {
+    let object = __RcTestObject::new();
+    let mut expected = __ThreadTestData::current();
+
+    let obj = DeclareClassIvarInit::new_with_object(DeclareClassIvarInit::alloc(), &object);
+    expected.retain += 1;
+    expected.assert_current();
+
+    assert_eq!(*obj.number, 42);
+    assert!(*obj.flag);
+    assert_eq!(Id::as_ptr(&obj.object), Id::as_ptr(&object));
+    assert!(obj.optional.is_none());
+
+    drop(obj);
+    expected.release += 1;
+    expected.assert_current();
+}
+
+#[test]
+fn test_set_ivars_dropped_on_failure() {
+    let object = __RcTestObject::new();
+    let mut expected = __ThreadTestData::current();
+
+    let obj = DeclareClassIvarInit::new_failing(DeclareClassIvarInit::alloc(), &object);
+    assert!(obj.is_none());
+    // The instance variables were dropped when the partially initialized
+    // object was deallocated.
+    expected.retain += 2;
+    expected.release += 2;
+    expected.assert_current();
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "fails a debug assertion")]
+fn test_property_nonnull_set_to_nil() {
+    let object = __RcTestObject::new();
+    let obj = DeclareClassIvarInit::new_with_object(DeclareClassIvarInit::alloc(), &object);
+
+    let _: () = unsafe { msg_send![&obj, setObject: ptr::null_mut::<__RcTestObject>()] };
+    assert_eq!(Id::as_ptr(&obj.object), Id::as_ptr(&object));
+}
+
+declare_class!(
+    struct DeclareClassSuperCopy;
+
+    unsafe impl ClassType for DeclareClassSuperCopy {
+        type Super = __RcTestObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassSuperCopy";
+    }
+
+    unsafe impl DeclareClassSuperCopy {
+        #[method_id(init)]
+        fn init(this: Allocated<Self>) -> Option<Id<Self>> {
+            let this = this.set_ivars(());
+            unsafe { msg_send_id![super(this), init] }
+        }
+
+        #[method_id(copySuper)]
+        fn copy_super(&self) -> Id<__RcTestObject> {
+            unsafe { msg_send_id![super(self), copy] }
+        }
+    }
+);
+
+extern_methods!(
+    unsafe impl DeclareClassSuperCopy {
+        #[method_id(new)]
+        fn new() -> Id<Self>;
+
+        #[method_id(copySuper)]
+        fn call_copy_super(&self) -> Id<__RcTestObject>;
+    }
+);
+
+#[test]
+fn test_msg_send_id_super() {
+    let obj = DeclareClassSuperCopy::new();
+    let mut expected = __ThreadTestData::current();
+
+    let copy = obj.call_copy_super();
+    expected.copy += 1;
+    expected.alloc += 1;
+    expected.init += 1;
+    expected.assert_current();
+    assert_eq!(copy.class(), __RcTestObject::class());
+}
+
+struct StoredIvarsState {
+    number: Cell<i32>,
+    name: String,
+    object: Id<__RcTestObject>,
+}
+
+declare_class!(
+    struct DeclareClassStoredIvars;
+
+    unsafe impl ClassType for DeclareClassStoredIvars {
+        type Super = NSObject;
+        type Mutability = Mutable;
+        const NAME: &'static str = "DeclareClassStoredIvars";
+    }
+
+    impl DeclaredClass for DeclareClassStoredIvars {
+        type Ivars = StoredIvarsState;
+    }
+
+    unsafe impl DeclareClassStoredIvars {
+        #[method_id(initWithObject:)]
+        fn init_with_object(this: Allocated<Self>, object: &__RcTestObject) -> Option<Id<Self>> {
+            let this = this.set_ivars(StoredIvarsState {
+                number: Cell::new(42),
+                name: "stored".to_string(),
+                object: object.retain(),
+            });
+            unsafe { msg_send_id![super(this), init] }
+        }
+
+        #[method(number)]
+        fn number(&self) -> i32 {
+            self.ivars().number.get()
+        }
+    }
+);
+
+impl Drop for DeclareClassStoredIvars {
+    fn drop(&mut self) {
+        // The instance variables are still available in `Drop`.
+        assert!(!self.ivars().name.is_empty());
+    }
+}
+
+extern_methods!(
+    unsafe impl DeclareClassStoredIvars {
+        #[method_id(initWithObject:)]
+        fn new_with_object(this: Option<Allocated<Self>>, object: &__RcTestObject) -> Id<Self>;
+
+        #[method_id(new)]
+        fn new_uninitialized() -> Id<Self>;
+
+        #[method(number)]
+        fn get_number(&self) -> i32;
+    }
+);
+
+#[test]
+fn test_stored_ivars() {
+    let object = __RcTestObject::new();
+    let mut expected = __ThreadTestData::current();
+
+    let mut obj =
+        DeclareClassStoredIvars::new_with_object(DeclareClassStoredIvars::alloc(), &object);
+    expected.retain += 1;
+    expected.assert_current();
+
+    assert_eq!(obj.get_number(), 42);
+    assert_eq!(obj.ivars().name, "stored");
+    assert_eq!(Id::as_ptr(&obj.ivars().object), Id::as_ptr(&object));
+
+    obj.ivars().number.set(7);
+    assert_eq!(obj.get_number(), 7);
+    obj.ivars_mut().name = "changed".to_string();
+    assert_eq!(obj.ivars().name, "changed");
+
+    drop(obj);
+    expected.release += 1;
+    expected.assert_current();
+}
+
+#[test]
+#[should_panic = "tried to access the instance variables of DeclareClassStoredIvars, but they were not initialized"]
+fn test_stored_ivars_uninitialized() {
+    let obj = DeclareClassStoredIvars::new_uninitialized();
+    let _ = obj.ivars();
+}
+
+struct StoredIvarsSubclassState {
+    object: Option<Id<__RcTestObject>>,
+}
+
+declare_class!(
+    struct DeclareClassStoredIvarsSubclass;
+
+    unsafe impl ClassType for DeclareClassStoredIvarsSubclass {
+        type Super = DeclareClassStoredIvars;
+        type Mutability = Mutable;
+        const NAME: &'static str = "DeclareClassStoredIvarsSubclass";
+    }
+
+    impl DeclaredClass for DeclareClassStoredIvarsSubclass {
+        type Ivars = StoredIvarsSubclassState;
+    }
+
+    unsafe impl DeclareClassStoredIvarsSubclass {
+        #[method_id(initWithObject:)]
+        fn init_with_object(this: Allocated<Self>, object: &__RcTestObject) -> Option<Id<Self>> {
+            let this = this.set_ivars(StoredIvarsSubclassState {
+                object: Some(object.retain()),
+            });
+            unsafe { msg_send_id![super(this), initWithObject: object] }
+        }
+    }
+);
+
+extern_methods!(
+    unsafe impl DeclareClassStoredIvarsSubclass {
+        #[method_id(initWithObject:)]
+        fn new_with_object(this: Option<Allocated<Self>>, object: &__RcTestObject) -> Id<Self>;
+    }
+);
+
+#[test]
+fn test_stored_ivars_subclass() {
+    let object = __RcTestObject::new();
+    let mut expected = __ThreadTestData::current();
+
+    let obj = DeclareClassStoredIvarsSubclass::new_with_object(
+        DeclareClassStoredIvarsSubclass::alloc(),
+        &object,
+    );
+    expected.retain += 2;
+    expected.assert_current();
+
+    // Each class has its own storage.
+    assert!(obj.ivars().object.is_some());
+    assert_eq!(obj.get_number(), 42);
+    assert_eq!(obj.as_super().ivars().name, "stored");
+
+    drop(obj);
+    expected.release += 2;
+    expected.assert_current();
+}
+
+static INITIALIZE_CALLS: AtomicUsize = AtomicUsize::new(0);
+static INITIALIZE_STATE: ClassState<AtomicUsize> = ClassState::new(|| AtomicUsize::new(10));
+
+declare_class!(
+    struct DeclareClassInitialize;
+
+    unsafe impl ClassType for DeclareClassInitialize {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassInitialize";
+    }
+
+    unsafe impl DeclareClassInitialize {
+        #[initialize]
+        fn initialize() {
+            INITIALIZE_CALLS.fetch_add(1, Ordering::Relaxed);
+            INITIALIZE_STATE.get().fetch_add(1, Ordering::Relaxed);
+        }
+
+        #[method(state)]
+        fn state() -> usize {
+            INITIALIZE_STATE.get().load(Ordering::Relaxed)
+        }
+    }
+);
+
+declare_class!(
+    struct DeclareClassInitializeSubclass;
+
+    unsafe impl ClassType for DeclareClassInitializeSubclass {
+        type Super = DeclareClassInitialize;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassInitializeSubclass";
+    }
+);
+
+#[test]
+fn test_initialize() {
+    // Registering the class doesn't initialize it.
+    let cls = DeclareClassInitialize::class();
+    assert_eq!(INITIALIZE_CALLS.load(Ordering::Relaxed), 0);
+    assert!(INITIALIZE_STATE.try_get().is_none());
+
+    // But sending a message to it does.
+    let state: usize = unsafe { msg_send![cls, state] };
+    assert_eq!(state, 11);
+    assert_eq!(INITIALIZE_CALLS.load(Ordering::Relaxed), 1);
+
+    // The runtime sends `+initialize` to the subclass too, which is then
+    // handled by the superclass' implementation; the hook must not run
+    // again.
+    let subclass = DeclareClassInitializeSubclass::class();
+    let state: usize = unsafe { msg_send![subclass, state] };
+    assert_eq!(state, 11);
+    assert_eq!(INITIALIZE_CALLS.load(Ordering::Relaxed), 1);
+
+    let _obj: Id<DeclareClassInitializeSubclass> = unsafe { msg_send_id![subclass, new] };
+    assert_eq!(INITIALIZE_CALLS.load(Ordering::Relaxed), 1);
+}
+
+std::thread_local! {
+    static HIERARCHY_DROPS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+struct HierarchyBaseIvars {
+    base: u32,
+}
+
+declare_class!(
+    struct DeclareClassHierarchyBase;
+
+    unsafe impl ClassType for DeclareClassHierarchyBase {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassHierarchyBase";
+    }
+
+    impl DeclaredClass for DeclareClassHierarchyBase {
+        type Ivars = HierarchyBaseIvars;
+    }
+
+    unsafe impl DeclareClassHierarchyBase {
+        #[method_id(initWithNumber:)]
+        fn init_with_number(this: Allocated<Self>, number: u32) -> Option<Id<Self>> {
+            let this = this.set_ivars(HierarchyBaseIvars { base: number });
+            unsafe { msg_send_id![super(this), init] }
+        }
+
+        #[method(value)]
+        fn value(&self) -> u32 {
+            self.ivars().base
+        }
+
+        #[method(classValue)]
+        fn class_value() -> u32 {
+            1
+        }
+
+        #[method(receivingClass)]
+        fn receiving_class(cls: &AnyClass) -> *const AnyClass {
+            cls
+        }
+
+        #[method(classValueWithOffset:)]
+        fn class_value_with_offset(cls: u32) -> u32 {
+            cls + 1
+        }
+    }
+);
+
+impl Drop for DeclareClassHierarchyBase {
+    fn drop(&mut self) {
+        HIERARCHY_DROPS.with(|drops| drops.borrow_mut().push("base"));
+    }
+}
+
+declare_class!(
+    struct DeclareClassHierarchyMiddle {
+        middle: IvarEncode<u32, "_middle">,
+    }
+
+    mod hierarchy_middle;
+
+    unsafe impl ClassType for DeclareClassHierarchyMiddle {
+        type Super = DeclareClassHierarchyBase;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassHierarchyMiddle";
+    }
+
+    unsafe impl DeclareClassHierarchyMiddle {
+        #[method_id(initWithNumber:)]
+        fn init_with_number(this: Allocated<Self>, number: u32) -> Option<Id<Self>> {
+            let this = this.set_ivars(hierarchy_middle::Ivars {
+                middle: number * 10,
+            });
+            unsafe { msg_send_id![super(this), initWithNumber: number] }
+        }
+
+        #[method(value)]
+        fn value(&self) -> u32 {
+            let value: u32 = unsafe { msg_send![super(self), value] };
+            value + *self.middle
+        }
+
+        #[method(classValue)]
+        fn class_value(cls: &AnyClass) -> u32 {
+            let value: u32 = unsafe { msg_send![super(cls, Self), classValue] };
+            value + 10
+        }
+
+        #[method(receivingClass)]
+        fn receiving_class(cls: &AnyClass) -> *const AnyClass {
+            unsafe { msg_send![super(cls, Self), receivingClass] }
+        }
     }
+);
+
+impl Drop for DeclareClassHierarchyMiddle {
+    fn drop(&mut self) {
+        // The superclass' state is still available.
+        assert_eq!(self.as_super().ivars().base, 1);
+        HIERARCHY_DROPS.with(|drops| drops.borrow_mut().push("middle"));
+    }
+}
+
+struct HierarchyLeafIvars {
+    leaf: u32,
+}
+
+declare_class!(
+    struct DeclareClassHierarchyLeaf;
+
+    unsafe impl ClassType for DeclareClassHierarchyLeaf {
+        type Super = DeclareClassHierarchyMiddle;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassHierarchyLeaf";
+    }
+
+    impl DeclaredClass for DeclareClassHierarchyLeaf {
+        type Ivars = HierarchyLeafIvars;
+    }
+
+    unsafe impl DeclareClassHierarchyLeaf {
+        #[method_id(initWithNumber:)]
+        fn init_with_number(this: Allocated<Self>, number: u32) -> Option<Id<Self>> {
+            let this = this.set_ivars(HierarchyLeafIvars {
+                leaf: number * 100,
+            });
+            unsafe { msg_send_id![super(this), initWithNumber: number] }
+        }
+
+        #[method(value)]
+        fn value(&self) -> u32 {
+            let value: u32 = unsafe { msg_send![super(self), value] };
+            value + self.ivars().leaf
+        }
+
+        #[method(classValue)]
+        fn class_value(cls: &AnyClass) -> u32 {
+            let value: u32 = unsafe { msg_send![super(cls, Self), classValue] };
+            value + 100
+        }
+    }
+);
+
+impl Drop for DeclareClassHierarchyLeaf {
+    fn drop(&mut self) {
+        assert_eq!(*self.middle, 10);
+        HIERARCHY_DROPS.with(|drops| drops.borrow_mut().push("leaf"));
+    }
+}
+
+// Inherits the class methods of the middle class without overriding them.
+declare_class!(
+    struct DeclareClassHierarchyInheritor;
+
+    unsafe impl ClassType for DeclareClassHierarchyInheritor {
+        type Super = DeclareClassHierarchyMiddle;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareClassHierarchyInheritor";
+    }
+);
+
+extern_methods!(
+    unsafe impl DeclareClassHierarchyBase {
+        #[method(value)]
+        fn get_value(&self) -> u32;
+    }
+);
+
+fn class_value(cls: &AnyClass) -> u32 {
+    unsafe { msg_send![cls, classValue] }
+}
+
+#[test]
+fn test_hierarchy_super_methods() {
+    let base: Id<DeclareClassHierarchyBase> =
+        unsafe { msg_send_id![DeclareClassHierarchyBase::alloc(), initWithNumber: 1u32] };
+    let middle: Id<DeclareClassHierarchyMiddle> =
+        unsafe { msg_send_id![DeclareClassHierarchyMiddle::alloc(), initWithNumber: 1u32] };
+    let leaf: Id<DeclareClassHierarchyLeaf> =
+        unsafe { msg_send_id![DeclareClassHierarchyLeaf::alloc(), initWithNumber: 1u32] };
+
+    // Each override calls the implementation of its own superclass, even
+    // when called on an instance of a subclass.
+    assert_eq!(base.get_value(), 1);
+    assert_eq!(middle.get_value(), 11);
+    assert_eq!(leaf.get_value(), 111);
+
+    assert_eq!(class_value(DeclareClassHierarchyBase::class()), 1);
+    assert_eq!(class_value(DeclareClassHierarchyMiddle::class()), 11);
+    assert_eq!(class_value(DeclareClassHierarchyLeaf::class()), 111);
+    assert_eq!(class_value(DeclareClassHierarchyInheritor::class()), 11);
+}
+
+#[test]
+fn test_class_method_argument_named_cls() {
+    // Only `cls: &AnyClass` is the receiver, other types are normal arguments
+    let value: u32 =
+        unsafe { msg_send![DeclareClassHierarchyBase::class(), classValueWithOffset: 41u32] };
+    assert_eq!(value, 42);
+}
+
+#[test]
+fn test_hierarchy_super_class_method_receiver() {
+    fn receiving_class(cls: &AnyClass) -> *const AnyClass {
+        unsafe { msg_send![cls, receivingClass] }
+    }
+
+    for cls in [
+        DeclareClassHierarchyBase::class(),
+        DeclareClassHierarchyMiddle::class(),
+        DeclareClassHierarchyLeaf::class(),
+        // The inherited implementation in the middle class must pass the
+        // subclass on as the receiver of the super call.
+        DeclareClassHierarchyInheritor::class(),
+    ] {
+        assert_eq!(receiving_class(cls), cls as *const AnyClass);
+    }
+}
+
+#[test]
+fn test_hierarchy_ivars() {
+    let leaf: Id<DeclareClassHierarchyLeaf> =
+        unsafe { msg_send_id![DeclareClassHierarchyLeaf::alloc(), initWithNumber: 1u32] };
+
+    // Through `Deref` to the superclasses.
+    assert_eq!(leaf.ivars().leaf, 100);
+    assert_eq!(*leaf.middle, 10);
+    assert_eq!(leaf.as_super().as_super().ivars().base, 1);
+    let base: &DeclareClassHierarchyBase = &leaf;
+    assert_eq!(base.ivars().base, 1);
+}
+
+#[test]
+fn test_hierarchy_dealloc() {
+    HIERARCHY_DROPS.with(|drops| drops.borrow_mut().clear());
+
+    let leaf: Id<DeclareClassHierarchyLeaf> =
+        unsafe { msg_send_id![DeclareClassHierarchyLeaf::alloc(), initWithNumber: 1u32] };
+    drop(leaf);
+    HIERARCHY_DROPS.with(|drops| assert_eq!(*drops.borrow(), ["leaf", "middle", "base"]));
+
+    HIERARCHY_DROPS.with(|drops| drops.borrow_mut().clear());
+
+    let middle: Id<DeclareClassHierarchyMiddle> =
+        unsafe { msg_send_id![DeclareClassHierarchyMiddle::alloc(), initWithNumber: 1u32] };
+    drop(middle);
+    HIERARCHY_DROPS.with(|drops| assert_eq!(*drops.borrow(), ["middle", "base"]));
+}
+
+#[cfg(feature = "objc2-proc-macros")]
+declare_class!(
+    struct DeclareClassUniqueName;
+
+    unsafe impl ClassType for DeclareClassUniqueName {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = crate::unique_class_name!("DeclareClassUniqueName");
+    }
+);
+
+#[cfg(feature = "objc2-proc-macros")]
+declare_class!(
+    struct DeclareClassUniqueNameOther;
+
+    unsafe impl ClassType for DeclareClassUniqueNameOther {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = crate::unique_class_name!("DeclareClassUniqueName");
+    }
+);
+
+#[test]
+#[cfg(feature = "objc2-proc-macros")]
+fn test_unique_class_name() {
+    let name = DeclareClassUniqueName::NAME;
+    let (prefix, hash) = name.split_at("DeclareClassUniqueName_".len());
+    assert_eq!(prefix, "DeclareClassUniqueName_");
+    assert_eq!(hash.len(), 16);
+
+    // The runtime name is the uniqued name.
+    assert_eq!(DeclareClassUniqueName::class().name(), name);
+
+    // Declaring a class with the same name elsewhere doesn't conflict.
+    assert_ne!(DeclareClassUniqueNameOther::NAME, name);
+    assert_eq!(
+        DeclareClassUniqueNameOther::class().name(),
+        DeclareClassUniqueNameOther::NAME
+    );
+}
+
+declare_protocol!(
+    #[allow(clippy::missing_safety_doc, dead_code)]
+    unsafe trait DeclareProtocolTest: NSObjectProtocol {
+        #[method(value)]
+        fn value(&self) -> u32;
+
+        #[method(add::)]
+        fn add(a: u32, b: u32) -> u32;
+
+        #[method(check:error:_)]
+        fn check(&self, succeed: bool) -> Result<(), Id<__RcTestObject>>;
+
+        #[property(object, copy)]
+        #[method_id(object)]
+        fn object(&self) -> Id<NSObject>;
+
+        #[optional]
+        #[property(isEnabled, readonly, atomic)]
+        #[method(isEnabled)]
+        fn is_enabled(&self) -> bool;
+
+        #[optional]
+        #[method_id(objectWithNumber:)]
+        fn object_with_number(number: i32) -> Option<Id<NSObject>>;
+    }
+
+    unsafe impl ProtocolType for dyn DeclareProtocolTest {
+        const NAME: &'static str = "DeclareProtocolTest";
+    }
+);
+
+declare_protocol!(
+    #[allow(clippy::missing_safety_doc)]
+    unsafe trait DeclareProtocolTestSub: DeclareProtocolTest {
+        #[method(subValue)]
+        fn sub_value(&self) -> u32;
+    }
+
+    unsafe impl ProtocolType for dyn DeclareProtocolTestSub {}
+);
+
+declare_class!(
+    struct DeclareProtocolTestImpl;
+
+    unsafe impl ClassType for DeclareProtocolTestImpl {
+        type Super = NSObject;
+        type Mutability = Immutable;
+        const NAME: &'static str = "DeclareProtocolTestImpl";
+    }
+
+    unsafe impl NSObjectProtocol for DeclareProtocolTestImpl {}
+
+    unsafe impl DeclareProtocolTest for DeclareProtocolTestImpl {
+        #[method(value)]
+        fn value(&self) -> u32 {
+            42
+        }
+
+        #[method(add::)]
+        fn add(a: u32, b: u32) -> u32 {
+            a + b
+        }
+
+        #[method(check:error:)]
+        fn check(&self, succeed: bool) -> Result<(), Id<__RcTestObject>> {
+            if succeed {
+                Ok(())
+            } else {
+                Err(__RcTestObject::new())
+            }
+        }
+
+        #[method_id(object)]
+        fn object(&self) -> Id<NSObject> {
+            NSObject::new()
+        }
+    }
+
+    unsafe impl DeclareProtocolTestSub for DeclareProtocolTestImpl {
+        #[method(subValue)]
+        fn sub_value(&self) -> u32 {
+            7
+        }
+    }
+);
+
+extern_methods!(
+    unsafe impl DeclareProtocolTestImpl {
+        #[method_id(new)]
+        fn new() -> Id<Self>;
+    }
+);
+
+fn protocol_property_attributes(protocol: &AnyProtocol, name: &str) -> Option<String> {
+    let name = CString::new(name).unwrap();
+    let property = unsafe {
+        ffi::protocol_getProperty(
+            protocol.as_ptr(),
+            name.as_ptr(),
+            Bool::YES.as_raw(),
+            Bool::YES.as_raw(),
+        )
+    };
+    let property = if property.is_null() {
+        unsafe {
+            ffi::protocol_getProperty(
+                protocol.as_ptr(),
+                name.as_ptr(),
+                Bool::NO.as_raw(),
+                Bool::YES.as_raw(),
+            )
+        }
+    } else {
+        property
+    };
+    if property.is_null() {
+        return None;
+    }
+    let attributes = unsafe { CStr::from_ptr(ffi::property_getAttributes(property)) };
+    Some(attributes.to_str().unwrap().to_string())
+}
+
+#[test]
+fn test_declare_protocol() {
+    let protocol = <dyn DeclareProtocolTest>::protocol().unwrap();
+    assert_eq!(protocol.name(), "DeclareProtocolTest");
+    assert!(protocol.conforms_to(<dyn NSObjectProtocol>::protocol().unwrap()));
+    // Registering happens only once
+    assert_eq!(<dyn DeclareProtocolTest>::protocol().unwrap(), protocol);
+
+    let sub_protocol = <dyn DeclareProtocolTestSub>::protocol().unwrap();
+    assert_eq!(sub_protocol.name(), "DeclareProtocolTestSub");
+    assert!(sub_protocol.conforms_to(protocol));
+
+    let cls = DeclareProtocolTestImpl::class();
+    assert!(cls.conforms_to(protocol));
+    assert!(cls.conforms_to(sub_protocol));
+}
+
+#[test]
+fn test_declare_protocol_object() {
+    let obj = DeclareProtocolTestImpl::new();
+    let proto: &ProtocolObject<dyn DeclareProtocolTest> = ProtocolObject::from_ref(&*obj);
+    assert_eq!(proto.value(), 42);
+    assert!(proto.check(true).is_ok());
+    assert!(proto.check(false).is_err());
+    let _object = proto.object();
+
+    let proto: &ProtocolObject<dyn DeclareProtocolTestSub> = ProtocolObject::from_ref(&*obj);
+    assert_eq!(proto.sub_value(), 7);
+    assert_eq!(proto.value(), 42);
+
+    assert_eq!(
+        <DeclareProtocolTestImpl as DeclareProtocolTest>::add(1, 2),
+        3
+    );
+}
+
+#[test]
+#[cfg(feature = "malloc")]
+fn test_declare_protocol_methods() {
+    let protocol = <dyn DeclareProtocolTest>::protocol().unwrap();
+    let bool_enc = Bool::ENCODING.to_string();
+
+    let required = protocol.method_descriptions(true);
+    assert_eq!(required.len(), 3, "{required:?}");
+    for (sel, types) in [
+        (sel!(value), "I@:".to_string()),
+        (sel!(check:error:), format!("{bool_enc}@:{bool_enc}^@")),
+        (sel!(object), "@@:".to_string()),
+    ] {
+        assert!(
+            required
+                .iter()
+                .any(|desc| desc.sel == sel && desc.types == types),
+            "{required:?} missing {sel} with types {types}",
+        );
+    }
+
+    let optional = protocol.method_descriptions(false);
+    assert_eq!(optional.len(), 1);
+    assert_eq!(optional[0].sel, sel!(isEnabled));
+    assert_eq!(optional[0].types, format!("{bool_enc}@:"));
+
+    let desc = MethodDescription {
+        sel: sel!(add::),
+        types: "I@:II",
+    };
+    assert_eq!(protocol.class_method_descriptions(true), [desc]);
+    let desc = MethodDescription {
+        sel: sel!(objectWithNumber:),
+        types: "@@:i",
+    };
+    assert_eq!(protocol.class_method_descriptions(false), [desc]);
+}
+
+#[test]
+fn test_declare_protocol_properties() {
+    let protocol = <dyn DeclareProtocolTest>::protocol().unwrap();
+
+    assert_eq!(
+        protocol_property_attributes(protocol, "object").as_deref(),
+        Some("T@,C,N"),
+    );
+    assert_eq!(
+        protocol_property_attributes(protocol, "isEnabled").as_deref(),
+        Some(&*format!("T{},R", Bool::ENCODING)),
+    );
+    assert_eq!(protocol_property_attributes(protocol, "value"), None);
 }
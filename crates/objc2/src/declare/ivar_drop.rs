@@ -2,7 +2,7 @@ use alloc::boxed::Box;
 use core::ffi::c_void;
 
 use crate::encode::{Encode, Encoding};
-use crate::rc::Id;
+use crate::rc::{Id, Unretained, WeakId};
 use crate::Message;
 
 use super::InnerIvarType;
@@ -23,6 +23,9 @@ mod private {
 /// - `Option<Box<T>>`
 /// - `Id<T>`
 /// - `Option<Id<T>>`
+/// - `Unretained<T>`
+/// - `Option<Unretained<T>>`
+/// - `WeakId<T>`
 ///
 /// Further may be added when the standard library guarantee their layout.
 #[repr(transparent)]
@@ -159,6 +162,81 @@ unsafe impl<T: Message> InnerIvarType for IvarDrop<Option<Id<T>>> {
     }
 }
 
+// When verifying, `Unretained` stores a pointer to the shared deallocation
+// state instead of the object itself, so we use `*const c_void` there.
+#[cfg(not(all(debug_assertions, feature = "verify")))]
+unsafe impl<T: Message> Encode for IvarDrop<Unretained<T>> {
+    const ENCODING: Encoding = <*const T>::ENCODING;
+}
+
+#[cfg(all(debug_assertions, feature = "verify"))]
+unsafe impl<T: Message> Encode for IvarDrop<Unretained<T>> {
+    const ENCODING: Encoding = <*const c_void>::ENCODING;
+}
+
+// SAFETY: `Option<Unretained<T>>` is safe to zero-initialize
+unsafe impl<T: Message> private::IvarDropHelper for Unretained<T> {
+    type Inner = Option<Unretained<T>>;
+}
+
+// SAFETY: `Unretained<T>` is a non-null pointer (or a `Box` when verifying),
+// and hence safe to store as a pointer.
+//
+// The user ensures that the pointer has been initialized in an `init`
+// method before being used.
+unsafe impl<T: Message> InnerIvarType for IvarDrop<Unretained<T>> {
+    type Output = Unretained<T>;
+
+    #[inline]
+    unsafe fn __deref(&self) -> &Self::Output {
+        match &self.0 {
+            Some(inner) => inner,
+            None => unsafe { unretained_unreachable() },
+        }
+    }
+
+    #[inline]
+    unsafe fn __deref_mut(&mut self) -> &mut Self::Output {
+        match &mut self.0 {
+            Some(inner) => inner,
+            None => unsafe { unretained_unreachable() },
+        }
+    }
+}
+
+#[cfg(not(all(debug_assertions, feature = "verify")))]
+unsafe impl<T: Message> Encode for IvarDrop<Option<Unretained<T>>> {
+    const ENCODING: Encoding = <*const T>::ENCODING;
+}
+
+#[cfg(all(debug_assertions, feature = "verify"))]
+unsafe impl<T: Message> Encode for IvarDrop<Option<Unretained<T>>> {
+    const ENCODING: Encoding = <*const c_void>::ENCODING;
+}
+
+// SAFETY: `Option<Unretained<T>>` is safe to zero-initialize
+unsafe impl<T: Message> private::IvarDropHelper for Option<Unretained<T>> {
+    type Inner = Option<Unretained<T>>;
+}
+
+// SAFETY: `Unretained<T>` guarantees the null-pointer optimization.
+//
+// This is valid to initialize as all-zeroes, so the user doesn't have to do
+// anything to initialize it.
+unsafe impl<T: Message> InnerIvarType for IvarDrop<Option<Unretained<T>>> {
+    type Output = Option<Unretained<T>>;
+
+    #[inline]
+    unsafe fn __deref(&self) -> &Self::Output {
+        &self.0
+    }
+
+    #[inline]
+    unsafe fn __deref_mut(&mut self) -> &mut Self::Output {
+        &mut self.0
+    }
+}
+
 // TODO: Allow the following once their layout is guaranteed by `std`:
 // - Arc<T>
 // - Option<Arc<T>>
@@ -169,8 +247,41 @@ unsafe impl<T: Message> InnerIvarType for IvarDrop<Option<Id<T>>> {
 // - Vec<T>
 // - String
 
-// TODO: Allow `WeakId` once we figure out how to allow it being initialized
-// by default.
+// Note that we use `*const c_void`, since the ivar stores a pointer to the
+// weak slot, and not the object itself.
+unsafe impl<T: Message> Encode for IvarDrop<WeakId<T>> {
+    const ENCODING: Encoding = <*const c_void>::ENCODING;
+}
+
+// SAFETY: `Option<WeakId<T>>` is safe to zero-initialize, since `WeakId` is
+// a `#[repr(transparent)]` wrapper around a `Box`.
+unsafe impl<T: Message> private::IvarDropHelper for WeakId<T> {
+    type Inner = Option<WeakId<T>>;
+}
+
+// SAFETY: `WeakId` is a `Box`, and hence safe to store as a pointer.
+//
+// The user ensures that the WeakId has been initialized in an `init` method
+// before being used (e.g. with `WeakId::default()`).
+unsafe impl<T: Message> InnerIvarType for IvarDrop<WeakId<T>> {
+    type Output = WeakId<T>;
+
+    #[inline]
+    unsafe fn __deref(&self) -> &Self::Output {
+        match &self.0 {
+            Some(inner) => inner,
+            None => unsafe { weak_id_unreachable() },
+        }
+    }
+
+    #[inline]
+    unsafe fn __deref_mut(&mut self) -> &mut Self::Output {
+        match &mut self.0 {
+            Some(inner) => inner,
+            None => unsafe { weak_id_unreachable() },
+        }
+    }
+}
 
 #[inline]
 #[track_caller]
@@ -186,6 +297,34 @@ unsafe fn id_unreachable() -> ! {
     }
 }
 
+#[inline]
+#[track_caller]
+unsafe fn unretained_unreachable() -> ! {
+    #[cfg(debug_assertions)]
+    {
+        unreachable!("an Unretained in instance variables must always be initialized before use!")
+    }
+    // SAFETY: Checked by caller
+    #[cfg(not(debug_assertions))]
+    unsafe {
+        core::hint::unreachable_unchecked()
+    }
+}
+
+#[inline]
+#[track_caller]
+unsafe fn weak_id_unreachable() -> ! {
+    #[cfg(debug_assertions)]
+    {
+        unreachable!("a WeakId in instance variables must always be initialized before use!")
+    }
+    // SAFETY: Checked by caller
+    #[cfg(not(debug_assertions))]
+    unsafe {
+        core::hint::unreachable_unchecked()
+    }
+}
+
 #[inline]
 #[track_caller]
 unsafe fn box_unreachable() -> ! {
@@ -202,6 +341,8 @@ unsafe fn box_unreachable() -> ! {
 
 #[cfg(test)]
 mod tests {
+    use core::ptr;
+
     use super::*;
     use crate::declare::{Ivar, IvarType};
     use crate::mutability::Mutable;
@@ -357,6 +498,91 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_weak() {
+        declare_class!(
+            struct WeakIvarTester {
+                weak: IvarDrop<WeakId<__RcTestObject>, "_weak">,
+            }
+
+            mod weakivartester;
+
+            unsafe impl ClassType for WeakIvarTester {
+                type Super = NSObject;
+                type Mutability = Mutable;
+                const NAME: &'static str = "WeakIvarTester";
+            }
+
+            unsafe impl WeakIvarTester {
+                #[method(init)]
+                fn init(&mut self) -> Option<&mut Self> {
+                    let this: Option<&mut Self> = unsafe { msg_send![super(self), init] };
+                    this.map(|this| {
+                        Ivar::write(&mut this.weak, WeakId::default());
+                        this
+                    })
+                }
+            }
+        );
+
+        let mut obj: Id<WeakIvarTester> = unsafe { msg_send_id![WeakIvarTester::class(), new] };
+        assert!(obj.weak.load().is_none());
+
+        let inner = __RcTestObject::new();
+        *obj.weak = WeakId::new(&inner);
+        assert_eq!(obj.weak.load().as_deref(), Some(&*inner));
+
+        drop(inner);
+        assert!(obj.weak.load().is_none());
+    }
+
+    #[test]
+    fn test_unretained() {
+        declare_class!(
+            struct UnretainedIvarTester {
+                unretained: IvarDrop<Unretained<__RcTestObject>, "_unretained">,
+                optional: IvarDrop<Option<Unretained<__RcTestObject>>, "_optional">,
+            }
+
+            mod unretainedivartester;
+
+            unsafe impl ClassType for UnretainedIvarTester {
+                type Super = NSObject;
+                type Mutability = Mutable;
+                const NAME: &'static str = "UnretainedIvarTester";
+            }
+
+            unsafe impl UnretainedIvarTester {
+                #[method(initWithObject:)]
+                fn init_with_object(&mut self, obj: &__RcTestObject) -> Option<&mut Self> {
+                    let this: Option<&mut Self> = unsafe { msg_send![super(self), init] };
+                    this.map(|this| {
+                        Ivar::write(&mut this.unretained, Unretained::new(obj));
+                        this
+                    })
+                }
+            }
+        );
+
+        let inner = __RcTestObject::new();
+        let expected = __ThreadTestData::current();
+
+        let mut obj: Id<UnretainedIvarTester> =
+            unsafe { msg_send_id![UnretainedIvarTester::alloc(), initWithObject: &*inner] };
+        assert!(obj.optional.is_none());
+        *obj.optional = Some(Unretained::new(&inner));
+        expected.assert_current();
+
+        assert!(ptr::eq(unsafe { obj.unretained.get() }, &*inner));
+        let optional = (*obj.optional).as_ref().unwrap();
+        assert!(ptr::eq(unsafe { optional.get() }, &*inner));
+
+        // Neither storing nor dropping the references retain or release the
+        // object
+        drop(obj);
+        expected.assert_current();
+    }
+
     #[test]
     #[cfg_attr(not(debug_assertions), ignore = "only panics in debug mode")]
     #[should_panic = "an Id in instance variables must always be initialized before use"]
@@ -115,6 +115,7 @@
 //! assert_eq!(n, 12);
 //! ```
 
+mod class_state;
 #[cfg(test)]
 mod declare_class_tests;
 mod ivar;
@@ -122,14 +123,16 @@ mod ivar_bool;
 mod ivar_drop;
 mod ivar_encode;
 mod ivar_forwarding_impls;
+mod property;
 
 use alloc::format;
 use alloc::string::ToString;
+use alloc::vec::Vec;
 use core::mem;
 use core::mem::ManuallyDrop;
 use core::ptr;
 use core::ptr::NonNull;
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 
 use crate::encode::__unstable::{EncodeArguments, EncodeReturn};
 use crate::encode::{Encode, Encoding, RefEncode};
@@ -140,10 +143,12 @@ use crate::runtime::{AnyClass, AnyObject, AnyProtocol, Bool, Imp, Sel};
 use crate::sel;
 use crate::Message;
 
+pub use class_state::ClassState;
 pub use ivar::{InnerIvarType, Ivar, IvarType};
 pub use ivar_bool::IvarBool;
 pub use ivar_drop::IvarDrop;
 pub use ivar_encode::IvarEncode;
+pub use property::{PropertyAttributes, PropertyOwnership};
 
 pub(crate) mod private {
     pub trait Sealed {}
@@ -587,7 +592,7 @@ impl ClassBuilder {
         assert!(success.as_bool(), "failed to add ivar {name}");
     }
 
-    unsafe fn add_ivar_inner<T>(&mut self, name: &str, encoding: &Encoding) {
+    pub(crate) unsafe fn add_ivar_inner<T>(&mut self, name: &str, encoding: &Encoding) {
         unsafe { self.add_ivar_inner_mono(name, mem::size_of::<T>(), T::LOG2_ALIGNMENT, encoding) }
     }
 
@@ -613,7 +618,40 @@ impl ClassBuilder {
         assert!(success, "failed to add protocol {proto}");
     }
 
-    // fn add_property(&self, name: &str, attributes: &[ffi::objc_property_attribute_t]);
+    /// Declares a property with the given name, type and attributes.
+    ///
+    /// This only registers the metadata of the property (which is what
+    /// `class_copyPropertyList`, key-value coding and similar tooling
+    /// inspects), the accessor methods must be added separately with
+    /// [`add_method`][Self::add_method]. If `ivar` is given, it is recorded
+    /// as the instance variable that backs the property.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// If the property wasn't successfully added for some reason - this
+    /// usually happens if there already was a property with that name.
+    #[doc(alias = "class_addProperty")]
+    pub fn add_property(
+        &mut self,
+        name: &str,
+        encoding: &Encoding,
+        ivar: Option<&str>,
+        attributes: PropertyAttributes,
+    ) {
+        let c_name = CString::new(name).unwrap();
+        let success = with_property_attributes(encoding, ivar, attributes, |attrs| {
+            Bool::from_raw(unsafe {
+                ffi::class_addProperty(
+                    self.as_mut_ptr(),
+                    c_name.as_ptr(),
+                    attrs.as_ptr(),
+                    attrs.len() as _,
+                )
+            })
+        });
+        assert!(success.as_bool(), "failed to add property {name}");
+    }
 
     /// Registers the [`ClassBuilder`], consuming it, and returns a reference
     /// to the newly registered [`AnyClass`].
@@ -642,6 +680,46 @@ impl Drop for ClassBuilder {
     }
 }
 
+/// Build the list of attributes for a declared property, and call `f` with
+/// it.
+fn with_property_attributes<R>(
+    encoding: &Encoding,
+    ivar: Option<&str>,
+    attributes: PropertyAttributes,
+    f: impl FnOnce(&[ffi::objc_property_attribute_t]) -> R,
+) -> R {
+    let encoding = CString::new(encoding.to_string()).unwrap();
+    let ivar = ivar.map(|ivar| CString::new(ivar).unwrap());
+    let empty: &CStr = Default::default();
+
+    let mut attrs = Vec::with_capacity(5);
+    let mut push = |name: &'static [u8], value: &CStr| {
+        attrs.push(ffi::objc_property_attribute_t {
+            name: name.as_ptr().cast(),
+            value: value.as_ptr(),
+        });
+    };
+    // Same order as Clang emits them in.
+    push(b"T\0", &encoding);
+    if attributes.is_readonly() {
+        push(b"R\0", empty);
+    }
+    match attributes.get_ownership() {
+        PropertyOwnership::Assign => {}
+        PropertyOwnership::Strong => push(b"&\0", empty),
+        PropertyOwnership::Copy => push(b"C\0", empty),
+        PropertyOwnership::Weak => push(b"W\0", empty),
+    }
+    if !attributes.is_atomic() {
+        push(b"N\0", empty);
+    }
+    if let Some(ivar) = &ivar {
+        push(b"V\0", ivar);
+    }
+
+    f(&attrs)
+}
+
 /// A type for declaring a new protocol and adding new methods to it
 /// before registering it.
 #[derive(Debug)]
@@ -671,7 +749,7 @@ impl ProtocolBuilder {
         NonNull::new(proto.cast()).map(|proto| Self { proto })
     }
 
-    fn add_method_description_inner(
+    pub(crate) fn add_method_description_inner(
         &mut self,
         sel: Sel,
         enc_args: &[Encoding],
@@ -728,6 +806,32 @@ impl ProtocolBuilder {
         )
     }
 
+    /// Declares a property with the given name, type and attributes.
+    ///
+    /// Classes that conform to the protocol must implement the accessor
+    /// methods of the property, which should be declared separately with
+    /// [`add_method_description`][Self::add_method_description].
+    #[doc(alias = "protocol_addProperty")]
+    pub fn add_property(
+        &mut self,
+        name: &str,
+        encoding: &Encoding,
+        attributes: PropertyAttributes,
+        required: bool,
+    ) {
+        let c_name = CString::new(name).unwrap();
+        with_property_attributes(encoding, None, attributes, |attrs| unsafe {
+            ffi::protocol_addProperty(
+                self.as_mut_ptr(),
+                c_name.as_ptr(),
+                attrs.as_ptr(),
+                attrs.len() as _,
+                Bool::new(required).as_raw(),
+                Bool::YES.as_raw(),
+            )
+        });
+    }
+
     /// Adds a requirement on another protocol.
     pub fn add_protocol(&mut self, proto: &AnyProtocol) {
         unsafe {
@@ -750,7 +854,7 @@ mod tests {
     use super::*;
     use crate::mutability::Immutable;
     use crate::rc::Id;
-    use crate::runtime::{NSObject, NSZone, __NSCopying as NSCopying};
+    use crate::runtime::{__NSCopying as NSCopying, NSObject, NSZone};
     use crate::test_utils;
     use crate::{declare_class, msg_send, ClassType, ProtocolType};
 
@@ -0,0 +1,134 @@
+/// The memory management semantics of a declared property.
+///
+/// See [Apple's documentation on property attributes][apple-doc].
+///
+/// [apple-doc]: https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ProgrammingWithObjectiveC/EncapsulatingData/EncapsulatingData.html#//apple_ref/doc/uid/TP40011210-CH5-SW2
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PropertyOwnership {
+    /// The value is assigned directly, without retaining it.
+    ///
+    /// This is the default, and is used for non-object types, or for
+    /// `unsafe_unretained` object references.
+    #[doc(alias = "unsafe_unretained")]
+    Assign,
+    /// The setter retains the new value, and releases the old one.
+    #[doc(alias = "retain")]
+    Strong,
+    /// The setter sends `copy` to the new value, and releases the old one.
+    Copy,
+    /// The property holds a zeroing weak reference to the value.
+    Weak,
+}
+
+impl Default for PropertyOwnership {
+    #[inline]
+    fn default() -> Self {
+        Self::Assign
+    }
+}
+
+/// The attributes of a declared property.
+///
+/// By default, the property is `assign`, `readwrite` and `nonatomic`. Note
+/// that unlike in Objective-C's `@property` declarations, properties are
+/// `nonatomic` by default, since that is what most properties use in
+/// practice, and since not every kind of instance variable can be accessed
+/// atomically.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::declare::{PropertyAttributes, PropertyOwnership};
+///
+/// // @property (atomic, copy, readonly)
+/// let attributes = PropertyAttributes::new()
+///     .ownership(PropertyOwnership::Copy)
+///     .readonly(true)
+///     .atomic(true);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PropertyAttributes {
+    ownership: PropertyOwnership,
+    readonly: bool,
+    atomic: bool,
+}
+
+impl PropertyAttributes {
+    /// Create the default set of attributes.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            ownership: PropertyOwnership::Assign,
+            readonly: false,
+            atomic: false,
+        }
+    }
+
+    /// Set the memory management semantics of the property.
+    #[inline]
+    pub const fn ownership(mut self, ownership: PropertyOwnership) -> Self {
+        self.ownership = ownership;
+        self
+    }
+
+    /// Set whether the property is read-only, and hence has no setter.
+    #[inline]
+    pub const fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Set whether the accessors of the property are atomic.
+    #[inline]
+    pub const fn atomic(mut self, atomic: bool) -> Self {
+        self.atomic = atomic;
+        self
+    }
+
+    /// The memory management semantics of the property.
+    #[inline]
+    pub const fn get_ownership(&self) -> PropertyOwnership {
+        self.ownership
+    }
+
+    /// Whether the property is read-only.
+    #[inline]
+    pub const fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
+    /// Whether the accessors of the property are atomic.
+    #[inline]
+    pub const fn is_atomic(&self) -> bool {
+        self.atomic
+    }
+}
+
+impl Default for PropertyAttributes {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults() {
+        let attributes = PropertyAttributes::default();
+        assert_eq!(attributes.get_ownership(), PropertyOwnership::Assign);
+        assert!(!attributes.is_readonly());
+        assert!(!attributes.is_atomic());
+
+        let attributes = attributes
+            .ownership(PropertyOwnership::Weak)
+            .readonly(true)
+            .atomic(true);
+        assert_eq!(attributes.get_ownership(), PropertyOwnership::Weak);
+        assert!(attributes.is_readonly());
+        assert!(attributes.is_atomic());
+    }
+}
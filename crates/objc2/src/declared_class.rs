@@ -0,0 +1,89 @@
+use core::ptr::NonNull;
+
+use crate::__macro_helpers::{ivars_ptr, StoredIvars};
+use crate::ClassType;
+
+/// Marks class types whose implementation is defined in Rust.
+///
+/// This is implemented automatically for your type by the
+/// [`declare_class!`][crate::declare_class] macro, and allows initializing
+/// all the instance variables of the class at once using
+/// [`Allocated::set_ivars`], before calling the superclass' initializer with
+/// [`msg_send_id![super(this), init]`][crate::msg_send_id].
+///
+/// [`Allocated::set_ivars`]: crate::rc::Allocated::set_ivars
+///
+///
+/// # Safety
+///
+/// This is meant to be an internal trait, and should not be implemented
+/// manually. `__write_ivars` must initialize every instance variable of the
+/// class with the given values.
+pub unsafe trait DeclaredClass: ClassType {
+    /// A struct containing the initial values of the class' instance
+    /// variables.
+    ///
+    /// In [`declare_class!`][crate::declare_class], this is either the type
+    /// given in `impl DeclaredClass for T { type Ivars = ...; }`, which is
+    /// then stored in a single hidden instance variable, or a struct
+    /// generated in the ivar helper module as `Ivars`, with a field for each
+    /// instance variable. If the class has no instance variables, this is
+    /// `()`.
+    type Ivars: Sized;
+
+    /// Get a reference to the instance variables of the object.
+    ///
+    /// This is only available on classes that store their instance
+    /// variables in a single struct.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// If the instance variables have not yet been initialized with
+    /// [`Allocated::set_ivars`], e.g. if an `init` method forgot to do so.
+    ///
+    /// [`Allocated::set_ivars`]: crate::rc::Allocated::set_ivars
+    #[inline]
+    #[track_caller]
+    fn ivars(&self) -> &Self::Ivars
+    where
+        Self: Sized + StoredIvars,
+    {
+        let ptr = ivars_ptr(NonNull::from(self));
+        // SAFETY: The instance variables are initialized, and are borrowed
+        // for the same lifetime as the object.
+        unsafe { ptr.as_ref() }
+    }
+
+    /// Get a mutable reference to the instance variables of the object.
+    ///
+    /// See [`ivars`][Self::ivars] for details.
+    #[inline]
+    #[track_caller]
+    fn ivars_mut(&mut self) -> &mut Self::Ivars
+    where
+        Self: Sized + StoredIvars,
+    {
+        let mut ptr = ivars_ptr(NonNull::from(self));
+        // SAFETY: The instance variables are initialized, and we have
+        // unique access to the object.
+        unsafe { ptr.as_mut() }
+    }
+
+    /// # Safety
+    ///
+    /// The object must be an allocated, but not yet initialized, instance of
+    /// this class or one of its subclasses, and its instance variables must
+    /// not have been initialized already.
+    #[doc(hidden)]
+    unsafe fn __write_ivars(obj: NonNull<Self>, ivars: Self::Ivars);
+
+    /// # Safety
+    ///
+    /// Must only be called from the `dealloc` method of this class.
+    #[doc(hidden)]
+    #[inline]
+    unsafe fn __drop_ivars(obj: NonNull<Self>) {
+        let _ = obj;
+    }
+}
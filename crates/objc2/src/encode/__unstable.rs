@@ -78,8 +78,15 @@ pub trait EncodeConvertArgument: convert_private::Sealed {
     #[doc(hidden)]
     type __StoredBeforeMessage: Sized;
 
+    /// A helper type for out parameters in methods declared with
+    /// `declare_class!`, which is dropped after the method body has run.
     #[doc(hidden)]
-    fn __from_declared_param(inner: Self::__Inner) -> Self;
+    type __DeclaredParamGuard: Sized;
+
+    #[doc(hidden)]
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard)
+    where
+        Self: Sized;
 
     #[doc(hidden)]
     fn __into_argument(self) -> (Self::__Inner, Self::__StoredBeforeMessage);
@@ -106,9 +113,11 @@ impl<T: Encode> EncodeConvertArgument for T {
 
     type __StoredBeforeMessage = ();
 
+    type __DeclaredParamGuard = ();
+
     #[inline]
-    fn __from_declared_param(inner: Self::__Inner) -> Self {
-        inner
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard) {
+        (inner, ())
     }
 
     #[inline]
@@ -136,9 +145,11 @@ impl EncodeConvertArgument for bool {
 
     type __StoredBeforeMessage = ();
 
+    type __DeclaredParamGuard = ();
+
     #[inline]
-    fn __from_declared_param(inner: Self::__Inner) -> Self {
-        inner.as_bool()
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard) {
+        (inner.as_bool(), ())
     }
 
     #[inline]
@@ -230,7 +241,7 @@ mod tests {
             TypeId::of::<i32>()
         );
         assert_eq!(
-            <i32 as EncodeConvertArgument>::__from_declared_param(42),
+            unsafe { <i32 as EncodeConvertArgument>::__from_declared_param(42) }.0,
             42
         );
         assert_eq!(EncodeConvertArgument::__into_argument(42i32).0, 42);
@@ -242,18 +253,17 @@ mod tests {
             TypeId::of::<<i8 as EncodeConvertArgument>::__Inner>(),
             TypeId::of::<i8>()
         );
-        assert_eq!(<i8 as EncodeConvertArgument>::__from_declared_param(-3), -3);
+        assert_eq!(
+            unsafe { <i8 as EncodeConvertArgument>::__from_declared_param(-3) }.0,
+            -3
+        );
         assert_eq!(EncodeConvertArgument::__into_argument(-3i32).0, -3);
     }
 
     #[test]
     fn convert_bool() {
-        assert!(!<bool as EncodeConvertArgument>::__from_declared_param(
-            Bool::NO
-        ));
-        assert!(<bool as EncodeConvertArgument>::__from_declared_param(
-            Bool::YES
-        ));
+        assert!(!unsafe { <bool as EncodeConvertArgument>::__from_declared_param(Bool::NO) }.0);
+        assert!(unsafe { <bool as EncodeConvertArgument>::__from_declared_param(Bool::YES) }.0);
         assert!(!<bool as EncodeConvertReturn>::__from_return(Bool::NO));
         assert!(<bool as EncodeConvertReturn>::__from_return(Bool::YES));
 
@@ -0,0 +1,336 @@
+//! Hooks into the runtime's exception handling.
+//!
+//! These are not available on 32-bit macOS, since the legacy runtime used
+//! there doesn't expose them.
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::hint;
+#[cfg(feature = "gnustep-1-7")]
+use core::mem;
+use core::panic::AssertUnwindSafe;
+#[cfg(feature = "gnustep-1-7")]
+use core::ptr;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use super::Exception;
+use crate::ffi;
+
+type Hook = Arc<dyn Fn(&Exception) + Send + Sync>;
+
+/// A stack of hooks installed from Rust, along with the raw hook that was
+/// installed before any of them.
+struct HookState<R> {
+    next_id: usize,
+    hooks: Vec<(usize, Hook)>,
+    previous: Option<R>,
+}
+
+/// A global [`HookState`] protected by a spinlock (`Mutex::new` is not
+/// `const` in our MSRV).
+struct Hooks<R> {
+    lock: AtomicBool,
+    state: UnsafeCell<HookState<R>>,
+}
+
+// SAFETY: The state is only accessed while holding the lock, and the hooks
+// are `Send + Sync`.
+unsafe impl<R: Send> Sync for Hooks<R> {}
+
+impl<R: Copy> Hooks<R> {
+    const fn new() -> Self {
+        Self {
+            lock: AtomicBool::new(false),
+            state: UnsafeCell::new(HookState {
+                next_id: 0,
+                hooks: Vec::new(),
+                previous: None,
+            }),
+        }
+    }
+
+    /// Run the given closure with exclusive access to the state.
+    ///
+    /// The closure must not call the hooks.
+    fn with_lock<T>(&self, f: impl FnOnce(&mut HookState<R>) -> T) -> T {
+        while self
+            .lock
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            hint::spin_loop();
+        }
+        // SAFETY: We hold the lock.
+        let res = f(unsafe { &mut *self.state.get() });
+        self.lock.store(false, Ordering::Release);
+        res
+    }
+
+    /// Push a hook, installing `trampoline` with `swap` if it is the first.
+    fn push(&self, hook: Hook, trampoline: R, swap: unsafe fn(Option<R>) -> Option<R>) -> usize {
+        self.with_lock(|state| {
+            if state.hooks.is_empty() {
+                // SAFETY: The trampoline is a valid hook.
+                state.previous = unsafe { swap(Some(trampoline)) };
+            }
+            let id = state.next_id;
+            state.next_id += 1;
+            state.hooks.push((id, hook));
+            id
+        })
+    }
+
+    /// Remove a hook, restoring the previous raw hook if it was the last.
+    fn remove(&self, id: usize, swap: unsafe fn(Option<R>) -> Option<R>) {
+        let _hook = self.with_lock(|state| {
+            let index = state.hooks.iter().position(|(i, _)| *i == id)?;
+            let hook = state.hooks.remove(index);
+            if state.hooks.is_empty() {
+                // SAFETY: The previous hook was installed before ours, so it
+                // is valid to restore.
+                let _ = unsafe { swap(state.previous.take()) };
+            }
+            Some(hook)
+        });
+        // The hook is dropped outside the lock.
+    }
+
+    /// The innermost hook, and the raw hook that was installed before ours.
+    fn current(&self) -> (Option<Hook>, Option<R>) {
+        self.with_lock(|state| {
+            let hook = state.hooks.last().map(|(_, hook)| Arc::clone(hook));
+            (hook, state.previous)
+        })
+    }
+}
+
+/// Call the hook, aborting if it panics, since we cannot unwind into the
+/// runtime.
+fn call_hook(hook: &(dyn Fn(&Exception) + Send + Sync), exception: *mut ffi::objc_object) {
+    // SAFETY: The runtime passes a valid exception object.
+    let exception = match unsafe { exception.cast::<Exception>().as_ref() } {
+        Some(exception) => exception,
+        // `@throw nil`
+        None => return,
+    };
+    // The panic message is printed by the panic hook before we abort.
+    if std::panic::catch_unwind(AssertUnwindSafe(|| hook(exception))).is_err() {
+        std::process::abort();
+    }
+}
+
+/// Restores the previous hook when dropped.
+///
+/// Created with [`set_uncaught_exception_handler`] or
+/// [`set_exception_preprocessor`]. Hooks may be nested, in which case the
+/// most recently installed hook that hasn't been dropped is used.
+///
+/// Use [`mem::forget`] to keep the hook installed for the rest of the
+/// program.
+///
+/// [`mem::forget`]: core::mem::forget
+#[must_use = "the hook is removed when the guard is dropped"]
+pub struct ExceptionHookGuard {
+    id: usize,
+    remove: fn(usize),
+}
+
+impl Drop for ExceptionHookGuard {
+    fn drop(&mut self) {
+        (self.remove)(self.id);
+    }
+}
+
+impl fmt::Debug for ExceptionHookGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExceptionHookGuard")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+type UncaughtHandler = unsafe extern "C" fn(exception: *mut ffi::objc_object);
+
+static UNCAUGHT_HOOKS: Hooks<UncaughtHandler> = Hooks::new();
+
+unsafe fn swap_uncaught_handler(new: Option<UncaughtHandler>) -> Option<UncaughtHandler> {
+    #[cfg(feature = "apple")]
+    {
+        // The runtime always has a handler installed.
+        new.map(|new| unsafe { ffi::objc_setUncaughtExceptionHandler(new) })
+    }
+    #[cfg(feature = "gnustep-1-7")]
+    {
+        let hook = ptr::addr_of_mut!(ffi::_objc_unexpected_exception);
+        // SAFETY: The hook is only modified while holding our lock (unless
+        // someone else modifies it, which we can't protect against).
+        unsafe { mem::replace(&mut *hook, new) }
+    }
+}
+
+unsafe extern "C" fn uncaught_trampoline(exception: *mut ffi::objc_object) {
+    let (hook, previous) = UNCAUGHT_HOOKS.current();
+    if let Some(hook) = hook {
+        call_hook(&*hook, exception);
+    }
+    if let Some(previous) = previous {
+        // SAFETY: Forward to the previous handler.
+        unsafe { previous(exception) };
+    }
+}
+
+/// Install a handler that is called when an Objective-C exception is not
+/// caught, before the process is terminated.
+///
+/// This is useful for logging uncaught exceptions, or forwarding them to a
+/// crash reporter. The previously installed handler is called afterwards,
+/// which usually prints the exception and aborts.
+///
+/// If the handler panics, the panic message is printed, and the process is
+/// aborted, since we cannot unwind into the runtime. This can be used to
+/// turn uncaught exceptions into Rust panics, with the usual panic hook and
+/// backtrace.
+///
+/// The handler is uninstalled, and the previous handler restored, when the
+/// returned guard is dropped.
+///
+/// On GNUStep, this uses the `_objc_unexpected_exception` hook.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::exception::set_uncaught_exception_handler;
+///
+/// let guard = set_uncaught_exception_handler(|exception| {
+///     panic!("uncaught exception: {exception:?}");
+/// });
+/// // Keep the handler installed for the rest of the program.
+/// core::mem::forget(guard);
+/// ```
+#[doc(alias = "objc_setUncaughtExceptionHandler")]
+#[doc(alias = "NSSetUncaughtExceptionHandler")]
+pub fn set_uncaught_exception_handler(
+    handler: impl Fn(&Exception) + Send + Sync + 'static,
+) -> ExceptionHookGuard {
+    let id = UNCAUGHT_HOOKS.push(
+        Arc::new(handler),
+        uncaught_trampoline,
+        swap_uncaught_handler,
+    );
+    ExceptionHookGuard {
+        id,
+        remove: |id| UNCAUGHT_HOOKS.remove(id, swap_uncaught_handler),
+    }
+}
+
+#[cfg(feature = "apple")]
+type Preprocessor = unsafe extern "C" fn(exception: *mut ffi::objc_object) -> *mut ffi::objc_object;
+
+#[cfg(feature = "apple")]
+static PREPROCESSOR_HOOKS: Hooks<Preprocessor> = Hooks::new();
+
+#[cfg(feature = "apple")]
+unsafe fn swap_preprocessor(new: Option<Preprocessor>) -> Option<Preprocessor> {
+    // The runtime always has a preprocessor installed.
+    new.map(|new| unsafe { ffi::objc_setExceptionPreprocessor(new) })
+}
+
+#[cfg(feature = "apple")]
+unsafe extern "C" fn preprocessor_trampoline(
+    exception: *mut ffi::objc_object,
+) -> *mut ffi::objc_object {
+    let (hook, previous) = PREPROCESSOR_HOOKS.current();
+    if let Some(hook) = hook {
+        call_hook(&*hook, exception);
+    }
+    match previous {
+        // SAFETY: Forward to the previous preprocessor.
+        Some(previous) => unsafe { previous(exception) },
+        None => exception,
+    }
+}
+
+/// Install a hook that is called every time an Objective-C exception is
+/// thrown, before the runtime starts looking for a handler.
+///
+/// This sees every exception, including those that are later caught, and is
+/// useful for logging where exceptions originate from.
+///
+/// If the hook panics, the panic message is printed, and the process is
+/// aborted, since we cannot unwind into the runtime.
+///
+/// The hook is uninstalled, and the previous hook restored, when the
+/// returned guard is dropped.
+///
+/// This is only available on Apple's runtime.
+#[cfg(feature = "apple")]
+#[doc(alias = "objc_setExceptionPreprocessor")]
+pub fn set_exception_preprocessor(
+    hook: impl Fn(&Exception) + Send + Sync + 'static,
+) -> ExceptionHookGuard {
+    let id = PREPROCESSOR_HOOKS.push(Arc::new(hook), preprocessor_trampoline, swap_preprocessor);
+    ExceptionHookGuard {
+        id,
+        remove: |id| PREPROCESSOR_HOOKS.remove(id, swap_preprocessor),
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "exception")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uncaught_exception_handler_guards() {
+        fn current() -> Option<usize> {
+            unsafe {
+                let current = swap_uncaught_handler(Some(uncaught_trampoline));
+                let _ = swap_uncaught_handler(current);
+                current.map(|handler| handler as usize)
+            }
+        }
+
+        let initial = current();
+        let guard1 = set_uncaught_exception_handler(|_| {});
+        let trampoline = Some(uncaught_trampoline as UncaughtHandler as usize);
+        assert_eq!(current(), trampoline);
+        let guard2 = set_uncaught_exception_handler(|_| {});
+        // Dropped out of order
+        drop(guard1);
+        assert_eq!(current(), trampoline);
+        drop(guard2);
+        assert_eq!(current(), initial);
+    }
+
+    #[test]
+    #[cfg(feature = "apple")]
+    fn test_exception_preprocessor() {
+        use alloc::string::ToString;
+        use std::sync::Mutex;
+
+        use crate::exception::tests::new_exception;
+        use crate::exception::{catch, throw};
+
+        let reasons = Arc::new(Mutex::new(Vec::new()));
+        let guard = set_exception_preprocessor({
+            let reasons = Arc::clone(&reasons);
+            move |exception| {
+                reasons.lock().unwrap().push(exception.reason());
+            }
+        });
+
+        let exception = new_exception("MyException", "preprocessed");
+        let result = unsafe { catch::<()>(|| throw(exception)) };
+        assert!(result.is_err());
+        drop(guard);
+
+        let exception = new_exception("MyException", "not preprocessed");
+        let result = unsafe { catch::<()>(|| throw(exception)) };
+        assert!(result.is_err());
+
+        let reasons = reasons.lock().unwrap();
+        assert_eq!(&*reasons, &[Some("preprocessed".to_string())]);
+    }
+}
@@ -0,0 +1,685 @@
+//! # `@throw` and `@try/@catch` exceptions.
+//!
+//! By default, if the [`msg_send!`] macro causes an exception to be thrown,
+//! this will unwind into Rust, resulting in undefined behavior. However, this
+//! crate has an `"catch-all"` feature which, when enabled, wraps each
+//! [`msg_send!`] in a `@catch` and panics if an exception is caught,
+//! preventing Objective-C from unwinding into Rust.
+//!
+//! Most of the functionality in this module is only available when the
+//! `"exception"` feature is enabled.
+//!
+//! See the following links for more information:
+//! - [Exception Programming Topics for Cocoa](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/Exceptions/Exceptions.html)
+//! - [The Objective-C Programming Language - Exception Handling](https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/ObjectiveC/Chapters/ocExceptionHandling.html)
+//! - [Exception Handling in LLVM](https://llvm.org/docs/ExceptionHandling.html)
+//!
+//! [`msg_send!`]: crate::msg_send
+
+// TODO: Test this with panic=abort, and ensure that the code-size is
+// reasonable in that case.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(all(feature = "panic-to-exception", feature = "unstable-c-unwind"))]
+use core::any::Any;
+#[cfg(feature = "exception")]
+use core::ffi::c_void;
+use core::fmt;
+#[cfg(feature = "exception")]
+use core::mem;
+use core::ops::Deref;
+use core::panic::RefUnwindSafe;
+use core::panic::UnwindSafe;
+#[cfg(feature = "exception")]
+use core::ptr;
+use std::error::Error;
+
+use crate::encode::{Encoding, RefEncode};
+#[cfg(feature = "exception")]
+use crate::ffi;
+use crate::ffi::NSUInteger;
+use crate::rc::{autoreleasepool_leaking, Id};
+#[cfg(any(
+    all(feature = "panic-to-exception", feature = "unstable-c-unwind"),
+    all(test, feature = "exception")
+))]
+use crate::runtime::__nsstring::nsstring_from_str;
+use crate::runtime::__nsstring::nsstring_to_str;
+use crate::runtime::{AnyClass, AnyObject, NSObject, NSObjectProtocol};
+use crate::{extern_methods, sel, ClassType, Message};
+use crate::{msg_send, msg_send_id};
+
+#[cfg(any(
+    all(feature = "apple", not(all(target_os = "macos", target_arch = "x86"))),
+    feature = "gnustep-1-7"
+))]
+mod hooks;
+
+#[cfg(any(
+    all(feature = "apple", not(all(target_os = "macos", target_arch = "x86"))),
+    feature = "gnustep-1-7"
+))]
+pub use self::hooks::*;
+
+/// An Objective-C exception.
+///
+/// While highly recommended that any exceptions you intend to throw are
+/// subclasses of `NSException`, this is not required by the runtime (similar
+/// to how Rust can panic with arbitary payloads using [`panic_any`]).
+///
+/// [`panic_any`]: std::panic::panic_any
+#[repr(transparent)]
+pub struct Exception(AnyObject);
+
+unsafe impl RefEncode for Exception {
+    const ENCODING_REF: Encoding = Encoding::Object;
+}
+
+unsafe impl Message for Exception {}
+
+impl Deref for Exception {
+    type Target = AnyObject;
+
+    #[inline]
+    fn deref(&self) -> &AnyObject {
+        &self.0
+    }
+}
+
+impl AsRef<AnyObject> for Exception {
+    #[inline]
+    fn as_ref(&self) -> &AnyObject {
+        self
+    }
+}
+
+impl Exception {
+    /// Whether the object is an instance of the given class.
+    fn is_kind_of_class(&self, cls: &AnyClass) -> bool {
+        if self.class().responds_to(sel!(isKindOfClass:)) {
+            // SAFETY: We only use `isKindOfClass:` on NSObject
+            let obj: *const Exception = self;
+            let obj = unsafe { obj.cast::<NSObject>().as_ref().unwrap() };
+            obj.__isKindOfClass(cls)
+        } else {
+            false
+        }
+    }
+
+    /// Whether the object is an instance of the class with the given name.
+    fn is_kind_of(&self, name: &str) -> bool {
+        // Get class dynamically instead of with `class!` macro
+        AnyClass::get(name).map_or(false, |cls| self.is_kind_of_class(cls))
+    }
+
+    /// Whether the exception is an instance of `NSException`.
+    ///
+    /// Objective-C allows throwing any object, so this may be `false` if,
+    /// for example, an `NSString` was thrown.
+    pub fn is_nsexception(&self) -> bool {
+        self.is_kind_of("NSException")
+    }
+
+    /// Attempt to downcast the exception to the given class.
+    ///
+    /// This is an associated method, and must be called as
+    /// `Exception::downcast::<MyException>(exception)`.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns the exception unchanged if it is not an instance of `T` (or
+    /// one of its subclasses).
+    pub fn downcast<T: ClassType>(this: Id<Self>) -> Result<Id<T>, Id<Self>> {
+        if this.is_kind_of_class(T::class()) {
+            // SAFETY: Just checked that the object is an instance of `T`.
+            //
+            // Code throwing an exception know that they don't hold sole
+            // access to that object any more, so even if `T` is mutable, it
+            // is okay to create a new `Id` to it here.
+            Ok(unsafe { Id::cast(this) })
+        } else {
+            Err(this)
+        }
+    }
+
+    /// The name of the exception, such as `NSInvalidArgumentException`.
+    ///
+    /// Returns `None` if the exception is not an `NSException`.
+    pub fn name(&self) -> Option<String> {
+        if !self.is_nsexception() {
+            return None;
+        }
+        // SAFETY: Just checked that object is an NSException
+        let name = unsafe { self.__name() }?;
+        // SAFETY: `name` is guaranteed to be an NSString.
+        Some(autoreleasepool_leaking(|pool| unsafe {
+            nsstring_to_str(&name, pool).to_string()
+        }))
+    }
+
+    /// A human-readable description of why the exception was thrown.
+    ///
+    /// If the exception is not an `NSException`, such as when an `NSString`
+    /// was thrown, this is the `description` of the thrown object instead.
+    pub fn reason(&self) -> Option<String> {
+        let string = if self.is_nsexception() {
+            // SAFETY: Just checked that object is an NSException
+            unsafe { self.__reason() }
+        } else if self.class().responds_to(sel!(description)) {
+            // SAFETY: `description` is available on all `NSObject`s, and
+            // returns an `NSString`.
+            unsafe { msg_send_id![self, description] }
+        } else {
+            None
+        }?;
+        // SAFETY: The string is guaranteed to be an NSString.
+        Some(autoreleasepool_leaking(|pool| unsafe {
+            nsstring_to_str(&string, pool).to_string()
+        }))
+    }
+
+    /// The `userInfo` dictionary of the exception.
+    ///
+    /// This is an `NSDictionary`, and is `None` if the exception is not an
+    /// `NSException`, or if it has no `userInfo`.
+    pub fn user_info(&self) -> Option<Id<NSObject>> {
+        if self.is_nsexception() {
+            // SAFETY: Just checked that object is an NSException
+            unsafe { self.__user_info() }
+        } else {
+            None
+        }
+    }
+
+    /// The symbolicated call stack at the point where the exception was
+    /// first thrown, with one entry per frame.
+    ///
+    /// Empty if the exception is not an `NSException`, or if it was never
+    /// thrown.
+    pub fn call_stack_symbols(&self) -> Vec<String> {
+        if !self.is_nsexception() {
+            return Vec::new();
+        }
+        // SAFETY: Just checked that object is an NSException
+        let array = unsafe { self.__call_stack_symbols() };
+        autoreleasepool_leaking(|pool| {
+            // SAFETY: The array contains `NSString`s.
+            unsafe {
+                array_map(array.as_deref(), |obj| {
+                    nsstring_to_str(obj, pool).to_string()
+                })
+            }
+        })
+    }
+
+    /// The return addresses of the call stack at the point where the
+    /// exception was first thrown, with one entry per frame.
+    ///
+    /// Empty if the exception is not an `NSException`, or if it was never
+    /// thrown.
+    pub fn call_stack_return_addresses(&self) -> Vec<usize> {
+        if !self.is_nsexception() {
+            return Vec::new();
+        }
+        // SAFETY: Just checked that object is an NSException
+        let array = unsafe { self.__call_stack_return_addresses() };
+        // SAFETY: The array contains `NSNumber`s.
+        unsafe {
+            array_map(array.as_deref(), |obj| {
+                let addr: NSUInteger = msg_send![obj, unsignedIntegerValue];
+                addr as usize
+            })
+        }
+    }
+}
+
+/// Map each object in an `NSArray` with the given closure.
+///
+///
+/// # Safety
+///
+/// The object must be an `NSArray`, and `f` must be safe to call with each
+/// element.
+unsafe fn array_map<T>(array: Option<&NSObject>, f: impl Fn(&NSObject) -> T) -> Vec<T> {
+    let array = match array {
+        Some(array) => array,
+        None => return Vec::new(),
+    };
+    // SAFETY: The object is an `NSArray`.
+    let count: NSUInteger = unsafe { msg_send![array, count] };
+    (0..count)
+        .map(|i| {
+            // SAFETY: The index is in bounds, and the array is not modified
+            // while we're iterating, so the object stays alive.
+            let obj: &NSObject = unsafe { msg_send![array, objectAtIndex: i] };
+            f(obj)
+        })
+        .collect()
+}
+
+extern_methods!(
+    unsafe impl Exception {
+        // Only safe on NSException
+        // Returns NSString
+        #[method_id(name)]
+        unsafe fn __name(&self) -> Option<Id<NSObject>>;
+
+        // Only safe on NSException
+        // Returns NSString
+        #[method_id(reason)]
+        unsafe fn __reason(&self) -> Option<Id<NSObject>>;
+
+        // Only safe on NSException
+        // Returns NSDictionary
+        #[method_id(userInfo)]
+        unsafe fn __user_info(&self) -> Option<Id<NSObject>>;
+
+        // Only safe on NSException
+        // Returns NSArray<NSString>
+        #[method_id(callStackSymbols)]
+        unsafe fn __call_stack_symbols(&self) -> Option<Id<NSObject>>;
+
+        // Only safe on NSException
+        // Returns NSArray<NSNumber>
+        #[method_id(callStackReturnAddresses)]
+        unsafe fn __call_stack_return_addresses(&self) -> Option<Id<NSObject>>;
+    }
+);
+
+// Note: We can't implement `Send` nor `Sync` since the exception could be
+// anything!
+
+impl fmt::Debug for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "exception ")?;
+
+        // Attempt to present a somewhat usable error message if the exception
+        // is an instance of NSException.
+        if self.is_nsexception() {
+            let obj: &AnyObject = self.as_ref();
+            write!(f, "{obj:?} '{}'", self.name().unwrap_or_default())?;
+            if let Some(reason) = self.reason() {
+                write!(f, " reason:{reason}")
+            } else {
+                write!(f, " reason:(NULL)")
+            }
+        } else if self.is_kind_of("NSString") {
+            // Strings are sometimes thrown directly, e.g. `@throw @"..."`.
+            write!(f, "{:?} {:?}", self.0, self.reason().unwrap_or_default())
+        } else {
+            // Fall back to `AnyObject` Debug
+            write!(f, "{:?}", self.0)
+        }
+    }
+}
+
+impl fmt::Display for Exception {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(reason) = self.reason() {
+            write!(f, "{reason}")
+        } else {
+            write!(f, "unknown exception")
+        }
+    }
+}
+
+impl Error for Exception {}
+
+impl UnwindSafe for Exception {}
+impl RefUnwindSafe for Exception {}
+
+/// Throws an Objective-C exception.
+///
+/// This is the Objective-C equivalent of Rust's [`panic!`].
+///
+///
+/// # Safety
+///
+/// This unwinds from Objective-C, and the exception must be caught using an
+/// Objective-C exception handler like [`catch`] (and specifically not
+/// [`catch_unwind`]).
+///
+/// This also invokes undefined behaviour until `C-unwind` is stabilized, see
+/// [RFC-2945] - you can try this out on nightly using the `unstable-c-unwind`
+/// feature flag.
+///
+/// [`catch_unwind`]: std::panic::catch_unwind
+/// [RFC-2945]: https://rust-lang.github.io/rfcs/2945-c-unwind-abi.html
+#[inline]
+#[cfg(feature = "exception")] // For consistency, not strictly required
+pub unsafe fn throw(exception: Id<Exception>) -> ! {
+    // We consume the exception object since we can't make any guarantees
+    // about its mutability.
+    let ptr = exception.0.as_ptr() as *mut ffi::objc_object;
+    // SAFETY: The object is valid and non-null (nil exceptions are not valid
+    // in the old runtime).
+    unsafe { ffi::objc_exception_throw(ptr) }
+}
+
+/// Create an `NSException` describing a Rust panic, with the panic message
+/// as the reason.
+///
+/// Returns `None` if `NSException` is not available, or if creating it
+/// failed.
+#[cfg(all(feature = "panic-to-exception", feature = "unstable-c-unwind"))]
+pub(crate) fn from_panic(payload: &(dyn Any + Send)) -> Option<Id<Exception>> {
+    let message = if let Some(s) = payload.downcast_ref::<&'static str>() {
+        s
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.as_str()
+    } else {
+        "Box<dyn Any>"
+    };
+
+    let cls = AnyClass::get("NSException")?;
+    let name = nsstring_from_str("RustPanic")?;
+    let reason = nsstring_from_str(message)?;
+    let user_info: *const NSObject = ptr::null();
+    // SAFETY: The name and reason are `NSString`s, and the user info may be
+    // `nil`.
+    unsafe {
+        msg_send_id![
+            cls,
+            exceptionWithName: &*name,
+            reason: &*reason,
+            userInfo: user_info,
+        ]
+    }
+}
+
+#[cfg(feature = "exception")]
+unsafe fn try_no_ret<F: FnOnce()>(closure: F) -> Result<(), Option<Id<Exception>>> {
+    #[cfg(not(feature = "unstable-c-unwind"))]
+    let f = {
+        extern "C" fn try_objc_execute_closure<F>(closure: &mut Option<F>)
+        where
+            F: FnOnce(),
+        {
+            // This is always passed Some, so it's safe to unwrap
+            let closure = closure.take().unwrap();
+            closure();
+        }
+
+        let f: extern "C" fn(&mut Option<F>) = try_objc_execute_closure;
+        let f: extern "C" fn(*mut c_void) = unsafe { mem::transmute(f) };
+        f
+    };
+
+    #[cfg(feature = "unstable-c-unwind")]
+    let f = {
+        extern "C-unwind" fn try_objc_execute_closure<F>(closure: &mut Option<F>)
+        where
+            F: FnOnce(),
+        {
+            // This is always passed Some, so it's safe to unwrap
+            let closure = closure.take().unwrap();
+            closure();
+        }
+
+        let f: extern "C-unwind" fn(&mut Option<F>) = try_objc_execute_closure;
+        let f: extern "C-unwind" fn(*mut c_void) = unsafe { mem::transmute(f) };
+        f
+    };
+
+    // Wrap the closure in an Option so it can be taken
+    let mut closure = Some(closure);
+    let context: *mut Option<F> = &mut closure;
+    let context = context.cast();
+
+    let mut exception = ptr::null_mut();
+    let success = unsafe { ffi::try_catch(f, context, &mut exception) };
+
+    if success == 0 {
+        Ok(())
+    } else {
+        // SAFETY:
+        // The exception is always a valid object or NULL.
+        //
+        // Since we do a retain inside `extern/exception.m`, the object has
+        // +1 retain count.
+        //
+        // Code throwing an exception know that they don't hold sole access to
+        // that object any more, so even if the type was originally mutable,
+        // it is okay to create a new `Id` to it here.
+        Err(unsafe { Id::new(exception.cast()) })
+    }
+}
+
+/// Tries to execute the given closure and catches an Objective-C exception
+/// if one is thrown.
+///
+/// This is the Objective-C equivalent of Rust's [`catch_unwind`].
+/// Accordingly, if your Rust code is compiled with `panic=abort` this cannot
+/// catch the exception.
+///
+/// Returns a `Result` that is either `Ok` if the closure succeeded without an
+/// exception being thrown, or an `Err` with the exception. The exception is
+/// automatically released.
+///
+/// The exception is `None` in the extremely exceptional case that the
+/// exception object is `nil`. This should basically never happen, but is
+/// technically possible on some systems with `@throw nil`, or in OOM
+/// situations.
+///
+/// [`catch_unwind`]: std::panic::catch_unwind
+///
+///
+/// # Safety
+///
+/// The given closure must not panic (e.g. normal Rust unwinding into this
+/// causes undefined behaviour).
+///
+/// Additionally, this unwinds through the closure from Objective-C, which is
+/// undefined behaviour until `C-unwind` is stabilized, see [RFC-2945] - you
+/// can try this out on nightly using the `unstable-c-unwind` feature flag.
+///
+/// [RFC-2945]: https://rust-lang.github.io/rfcs/2945-c-unwind-abi.html
+#[cfg(feature = "exception")]
+pub unsafe fn catch<R>(
+    closure: impl FnOnce() -> R + UnwindSafe,
+) -> Result<R, Option<Id<Exception>>> {
+    let mut value = None;
+    let value_ref = &mut value;
+    let closure = move || {
+        *value_ref = Some(closure());
+    };
+    let result = unsafe { try_no_ret(closure) };
+    // If the try succeeded, this was set so it's safe to unwrap
+    result.map(|()| value.unwrap())
+}
+
+/// Tries to execute the given closure and catches an Objective-C exception
+/// if one is thrown, but only if it is an instance of `T` (or one of its
+/// subclasses).
+///
+/// This is the equivalent of `@catch (T *exception)` in Objective-C. Other
+/// exceptions are rethrown.
+///
+/// See [`catch`] for details.
+///
+///
+/// # Safety
+///
+/// Same as [`catch`], and additionally, other exceptions are rethrown, which
+/// has the same safety requirements as [`throw`].
+#[cfg(feature = "exception")]
+pub unsafe fn catch_typed<T: ClassType, R>(
+    closure: impl FnOnce() -> R + UnwindSafe,
+) -> Result<R, Id<T>> {
+    match unsafe { catch(closure) } {
+        Ok(value) => Ok(value),
+        Err(Some(exception)) => match Exception::downcast::<T>(exception) {
+            Ok(exception) => Err(exception),
+            // SAFETY: Upheld by the caller
+            Err(exception) => unsafe { throw(exception) },
+        },
+        // SAFETY: Rethrow the `nil` exception, the caller upholds that this
+        // is safe.
+        Err(None) => unsafe { ffi::objc_exception_throw(ptr::null_mut()) },
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "exception")]
+mod tests {
+    use alloc::format;
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::runtime::NSObject;
+
+    #[test]
+    fn test_catch() {
+        let mut s = "Hello".to_string();
+        let result = unsafe {
+            catch(move || {
+                s.push_str(", World!");
+                s
+            })
+        };
+        assert_eq!(result.unwrap(), "Hello, World!");
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "apple", target_os = "macos", target_arch = "x86"),
+        ignore = "`NULL` exceptions are invalid on 32-bit / w. fragile runtime"
+    )]
+    fn test_catch_null() {
+        let s = "Hello".to_string();
+        let result = unsafe {
+            catch(move || {
+                if !s.is_empty() {
+                    ffi::objc_exception_throw(ptr::null_mut())
+                }
+                s.len()
+            })
+        };
+        assert!(result.unwrap_err().is_none());
+    }
+
+    #[test]
+    fn test_throw_catch_object() {
+        let obj = NSObject::new();
+        // TODO: Investigate why this is required on GNUStep!
+        let _obj2 = obj.clone();
+        let obj: Id<Exception> = unsafe { Id::cast(obj) };
+        let ptr: *const Exception = &*obj;
+
+        let result = unsafe { catch(|| throw(obj)) };
+        let obj = result.unwrap_err().unwrap();
+
+        assert_eq!(format!("{obj:?}"), format!("exception <NSObject: {ptr:p}>"));
+
+        assert!(ptr::eq(&*obj, ptr));
+    }
+
+    pub(super) fn new_exception(name: &str, reason: &str) -> Id<Exception> {
+        let cls = AnyClass::get("NSException").unwrap();
+        let name = nsstring_from_str(name).unwrap();
+        let reason = nsstring_from_str(reason).unwrap();
+        let user_info: *const NSObject = ptr::null();
+        unsafe {
+            msg_send_id![
+                cls,
+                exceptionWithName: &*name,
+                reason: &*reason,
+                userInfo: user_info,
+            ]
+        }
+    }
+
+    #[test]
+    fn test_nsexception() {
+        let exception = new_exception("MyException", "something happened");
+        assert!(exception.is_nsexception());
+        assert_eq!(exception.name().as_deref(), Some("MyException"));
+        assert_eq!(exception.reason().as_deref(), Some("something happened"));
+        assert!(exception.user_info().is_none());
+        // Not thrown yet
+        assert!(exception.call_stack_return_addresses().is_empty());
+
+        let result = unsafe { catch::<()>(|| throw(exception)) };
+        let exception = result.unwrap_err().unwrap();
+        assert_eq!(exception.to_string(), "something happened");
+        assert_eq!(
+            exception.call_stack_symbols().len(),
+            exception.call_stack_return_addresses().len()
+        );
+    }
+
+    #[test]
+    fn test_throw_catch_string() {
+        let string = nsstring_from_str("a string").unwrap();
+        let string: Id<Exception> = unsafe { Id::cast(string) };
+
+        let result = unsafe { catch::<()>(|| throw(string)) };
+        let exception = result.unwrap_err().unwrap();
+
+        assert!(!exception.is_nsexception());
+        assert_eq!(exception.name(), None);
+        assert_eq!(exception.reason().as_deref(), Some("a string"));
+        assert_eq!(exception.to_string(), "a string");
+        assert!(format!("{exception:?}").ends_with(r#" "a string""#));
+        assert!(exception.call_stack_symbols().is_empty());
+    }
+
+    #[test]
+    fn test_catch_typed() {
+        let exception = new_exception("MyException", "reason");
+        let ptr: *const Exception = &*exception;
+        let result = unsafe { catch_typed::<NSObject, ()>(|| throw(exception)) };
+        assert!(ptr::eq(&*result.unwrap_err(), ptr.cast()));
+
+        // Exceptions that don't match are rethrown
+        let exception = new_exception("MyException", "reason");
+        let ptr: *const Exception = &*exception;
+        let result =
+            unsafe { catch(|| catch_typed::<crate::rc::__RcTestObject, ()>(|| throw(exception))) };
+        let exception = result.unwrap_err().unwrap();
+        assert!(ptr::eq(&*exception, ptr));
+
+        let exception = Exception::downcast::<crate::rc::__RcTestObject>(exception).unwrap_err();
+        assert!(ptr::eq(&*exception, ptr));
+        let exception = Exception::downcast::<NSObject>(exception).unwrap();
+        assert!(ptr::eq(&*exception, ptr.cast()));
+    }
+
+    #[test]
+    #[cfg(all(feature = "panic-to-exception", feature = "unstable-c-unwind"))]
+    fn test_panic_to_exception() {
+        use core::panic::AssertUnwindSafe;
+
+        use crate::mutability::InteriorMutable;
+        use crate::{declare_class, msg_send, ClassType};
+
+        declare_class!(
+            struct PanickingObject;
+
+            unsafe impl ClassType for PanickingObject {
+                type Super = NSObject;
+                type Mutability = InteriorMutable;
+                const NAME: &'static str = "PanickingObject";
+            }
+
+            unsafe impl PanickingObject {
+                #[method(panicWithValue:)]
+                fn panic_with_value(&self, value: i32) -> i32 {
+                    panic!("panicked with {value}");
+                }
+            }
+        );
+
+        let obj: Id<PanickingObject> = unsafe { msg_send_id![PanickingObject::class(), new] };
+        let res = unsafe {
+            catch(AssertUnwindSafe(|| -> i32 {
+                msg_send![&obj, panicWithValue: 42i32]
+            }))
+        };
+        let exception = res.unwrap_err().unwrap();
+        assert_eq!(exception.to_string(), "panicked with 42");
+        assert!(format!("{exception:?}").contains("'RustPanic'"));
+    }
+}
@@ -185,6 +185,7 @@ extern "C" {}
 pub use objc_sys as ffi;
 
 pub use self::class_type::ClassType;
+pub use self::declared_class::DeclaredClass;
 #[doc(no_inline)]
 pub use self::encode::{Encode, Encoding, RefEncode};
 pub use self::message::{Message, MessageArguments, MessageReceiver};
@@ -192,7 +193,7 @@ pub use self::protocol_type::ProtocolType;
 
 #[cfg(feature = "objc2-proc-macros")]
 #[doc(hidden)]
-pub use objc2_proc_macros::__hash_idents;
+pub use objc2_proc_macros::{__hash_class_name, __hash_idents};
 
 #[cfg(not(feature = "objc2-proc-macros"))]
 #[doc(hidden)]
@@ -208,6 +209,7 @@ macro_rules! __hash_idents {
 pub mod __macro_helpers;
 mod class_type;
 pub mod declare;
+mod declared_class;
 pub mod encode;
 pub mod exception;
 mod macros;
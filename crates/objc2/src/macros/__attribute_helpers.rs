@@ -224,6 +224,52 @@ macro_rules! __extract_custom_attributes_inner {
         }
     };
 
+    // `initialize` attribute
+    {
+        (
+            #[initialize]
+            $($rest:tt)*
+        )
+        // If no existing `method` nor `method_id` attributes exist
+        ()
+        ($($m_optional:tt)*)
+        ($($m_checked:tt)*)
+        ($name:ident)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $crate::__extract_custom_attributes_inner! {
+            ($($rest)*)
+            // Add initialize attribute
+            (#[initialize])
+            ($($m_optional)*)
+            ($($m_checked)*)
+            ($name)
+
+            ($out_macro)
+            $($macro_args)*
+        }
+    };
+    // Duplicate `initialize` attributes
+    {
+        (
+            #[initialize]
+            $($rest:tt)*
+        )
+        ($($m_method:tt)*)
+        ($($m_optional:tt)*)
+        ($($m_checked:tt)*)
+        ($name:ident)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        fn $name() {
+            compile_error!("cannot combine the `initialize` attribute with `method`/`method_id`, or specify it twice")
+        }
+    };
+
     // `optional` attribute
     {
         (
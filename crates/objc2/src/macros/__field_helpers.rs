@@ -101,6 +101,13 @@ macro_rules! __parse_fields {
             );
 
             pub(super) fn __objc2_declare_ivars(__objc2_builder: &mut $crate::declare::ClassBuilder) {}
+
+            pub struct Ivars {}
+
+            pub(super) unsafe fn __objc2_write_ivars(
+                __objc2_obj: $crate::__macro_helpers::NonNull<$crate::runtime::AnyObject>,
+                __objc2_ivars: Ivars,
+            ) {}
         }
 
         $out_macro! {
@@ -130,6 +137,36 @@ macro_rules! __parse_fields {
                 $(
                     __objc2_builder.add_static_ivar::<$ivar_type_name>();
                 )+
+
+                // Properties
+                $(
+                    $ivar_type_name::__objc2_declare_property(__objc2_builder);
+                )+
+            }
+
+            /// The initial values of the instance variables.
+            ///
+            /// Pass this to `Allocated::set_ivars` in an `init` method.
+            #[allow(unreachable_pub)]
+            pub struct Ivars {
+                $(
+                    pub(super) $ivar_type_name: <<$ivar_type_name as $crate::declare::IvarType>::Type as $crate::declare::InnerIvarType>::Output,
+                )+
+            }
+
+            pub(super) unsafe fn __objc2_write_ivars(
+                __objc2_obj: $crate::__macro_helpers::NonNull<$crate::runtime::AnyObject>,
+                __objc2_ivars: Ivars,
+            ) {
+                $(
+                    // SAFETY: Upheld by caller
+                    unsafe {
+                        $crate::__macro_helpers::write_ivar::<$ivar_type_name>(
+                            __objc2_obj,
+                            __objc2_ivars.$ivar_type_name,
+                        )
+                    };
+                )+
             }
         }
 
@@ -179,55 +216,71 @@ macro_rules! __parse_fields {
     // IvarDrop
     (
         (
-            $(#[$m:meta])*
+            $(#[$($m:tt)*])*
             $vis:vis $field_name:ident: IvarDrop<$ty:ty, $ivar_name:literal>
             $(, $($rest_fields:tt)*)?
         )
-        ($($ivar_helper_module_v:vis mod $ivar_helper_module:ident)?)
-        ($($ivar_output:tt)*) ($($ivar_type_name:ident)*)
-        ($($parsed_fields:tt)*)
-
-        ($out_macro:path)
-        $($macro_args:tt)*
+        $($args:tt)*
     ) => {
-        $crate::__parse_fields! {
+        $crate::__extract_property_attribute! {
+            ($(#[$($m)*])*)
+            () ()
+
+            ($crate::__parse_fields_ivar)
+            (IvarDrop<$ty>)
+            ($vis $field_name: $ivar_name)
             ($($($rest_fields)*)?)
-            ($($ivar_helper_module_v mod $ivar_helper_module)?)
-            (
-                $($ivar_output)*
+            ($($args)*)
+        }
+    };
 
-                #[allow(non_camel_case_types)]
-                #[allow(unreachable_pub)]
-                pub struct $field_name {
-                    __priv: (),
-                }
+    // IvarEncode
+    (
+        (
+            $(#[$($m:tt)*])*
+            $vis:vis $field_name:ident: IvarEncode<$ty:ty, $ivar_name:literal>
+            $(, $($rest_fields:tt)*)?
+        )
+        $($args:tt)*
+    ) => {
+        $crate::__extract_property_attribute! {
+            ($(#[$($m)*])*)
+            () ()
 
-                // SAFETY:
-                // - The ivars are in a type used as an Objective-C object.
-                // - The ivar is added to the class in `__objc2_declare_ivars`.
-                // - Caller upholds that the ivars are properly initialized.
-                unsafe impl $crate::declare::IvarType for $field_name {
-                    type Type = IvarDrop<$ty>;
-                    const NAME: &'static $crate::__macro_helpers::str = $ivar_name;
-                }
-            ) ($($ivar_type_name)* $field_name)
-            (
-                $($parsed_fields)*
+            ($crate::__parse_fields_ivar)
+            (IvarEncode<$ty>)
+            ($vis $field_name: $ivar_name)
+            ($($($rest_fields)*)?)
+            ($($args)*)
+        }
+    };
 
-                $(#[$m])*
-                $vis $field_name: $crate::declare::Ivar<$($ivar_helper_module ::)? $field_name>,
-            )
+    // IvarBool
+    (
+        (
+            $(#[$($m:tt)*])*
+            $vis:vis $field_name:ident: IvarBool<$ivar_name:literal>
+            $(, $($rest_fields:tt)*)?
+        )
+        $($args:tt)*
+    ) => {
+        $crate::__extract_property_attribute! {
+            ($(#[$($m)*])*)
+            () ()
 
-            ($out_macro)
-            $($macro_args)*
+            ($crate::__parse_fields_ivar)
+            (IvarBool)
+            ($vis $field_name: $ivar_name)
+            ($($($rest_fields)*)?)
+            ($($args)*)
         }
     };
 
-    // IvarEncode
+    // Invalid type
     (
         (
             $(#[$m:meta])*
-            $vis:vis $field_name:ident: IvarEncode<$ty:ty, $ivar_name:literal>
+            $vis:vis $field_name:ident: $ty:ty
             $(, $($rest_fields:tt)*)?
         )
         ($($ivar_helper_module_v:vis mod $ivar_helper_module:ident)?)
@@ -237,52 +290,52 @@ macro_rules! __parse_fields {
         ($out_macro:path)
         $($macro_args:tt)*
     ) => {
+        $crate::__macro_helpers::compile_error!($crate::__macro_helpers::concat!(
+            "invalid type ",
+            $crate::__macro_helpers::stringify!($ty),
+            " in field ",
+            $crate::__macro_helpers::stringify!($field_name),
+            ". Type must be either `PhantomData`, `IvarDrop`, `IvarBool` or `IvarEncode`."
+        ));
+
         $crate::__parse_fields! {
             ($($($rest_fields)*)?)
             ($($ivar_helper_module_v mod $ivar_helper_module)?)
-            (
-                $($ivar_output)*
-
-                #[allow(non_camel_case_types)]
-                #[allow(unreachable_pub)]
-                pub struct $field_name {
-                    __priv: (),
-                }
-
-                // SAFETY: See above
-                unsafe impl $crate::declare::IvarType for $field_name {
-                    type Type = IvarEncode<$ty>;
-                    const NAME: &'static $crate::__macro_helpers::str = $ivar_name;
-                }
-            ) ($($ivar_type_name)* $field_name)
+            ($($ivar_output)*) ($($ivar_type_name)*)
             (
                 $($parsed_fields)*
 
                 $(#[$m])*
-                $vis $field_name: $crate::declare::Ivar<$($ivar_helper_module ::)? $field_name>,
+                $vis $field_name: $ty,
             )
 
             ($out_macro)
             $($macro_args)*
         }
-    };
+    }
+}
 
-    // IvarBool
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_fields_ivar {
     (
+        ($ivar_type:ty)
+        ($vis:vis $field_name:ident: $ivar_name:literal)
+        ($($rest_fields:tt)*)
         (
-            $(#[$m:meta])*
-            $vis:vis $field_name:ident: IvarBool<$ivar_name:literal>
-            $(, $($rest_fields:tt)*)?
+            ($($ivar_helper_module_v:vis mod $ivar_helper_module:ident)?)
+            ($($ivar_output:tt)*) ($($ivar_type_name:ident)*)
+            ($($parsed_fields:tt)*)
+
+            ($out_macro:path)
+            $($macro_args:tt)*
         )
-        ($($ivar_helper_module_v:vis mod $ivar_helper_module:ident)?)
-        ($($ivar_output:tt)*) ($($ivar_type_name:ident)*)
-        ($($parsed_fields:tt)*)
 
-        ($out_macro:path)
-        $($macro_args:tt)*
+        ($($m:tt)*)
+        ($($property:tt)*)
     ) => {
         $crate::__parse_fields! {
-            ($($($rest_fields)*)?)
+            ($($rest_fields)*)
             ($($ivar_helper_module_v mod $ivar_helper_module)?)
             (
                 $($ivar_output)*
@@ -293,16 +346,24 @@ macro_rules! __parse_fields {
                     __priv: (),
                 }
 
-                // SAFETY: See above
+                // SAFETY:
+                // - The ivars are in a type used as an Objective-C object.
+                // - The ivar is added to the class in `__objc2_declare_ivars`.
+                // - Caller upholds that the ivars are properly initialized.
                 unsafe impl $crate::declare::IvarType for $field_name {
-                    type Type = IvarBool;
+                    type Type = $ivar_type;
                     const NAME: &'static $crate::__macro_helpers::str = $ivar_name;
                 }
+
+                $crate::__declare_ivar_property! {
+                    ($field_name)
+                    ($($property)*)
+                }
             ) ($($ivar_type_name)* $field_name)
             (
                 $($parsed_fields)*
 
-                $(#[$m])*
+                $($m)*
                 $vis $field_name: $crate::declare::Ivar<$($ivar_helper_module ::)? $field_name>,
             )
 
@@ -310,42 +371,294 @@ macro_rules! __parse_fields {
             $($macro_args)*
         }
     };
+}
 
-    // Invalid type
-    (
+/// Split out the `#[property(...)]` attribute from the rest of the
+/// attributes on an instance variable.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __extract_property_attribute {
+    // Base case
+    {
+        ()
+        ($($m_kept:tt)*)
+        ($($property:tt)*)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $out_macro! {
+            $($macro_args)*
+            // Append attributes to the end of the macro arguments
+            ($($m_kept)*)
+            ($($property)*)
+        }
+    };
+
+    // `property` attribute
+    {
         (
-            $(#[$m:meta])*
-            $vis:vis $field_name:ident: $ty:ty
-            $(, $($rest_fields:tt)*)?
+            #[property($($args:tt)*)]
+            $($rest:tt)*
         )
-        ($($ivar_helper_module_v:vis mod $ivar_helper_module:ident)?)
-        ($($ivar_output:tt)*) ($($ivar_type_name:ident)*)
-        ($($parsed_fields:tt)*)
+        ($($m_kept:tt)*)
+        ()
 
         ($out_macro:path)
         $($macro_args:tt)*
+    } => {
+        $crate::__extract_property_attribute! {
+            ($($rest)*)
+            ($($m_kept)*)
+            ($($args)*)
+
+            ($out_macro)
+            $($macro_args)*
+        }
+    };
+
+    // Duplicate `property` attributes
+    {
+        (
+            #[property($($args:tt)*)]
+            $($rest:tt)*
+        )
+        ($($m_kept:tt)*)
+        ($($property:tt)+)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $crate::__macro_helpers::compile_error!("cannot specify the `property` attribute twice");
+
+        $crate::__extract_property_attribute! {
+            ($($rest)*)
+            ($($m_kept)*)
+            ($($property)+)
+
+            ($out_macro)
+            $($macro_args)*
+        }
+    };
+
+    // Other attributes
+    {
+        (
+            #[$($m:tt)*]
+            $($rest:tt)*
+        )
+        ($($m_kept:tt)*)
+        ($($property:tt)*)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $crate::__extract_property_attribute! {
+            ($($rest)*)
+            ($($m_kept)* #[$($m)*])
+            ($($property)*)
+
+            ($out_macro)
+            $($macro_args)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_ivar_property {
+    // No `#[property(...)]` attribute
+    (
+        ($field_name:ident)
+        ()
+    ) => {
+        impl $field_name {
+            #[inline]
+            fn __objc2_declare_property(_: &mut $crate::declare::ClassBuilder) {}
+        }
+    };
+
+    (
+        ($field_name:ident)
+        ($name:ident $(, $($flags:tt)*)?)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($flags)*)?)
+            ($field_name)
+            ($name)
+            // Ownership, readonly and atomic
+            () (false) (false)
+        }
+    };
+
+    (
+        ($field_name:ident)
+        ($($property:tt)*)
     ) => {
         $crate::__macro_helpers::compile_error!($crate::__macro_helpers::concat!(
-            "invalid type ",
-            $crate::__macro_helpers::stringify!($ty),
-            " in field ",
+            "invalid property attribute on field ",
             $crate::__macro_helpers::stringify!($field_name),
-            ". Type must be either `PhantomData`, `IvarDrop`, `IvarBool` or `IvarEncode`."
+            ". Expected `#[property(name, ...)]`.",
         ));
 
-        $crate::__parse_fields! {
-            ($($($rest_fields)*)?)
-            ($($ivar_helper_module_v mod $ivar_helper_module)?)
-            ($($ivar_output)*) ($($ivar_type_name)*)
-            (
-                $($parsed_fields)*
+        $crate::__declare_ivar_property! {
+            ($field_name)
+            ()
+        }
+    };
 
-                $(#[$m])*
-                $vis $field_name: $ty,
-            )
+    // Done parsing flags
+    (
+        @()
+        ($field_name:ident)
+        ($name:ident)
+        ($($ownership:ident)?) ($readonly:literal) ($atomic:literal)
+    ) => {
+        // SAFETY: The property is backed by the instance variable above, and
+        // `register_property` checks that the attributes are valid for it.
+        unsafe impl $crate::__macro_helpers::DeclaredProperty for $field_name {
+            const PROPERTY_NAME: &'static $crate::__macro_helpers::str =
+                $crate::__macro_helpers::stringify!($name);
+            const OWNERSHIP: $crate::__macro_helpers::Option<$crate::declare::PropertyOwnership> =
+                $crate::__declare_ivar_property!(@ownership $($ownership)?);
+            const READONLY: $crate::__macro_helpers::bool = $readonly;
+            const ATOMIC: $crate::__macro_helpers::bool = $atomic;
+        }
 
-            ($out_macro)
-            $($macro_args)*
+        impl $field_name {
+            #[inline]
+            fn __objc2_declare_property(__objc2_builder: &mut $crate::declare::ClassBuilder) {
+                $crate::__macro_helpers::register_property::<Self>(__objc2_builder);
+            }
         }
-    }
+    };
+
+    (@ownership) => {
+        $crate::__macro_helpers::None
+    };
+    (@ownership $ownership:ident) => {
+        $crate::__macro_helpers::Some($crate::declare::PropertyOwnership::$ownership)
+    };
+
+    // `readonly`
+    (
+        @(readonly $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        ($($ownership:ident)?) ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            ($($ownership)?) (true) ($atomic)
+        }
+    };
+
+    // `atomic`
+    (
+        @(atomic $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        ($($ownership:ident)?) ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            ($($ownership)?) ($readonly) (true)
+        }
+    };
+
+    // `nonatomic`
+    (
+        @(nonatomic $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        ($($ownership:ident)?) ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            ($($ownership)?) ($readonly) (false)
+        }
+    };
+
+    // Ownership
+    (
+        @(strong $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        () ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            (Strong) ($readonly) ($atomic)
+        }
+    };
+    (
+        @(copy $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        () ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            (Copy) ($readonly) ($atomic)
+        }
+    };
+    (
+        @(weak $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        () ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            (Weak) ($readonly) ($atomic)
+        }
+    };
+    (
+        @(assign $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        () ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            (Assign) ($readonly) ($atomic)
+        }
+    };
+
+    // Duplicate ownership, or unknown flag
+    (
+        @($flag:tt $(, $($rest:tt)*)?)
+        ($field_name:ident)
+        ($name:ident)
+        ($($ownership:ident)?) ($readonly:literal) ($atomic:literal)
+    ) => {
+        $crate::__macro_helpers::compile_error!($crate::__macro_helpers::concat!(
+            "invalid property attribute `",
+            $crate::__macro_helpers::stringify!($flag),
+            "` on field ",
+            $crate::__macro_helpers::stringify!($field_name),
+            ". Expected one of `strong`, `copy`, `weak`, `assign`, `readonly`, `atomic` or `nonatomic`, and at most one of the ownership attributes.",
+        ));
+
+        $crate::__declare_ivar_property! {
+            @($($($rest)*)?)
+            ($field_name)
+            ($name)
+            ($($ownership)?) ($readonly) ($atomic)
+        }
+    };
 }
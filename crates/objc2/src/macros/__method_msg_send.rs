@@ -142,6 +142,7 @@ macro_rules! __method_msg_send_id {
     ) => {
         $crate::__msg_send_id_helper! {
             @(send_message_id)
+            @(MsgSendId)
             @($receiver)
             @($($retain_semantics)?)
             @($sel)
@@ -228,6 +229,7 @@ macro_rules! __method_msg_send_id {
     ) => {
         $crate::__msg_send_id_helper! {
             @(send_message_id)
+            @(MsgSendId)
             @($receiver)
             @($($retain_semantics)?)
             @($($sel_parsed)*)
@@ -249,6 +251,7 @@ macro_rules! __method_msg_send_id {
         $crate::__msg_send_id_helper! {
             // Use error method
             @(send_message_id_error)
+            @(MsgSendId)
             @($receiver)
             @($($retain_semantics)?)
             @($($sel_parsed)* $sel :)
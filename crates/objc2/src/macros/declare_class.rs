@@ -50,9 +50,97 @@
 /// superclass' instance variables - this means is is good practice to name
 /// them with a prefix of your crate name, or similar.
 ///
+/// The ivar helper module additionally contains a struct `Ivars`, with a
+/// field of the same name for each instance variable. In an `init` method,
+/// you can use [`Allocated::set_ivars`] with this to initialize all the
+/// instance variables at once, and then call the superclass' initializer
+/// with [`msg_send_id![super(this), init]`][crate::msg_send_id]. This
+/// ensures at compile-time that no instance variable is left uninitialized.
+///
+/// [`Allocated::set_ivars`]: crate::rc::Allocated::set_ivars
+///
 /// [`declare::IvarType`]: crate::declare::IvarType
 ///
 ///
+/// ## Ivars struct
+///
+/// Alternatively, the instance variables can be stored in a single Rust
+/// struct, by declaring the class as a unit struct, and specifying the type
+/// of the instance variables in a [`DeclaredClass`] implementation just after
+/// the `ClassType` implementation:
+///
+/// ```ignore
+/// struct MyObjectIvars {
+///     counter: Cell<u32>,
+///     names: Vec<String>,
+/// }
+///
+/// declare_class!(
+///     struct MyObject;
+///
+///     unsafe impl ClassType for MyObject {
+///         // ...
+///     }
+///
+///     impl DeclaredClass for MyObject {
+///         type Ivars = MyObjectIvars;
+///     }
+///
+///     // Methods and protocols
+/// );
+/// ```
+///
+/// The struct does not need to implement [`Encode`], and is stored in a
+/// single hidden instance variable whose name is derived from
+/// [`ClassType::NAME`], so it doesn't conflict with any superclass' instance
+/// variables. It must be initialized with [`Allocated::set_ivars`] in every
+/// `init` method, after which it can be accessed with
+/// [`DeclaredClass::ivars`] (or [`DeclaredClass::ivars_mut`]). The struct is
+/// dropped in the generated `dealloc` method, after any `Drop` impl on the
+/// class has run.
+///
+/// [`DeclaredClass`]: crate::DeclaredClass
+/// [`DeclaredClass::ivars`]: crate::DeclaredClass::ivars
+/// [`DeclaredClass::ivars_mut`]: crate::DeclaredClass::ivars_mut
+/// [`Encode`]: crate::Encode
+///
+///
+/// ## Properties
+///
+/// An instance variable can additionally be exposed to Objective-C as a
+/// declared property by putting `#[property(name, ...)]` on the field, where
+/// `name` is the name of the property, and the rest are attributes like in
+/// an Objective-C `@property` declaration:
+/// - One of `strong`, `copy`, `weak` or `assign`. The default is `strong`
+///   for `IvarDrop<Id<T>>` and `IvarDrop<Option<Id<T>>>`, `weak` for
+///   `IvarDrop<WeakId<T>>` and `assign` otherwise.
+/// - `readonly`, in which case no setter is generated.
+/// - `atomic` or `nonatomic`. The default is the same as for
+///   [`PropertyAttributes`].
+///
+/// The property is registered with the class, and a getter `name` and a
+/// setter `setName:` are generated that follow the given memory management
+/// semantics. The setter sends `willChangeValueForKey:` and
+/// `didChangeValueForKey:` when the object has key-value observers.
+///
+/// ```ignore
+/// struct MyObject {
+///     #[property(title, copy)]
+///     title: IvarDrop<Option<Id<NSString>>, "_title">,
+///     #[property(isEnabled, readonly)]
+///     enabled: IvarBool<"_enabled">,
+/// }
+/// ```
+///
+/// Non-optional `Id<T>`, `WeakId<T>` and `Unretained<T>` instance variables
+/// must still be initialized in an `init` method before the property is
+/// used. Setting a property backed by a non-optional `Id<T>` or
+/// `Unretained<T>` to `nil` from Objective-C leaves the value unchanged
+/// (and fails a debug assertion).
+///
+/// [`PropertyAttributes`]: crate::declare::PropertyAttributes
+///
+///
 /// ## `ClassType` implementation
 ///
 /// This also resembles that in [`extern_class!`], except that
@@ -60,9 +148,19 @@
 /// entire application. Good practice here is to include your crate name in
 /// the prefix.
 ///
+/// Libraries that may be linked into a process several times (e.g. in
+/// different versions) can use [`unique_class_name!`] to append a hash to
+/// the name, which is then what `NAME` contains.
+///
+/// [`unique_class_name!`]: crate::unique_class_name
+///
 /// The class is guaranteed to have been created and registered with the
 /// Objective-C runtime after the [`ClassType::class`] function has been
-/// called.
+/// called. Declared classes are always registered at runtime in this way,
+/// the `"unstable-static-class"` feature only affects references to
+/// existing classes with [`class!`] and [`extern_class!`].
+///
+/// [`class!`]: crate::class
 ///
 /// The macro will generate a `dealloc` method for you, which will call any
 /// [`Drop`] impl you may have defined on the type.
@@ -78,7 +176,9 @@
 /// Objective-C equivalents "class methods" and "instance methods". In
 /// particular, if you use `self` or the special name `this` (or `_this`),
 /// your method will be registered as an instance method, and if you don't it
-/// will be registered as a class method.
+/// will be registered as a class method. Class methods may take the special
+/// first argument `cls: &AnyClass`, which is the class that received the
+/// message (this may be a subclass, if the method is inherited).
 ///
 /// On instance methods, you can freely choose between different types of
 /// receivers, e.g. `&self`, `this: *const Self`, `&mut self`, and so on. Note
@@ -109,8 +209,49 @@
 /// make it behave similarly to the Objective-C `BOOL`. Use [`runtime::Bool`]
 /// if you want to control this manually.
 ///
-/// Note that `&mut Id<_>` and other such out parameters are not yet
-/// supported, and may generate a panic at runtime.
+/// An associated function without arguments or a return type can be marked
+/// with `#[initialize]` instead, in which case it is registered as the
+/// class' `+initialize` method. The runtime calls this once, before the
+/// class (or one of its subclasses) receives its first message. Since the
+/// runtime also sends `+initialize` to subclasses that don't implement it
+/// themselves, the function is only run when it is sent to exactly this
+/// class. Class-level state can be stored in a `static` using
+/// [`declare::ClassState`], which may be initialized from here.
+///
+/// There is no equivalent for `+load`; the runtime only sends it to classes
+/// that exist when an image is loaded, while declared classes are registered
+/// lazily the first time [`ClassType::class`] is called. Code that should
+/// run when the class is set up can be put in `#[initialize]` instead.
+///
+/// [`declare::ClassState`]: crate::declare::ClassState
+///
+/// Out parameters are supported with `&mut Id<_>`, `&mut Option<Id<_>>`,
+/// `Option<&mut Id<_>>` and `Option<&mut Option<Id<_>>>` (the latter
+/// corresponding to e.g. `NSError **` in Objective-C). The value in the out
+/// parameter is retained while the method runs, and autoreleased when it
+/// returns, as the `__autoreleasing` convention requires.
+///
+/// Note that this means the caller must pass an initialized slot (either
+/// `NULL` or a valid object) for `&mut Option<Id<_>>` parameters, since the
+/// current value is read and retained. Error out parameters in particular
+/// are often left uninitialized by callers, so prefer the `Result` return
+/// type described below for those.
+///
+/// Additionally, similar to how [`msg_send!`] handles `error: _`, a method
+/// may return `Result<(), Id<E>>` if it uses `#[method(...)]`, or
+/// `Result<Id<T>, Id<E>>` if it uses `#[method_id(...)]`. In that case, the
+/// Objective-C method gets an extra `E **` out parameter as its last
+/// argument (so the selector must have one more argument than the Rust
+/// function), and returns `NO` or `nil` respectively on failure, storing the
+/// autoreleased error in the out parameter if it is not `NULL`. The return
+/// type must be spelled exactly as `Result<_, Id<_>>` for this to work.
+///
+/// ```ignore
+/// #[method(writeToURL:error:)]
+/// fn write_to_url(&self, url: &NSURL) -> Result<(), Id<NSError>> {
+///     // ...
+/// }
+/// ```
 ///
 /// ["associated functions"]: https://doc.rust-lang.org/reference/items/associated-items.html#methods
 /// ["methods"]: https://doc.rust-lang.org/reference/items/associated-items.html#methods
@@ -121,6 +262,36 @@
 /// [`runtime::Bool`]: crate::runtime::Bool
 ///
 ///
+/// ## Subclassing declared classes
+///
+/// The superclass may itself have been declared with `declare_class!`, and
+/// such hierarchies can be arbitrarily deep.
+///
+/// Overridden methods can call the superclass' implementation with
+/// [`msg_send![super(self), ...]`][`msg_send!`] in instance methods, and
+/// with `msg_send![super(cls, Self), ...]` in class methods (which must then
+/// take `cls: &AnyClass`, spelled exactly like that, as their first
+/// argument). These look up the superclass statically from
+/// [`ClassType::Super`], which means that they continue to work when the
+/// method is called on a subclass or an instance of a subclass (in contrast
+/// to e.g. `super(self, self.class().superclass().unwrap())`, which would
+/// recurse infinitely in that case).
+///
+/// The class dereferences to its superclass, so the superclass' Rust methods
+/// and (if declared with `IvarEncode` and similar) its instance variables
+/// can be used directly. If the classes store their instance variables in
+/// an ivars struct, [`DeclaredClass::ivars`] returns the struct of the class
+/// that it is called on, so use `self.as_super().ivars()` to access the
+/// superclass' ivars.
+///
+/// Each class in the hierarchy runs its own `Drop` implementation and drops
+/// its own instance variables in its generated `dealloc` method, before
+/// calling the superclass' `dealloc`. The subclass' state is thus dropped
+/// first, while the superclass' state is still available.
+///
+/// [`ClassType::Super`]: crate::ClassType::Super
+///
+///
 /// ## Protocol implementations
 ///
 /// You can specify protocols that the class should implement, along with any
@@ -147,8 +318,23 @@
 ///   equal to the one on the superclass.
 /// - The `verify` feature and debug assertions are enabled, and the required
 ///   protocol methods are not implemented.
+/// - A property's attributes are not supported by the type of its instance
+///   variable, or one of its accessors is also defined in the method
+///   definitions.
 /// - And possibly more similar cases.
 ///
+/// Additionally, [`DeclaredClass::ivars`] panics if the ivars struct was not
+/// initialized with [`Allocated::set_ivars`].
+///
+/// Panicking inside a declared method unwinds into the Objective-C code that
+/// called it, which is undefined behaviour. If the `"panic-to-exception"`
+/// feature is enabled, panics are instead caught at the method boundary,
+/// and the process is aborted. If the `"unstable-c-unwind"` feature is also
+/// enabled, the methods use the `"C-unwind"` ABI, and the panic is instead
+/// rethrown as an `NSException` named `RustPanic` with the panic message as
+/// its reason. A panic in a `Drop` implementation, which is run from
+/// `dealloc`, always aborts the process.
+///
 ///
 /// # Safety
 ///
@@ -160,7 +346,12 @@
 /// - [`ClassType::Mutability`] must be correct.
 /// - Any instance variables you specify under the struct definition must
 ///   either be able to be created using [`MaybeUninit::zeroed`], or be
-///   properly initialized in an `init` method.
+///   properly initialized in an `init` method (which is always the case if
+///   you use [`Allocated::set_ivars`]).
+/// - If an instance variable is declared as a `copy` property, copying the
+///   object must return an object of the same type. Additionally, the
+///   property's setter must not be called while a reference to the instance
+///   variable is alive.
 ///
 /// `unsafe impl T { ... }` asserts that the types match those that are
 /// expected when the method is invoked from Objective-C. Note that unlike
@@ -189,8 +380,8 @@
 /// # use objc2::runtime::{__NSCopying as NSCopying, NSObject, NSObjectProtocol, NSZone};
 /// # #[cfg(available_elsewhere)]
 /// use icrate::Foundation::{NSCopying, NSObject, NSObjectProtocol, NSZone};
-/// use objc2::declare::{Ivar, IvarDrop, IvarEncode};
-/// use objc2::rc::Id;
+/// use objc2::declare::{IvarDrop, IvarEncode};
+/// use objc2::rc::{Allocated, Id};
 /// use objc2::{
 ///     declare_class, extern_protocol, msg_send, msg_send_id, mutability, ClassType, ProtocolType,
 /// };
@@ -211,29 +402,19 @@
 ///     }
 ///
 ///     unsafe impl MyCustomObject {
-///         #[method(initWithFoo:)]
-///         fn init_with(this: &mut Self, foo: u8) -> Option<&mut Self> {
-///             let this: Option<&mut Self> = unsafe {
-///                 msg_send![super(this), init]
-///             };
-///
-///             this.map(|this| {
-///                 // Initialize instance variables
-///
-///                 // Some types like `u8`, `bool`, `Option<Box<T>>` and
-///                 // `Option<Id<T>>` are safe to zero-initialize, and we can
-///                 // write to the variable as normal:
-///                 *this.foo = foo;
-///                 *this.bar = 42;
-///
-///                 // For others like `&u8`, `Box<T>` or `Id<T>`, we have to
-///                 // initialize them with `Ivar::write`:
-///                 Ivar::write(&mut this.object, NSObject::new());
-///
-///                 // All the instance variables have been initialized; our
-///                 // initializer is sound
-///                 this
-///             })
+///         #[method_id(initWithFoo:)]
+///         fn init_with(this: Allocated<Self>, foo: u8) -> Option<Id<Self>> {
+///             // Initialize instance variables. Every field must be given a
+///             // value, so we can't forget to initialize e.g. `object`.
+///             let this = this.set_ivars(ivars::Ivars {
+///                 foo,
+///                 bar: 42,
+///                 object: NSObject::new(),
+///             });
+///
+///             // All the instance variables have been initialized; now we
+///             // can initialize the superclass.
+///             unsafe { msg_send_id![super(this), init] }
 ///         }
 ///
 ///         #[method(foo)]
@@ -398,6 +579,7 @@ macro_rules! declare_class {
 
         $crate::__inner_declare_class! {
             ($ivar_helper_module)
+            ()
 
             unsafe impl ClassType for $for {
                 $(#[inherits($($inheritance_rest),+)])?
@@ -444,6 +626,65 @@ macro_rules! declare_class {
 
         $crate::__inner_declare_class! {
             ()
+            ()
+
+            unsafe impl ClassType for $for {
+                $(#[inherits($($inheritance_rest),+)])?
+                type Super = $superclass;
+
+                type Mutability = $mutability;
+
+                const NAME: &'static str = $name_const;
+            }
+
+            $($methods)*
+        }
+    };
+
+    // Instance variables stored in a single struct
+    {
+        $(#[$m:meta])*
+        $v:vis struct $name:ident;
+
+        unsafe impl ClassType for $for:ty {
+            $(#[inherits($($inheritance_rest:ty),+)])?
+            type Super = $superclass:ty;
+
+            type Mutability = $mutability:ty;
+
+            const NAME: &'static str = $name_const:expr;
+        }
+
+        impl DeclaredClass for $for_declared:ty {
+            type Ivars = $ivars:ty;
+        }
+
+        $($methods:tt)*
+    } => {
+        $crate::__emit_struct_and_ivars! {
+            ($(#[$m])*)
+            ($v)
+            ($name)
+            ()
+            ()
+            (
+                // Superclasses are deallocated by calling `[super dealloc]`.
+                __superclass: $crate::__macro_helpers::ManuallyDrop<$superclass>,
+                // The instance variables are stored in a hidden instance
+                // variable, but the auto traits of the class should still
+                // depend on them.
+                __ivars: $crate::__macro_helpers::PhantomData<$ivars>,
+            )
+        }
+
+        const _: fn() = $crate::__macro_helpers::assert_declared_class_is_class_type::<
+            $for,
+            $for_declared,
+        >;
+
+        $crate::__inner_declare_class! {
+            ()
+            ($ivars)
 
             unsafe impl ClassType for $for {
                 $(#[inherits($($inheritance_rest),+)])?
@@ -488,6 +729,7 @@ macro_rules! declare_class {
 
         $crate::__inner_declare_class! {
             ()
+            ()
 
             unsafe impl ClassType for $for {
                 $(#[inherits($($inheritance_rest),+)])?
@@ -508,6 +750,7 @@ macro_rules! declare_class {
 macro_rules! __inner_declare_class {
     {
         ($($ivar_helper_module:ident)?)
+        ($($ivars:ty)?)
 
         unsafe impl ClassType for $for:ty {
             $(#[inherits($($inheritance_rest:ty),+)])?
@@ -560,6 +803,12 @@ macro_rules! __inner_declare_class {
                     });
 
                     $($ivar_helper_module::__objc2_declare_ivars(&mut __objc2_builder);)?
+                    $(
+                        $crate::__macro_helpers::register_ivars::<$ivars>(
+                            &mut __objc2_builder,
+                            <Self as ClassType>::NAME,
+                        );
+                    )?
 
                     // See the following links for more details:
                     // - <https://clang.llvm.org/docs/AutomaticReferenceCounting.html#dealloc>
@@ -573,7 +822,16 @@ macro_rules! __inner_declare_class {
                         //
                         // This also runs any `Drop` impl that the type may
                         // have.
-                        unsafe { $crate::__macro_helpers::drop_in_place(__objc2_self) };
+                        $crate::__macro_helpers::dealloc_boundary(|| unsafe {
+                            $crate::__macro_helpers::drop_in_place(__objc2_self);
+
+                            // Drop the instance variables stored in the
+                            // `Ivars` struct, if any. This is done after
+                            // the `Drop` impl, since that may use them.
+                            <$for as $crate::DeclaredClass>::__drop_ivars(
+                                $crate::__macro_helpers::NonNull::new_unchecked(__objc2_self),
+                            );
+                        });
 
                         // The superclass' "marker" that this stores is
                         // wrapped in `ManuallyDrop`, instead we drop it by
@@ -591,7 +849,9 @@ macro_rules! __inner_declare_class {
                         }
                     }
 
-                    if $crate::__macro_helpers::needs_drop::<Self>() {
+                    if $crate::__macro_helpers::needs_drop::<Self>()
+                        $(|| $crate::__macro_helpers::needs_drop::<$ivars>())?
+                    {
                         unsafe {
                             __objc2_builder.add_method(
                                 $crate::sel!(dealloc),
@@ -624,6 +884,12 @@ macro_rules! __inner_declare_class {
             }
         }
 
+        $crate::__declare_class_ivars! {
+            ($for)
+            ($($ivar_helper_module)?)
+            ($($ivars)?)
+        }
+
         // Methods
         $crate::__declare_class_methods! {
             $($methods)*
@@ -631,6 +897,85 @@ macro_rules! __inner_declare_class {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_class_ivars {
+    // Instance variables stored in a single struct
+    {
+        ($for:ty)
+        ()
+        ($ivars:ty)
+    } => {
+        // SAFETY: `write_stored_ivars` initializes the hidden instance
+        // variable, and `drop_stored_ivars` is only called from `dealloc`.
+        unsafe impl $crate::DeclaredClass for $for {
+            type Ivars = $ivars;
+
+            #[inline]
+            unsafe fn __write_ivars(
+                __objc2_obj: $crate::__macro_helpers::NonNull<Self>,
+                __objc2_ivars: Self::Ivars,
+            ) {
+                // SAFETY: Upheld by caller
+                unsafe { $crate::__macro_helpers::write_stored_ivars(__objc2_obj, __objc2_ivars) }
+            }
+
+            #[inline]
+            unsafe fn __drop_ivars(__objc2_obj: $crate::__macro_helpers::NonNull<Self>) {
+                // SAFETY: Upheld by caller
+                unsafe { $crate::__macro_helpers::drop_stored_ivars(__objc2_obj) }
+            }
+        }
+
+        // SAFETY: The hidden instance variable is registered in
+        // `ClassType::class`.
+        unsafe impl $crate::__macro_helpers::StoredIvars for $for {
+            #[inline]
+            fn __ivars_offset() -> $crate::__macro_helpers::isize {
+                static __OBJC2_IVARS_OFFSET: $crate::__macro_helpers::AtomicIsize =
+                    $crate::__macro_helpers::AtomicIsize::new(0);
+                $crate::__macro_helpers::ivars_offset::<Self>(&__OBJC2_IVARS_OFFSET)
+            }
+        }
+    };
+
+    // Instance variables stored in fields
+    {
+        ($for:ty)
+        ($($ivar_helper_module:ident)?)
+        ()
+    } => {
+        // SAFETY: `__objc2_write_ivars` writes every instance variable.
+        unsafe impl $crate::DeclaredClass for $for {
+            type Ivars = $crate::__select_ivars!($($ivar_helper_module)?);
+
+            #[inline]
+            unsafe fn __write_ivars(
+                __objc2_obj: $crate::__macro_helpers::NonNull<Self>,
+                __objc2_ivars: Self::Ivars,
+            ) {
+                $(
+                    // SAFETY: Upheld by caller
+                    unsafe {
+                        $ivar_helper_module::__objc2_write_ivars(__objc2_obj.cast(), __objc2_ivars)
+                    };
+                )?
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __select_ivars {
+    () => {
+        ()
+    };
+    ($ivar_helper_module:ident) => {
+        $ivar_helper_module::Ivars
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __select_name {
@@ -797,6 +1142,72 @@ macro_rules! __declare_class_rewrite_methods {
         ($($macro_arg:tt)*)
     } => {};
 
+    // Unsafe variant, returning `Result`
+    {
+        ($out_macro:path)
+        ($($macro_arg:tt)*)
+
+        $(#[$($m:tt)*])*
+        unsafe fn $name:ident($($args:tt)*) -> Result<$ok:ty, Id<$err:ty>> $body:block
+
+        $($rest:tt)*
+    } => {
+        $crate::__declare_class_rewrite_self_arg! {
+            ($($args)*)
+
+            ($crate::__extract_custom_attributes)
+            ($(#[$($m)*])*)
+            ($name)
+
+            ($out_macro)
+            ($($macro_arg)*)
+            (unsafe)
+            ($name)
+            (@result $ok, $err)
+            ($body)
+        }
+
+        $crate::__declare_class_rewrite_methods! {
+            ($out_macro)
+            ($($macro_arg)*)
+
+            $($rest)*
+        }
+    };
+
+    // Safe variant, returning `Result`
+    {
+        ($out_macro:path)
+        ($($macro_arg:tt)*)
+
+        $(#[$($m:tt)*])*
+        fn $name:ident($($args:tt)*) -> Result<$ok:ty, Id<$err:ty>> $body:block
+
+        $($rest:tt)*
+    } => {
+        $crate::__declare_class_rewrite_self_arg! {
+            ($($args)*)
+
+            ($crate::__extract_custom_attributes)
+            ($(#[$($m)*])*)
+            ($name)
+
+            ($out_macro)
+            ($($macro_arg)*)
+            ()
+            ($name)
+            (@result $ok, $err)
+            ($body)
+        }
+
+        $crate::__declare_class_rewrite_methods! {
+            ($out_macro)
+            ($($macro_arg)*)
+
+            $($rest)*
+        }
+    };
+
     // Unsafe variant
     {
         ($out_macro:path)
@@ -807,7 +1218,7 @@ macro_rules! __declare_class_rewrite_methods {
 
         $($rest:tt)*
     } => {
-        $crate::__rewrite_self_arg! {
+        $crate::__declare_class_rewrite_self_arg! {
             ($($args)*)
 
             ($crate::__extract_custom_attributes)
@@ -840,7 +1251,7 @@ macro_rules! __declare_class_rewrite_methods {
 
         $($rest:tt)*
     } => {
-        $crate::__rewrite_self_arg! {
+        $crate::__declare_class_rewrite_self_arg! {
             ($($args)*)
 
             ($crate::__extract_custom_attributes)
@@ -864,6 +1275,72 @@ macro_rules! __declare_class_rewrite_methods {
     };
 }
 
+/// Like `__rewrite_self_arg!`, but additionally allows class methods to
+/// take the receiving class as a `cls: &AnyClass` argument.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_class_rewrite_self_arg {
+    {
+        ($($args:tt)*)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $crate::__declare_class_rewrite_self_arg_inner! {
+            ($($args)*)
+            ($($args)*)
+
+            ($out_macro)
+            $($macro_args)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_class_rewrite_self_arg_inner {
+    // Class method with the receiving class.
+    //
+    // Only matched when spelled exactly as `cls: &AnyClass`, so that class
+    // methods can still take an ordinary first argument named `cls`.
+    {
+        (cls: &AnyClass $(, $($__args_rest:tt)*)?)
+        ($cls:ident: $cls_ty:ty $(, $($args_rest:tt)*)?)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $out_macro! {
+            $($macro_args)*
+
+            (add_class_method)
+            ($cls)
+            ($cls_ty)
+            (
+                $cls: $cls_ty,
+                _: $crate::runtime::Sel,
+            )
+            ($($($args_rest)*)?)
+        }
+    };
+
+    // Everything else
+    {
+        ($($args:tt)*)
+        ($($__args:tt)*)
+
+        ($out_macro:path)
+        $($macro_args:tt)*
+    } => {
+        $crate::__rewrite_self_arg! {
+            ($($args)*)
+
+            ($out_macro)
+            $($macro_args)*
+        }
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __declare_class_method_out {
@@ -871,7 +1348,7 @@ macro_rules! __declare_class_method_out {
         ()
         ($($qualifiers:tt)*)
         ($name:ident)
-        ($($ret:ty)?)
+        ($($ret:tt)*)
         ($body:block)
 
         ($builder_method:ident)
@@ -893,7 +1370,7 @@ macro_rules! __declare_class_method_out {
 
             ($($qualifiers)*)
             ($name)
-            ($($ret)?)
+            ($($ret)*)
             ($body)
 
             ($builder_method)
@@ -943,7 +1420,16 @@ macro_rules! __declare_class_rewrite_args {
             ($($args_converted)* $param : <$param_ty as $crate::encode::__unstable::EncodeConvertArgument>::__Inner,)
             (
                 $($body_prefix)*
-                let mut $param = <$param_ty as $crate::encode::__unstable::EncodeConvertArgument>::__from_declared_param($param);
+                // The guard handles out parameters, and must be kept alive
+                // until the method body has run. This is run inside
+                // `method_boundary`, since the conversion may panic.
+                //
+                // SAFETY: The argument was passed from Objective-C, and
+                // upholds the requirements of its type.
+                #[allow(unused_unsafe)]
+                let (mut $param, __objc2_guard) = unsafe {
+                    <$param_ty as $crate::encode::__unstable::EncodeConvertArgument>::__from_declared_param($param)
+                };
             )
 
             ($out_macro)
@@ -964,7 +1450,16 @@ macro_rules! __declare_class_rewrite_args {
             ($($args_converted)* $param : <$param_ty as $crate::encode::__unstable::EncodeConvertArgument>::__Inner,)
             (
                 $($body_prefix)*
-                let $param = <$param_ty as $crate::encode::__unstable::EncodeConvertArgument>::__from_declared_param($param);
+                // The guard handles out parameters, and must be kept alive
+                // until the method body has run. This is run inside
+                // `method_boundary`, since the conversion may panic.
+                //
+                // SAFETY: The argument was passed from Objective-C, and
+                // upholds the requirements of its type.
+                #[allow(unused_unsafe)]
+                let ($param, __objc2_guard) = unsafe {
+                    <$param_ty as $crate::encode::__unstable::EncodeConvertArgument>::__from_declared_param($param)
+                };
             )
 
             ($out_macro)
@@ -992,6 +1487,94 @@ macro_rules! __declare_class_rewrite_args {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __declare_class_method_out_inner {
+    // #[method(...)], returning `Result`
+    {
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        (@result $ok:ty, $err:ty)
+        ($body:block)
+
+        ($__builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($args_prefix:tt)*)
+
+        (#[method($($__sel:tt)*)])
+        ($($__m_optional:tt)*)
+        ($($m_checked:tt)*)
+
+        ($($args_converted:tt)*)
+        ($($body_prefix:tt)*)
+    } => {
+        $crate::__declare_class_method_abi! {
+            $($m_checked)*
+            ($($qualifiers)*)
+            fn $name(
+                $($args_prefix)*
+                $($args_converted)*
+                __objc2_error: $crate::__macro_helpers::Option<&mut *mut $err>,
+            ) -> $crate::runtime::Bool {
+                let __objc2_result: $crate::__macro_helpers::Result<$ok, $crate::rc::Id<$err>> =
+                    $crate::__macro_helpers::method_boundary(|| {
+                        $($body_prefix)*
+                        $body
+                    });
+                #[allow(unreachable_code)]
+                $crate::__macro_helpers::bool_error_return(__objc2_result, __objc2_error)
+            }
+        }
+    };
+
+    // #[method_id(...)], returning `Result`
+    {
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        (@result $ok:ty, $err:ty)
+        ($body:block)
+
+        ($__builder_method:ident)
+        ($__receiver:expr)
+        ($receiver_ty:ty)
+        ($($args_prefix:tt)*)
+
+        (#[method_id($($sel:tt)*)])
+        ($($__m_optional:tt)*)
+        ($($m_checked:tt)*)
+
+        ($($args_converted:tt)*)
+        ($($body_prefix:tt)*)
+    } => {
+        $crate::__declare_class_method_abi! {
+            $($m_checked)*
+            ($($qualifiers)*)
+            fn $name(
+                $($args_prefix)*
+                $($args_converted)*
+                __objc2_error: $crate::__macro_helpers::Option<&mut *mut $err>,
+            ) -> $crate::declare::__IdReturnValue {
+                let __objc2_result: $crate::__macro_helpers::Result<$ok, $crate::rc::Id<$err>> =
+                    $crate::__macro_helpers::method_boundary(|| {
+                        $($body_prefix)*
+                        $body
+                    });
+                #[allow(unreachable_code)]
+                let __objc2_result = $crate::__macro_helpers::id_error_return(__objc2_result, __objc2_error);
+
+                <$crate::__macro_helpers::RetainSemantics<{
+                    $crate::__macro_helpers::retain_semantics(
+                        $crate::__sel_helper! {
+                            @()
+                            $($sel)*
+                        }
+                    )
+                }> as $crate::__macro_helpers::MessageRecieveId<
+                    $receiver_ty,
+                    $crate::__macro_helpers::Option<$ok>,
+                >>::into_return(__objc2_result)
+            }
+        }
+    };
+
     // #[method(...)]
     {
         ($($qualifiers:tt)*)
@@ -1011,14 +1594,20 @@ macro_rules! __declare_class_method_out_inner {
         ($($args_converted:tt)*)
         ($($body_prefix:tt)*)
     } => {
-        $($m_checked)*
-        $($qualifiers)* extern "C" fn $name(
-            $($args_prefix)*
-            $($args_converted)*
-        ) $(-> <$ret as $crate::encode::__unstable::EncodeConvertReturn>::__Inner)? {
-            $($body_prefix)*
-            $crate::__convert_result! {
-                $body $(; $ret)?
+        $crate::__declare_class_method_abi! {
+            $($m_checked)*
+            ($($qualifiers)*)
+            fn $name(
+                $($args_prefix)*
+                $($args_converted)*
+            ) $(-> <$ret as $crate::encode::__unstable::EncodeConvertReturn>::__Inner)? {
+                $crate::__convert_result! {
+                    {
+                        $($body_prefix)*
+                        $body
+                    }
+                    $(; $ret)?
+                }
             }
         }
     };
@@ -1041,28 +1630,95 @@ macro_rules! __declare_class_method_out_inner {
 
         ($($args_converted:tt)*)
         ($($body_prefix:tt)*)
+    } => {
+        $crate::__declare_class_method_abi! {
+            $($m_checked)*
+            ($($qualifiers)*)
+            fn $name(
+                $($args_prefix)*
+                $($args_converted)*
+            ) -> $crate::declare::__IdReturnValue {
+                let __objc2_result = $crate::__macro_helpers::method_boundary(|| {
+                    $($body_prefix)*
+                    $body
+                });
+
+                #[allow(unreachable_code)]
+                <$crate::__macro_helpers::RetainSemantics<{
+                    $crate::__macro_helpers::retain_semantics(
+                        $crate::__sel_helper! {
+                            @()
+                            $($sel)*
+                        }
+                    )
+                }> as $crate::__macro_helpers::MessageRecieveId<
+                    $receiver_ty,
+                    $ret,
+                >>::into_return(__objc2_result)
+            }
+        }
+    };
+
+    // #[initialize]
+    {
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        ()
+        ($body:block)
+
+        (add_class_method)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+
+        (#[initialize])
+        ($($__m_optional:tt)*)
+        ($($m_checked:tt)*)
+
+        ()
+        ()
+    } => {
+        $crate::__declare_class_method_abi! {
+            $($m_checked)*
+            ($($qualifiers)*)
+            fn $name(
+                __objc2_cls: &$crate::runtime::AnyClass,
+                _: $crate::runtime::Sel,
+            ) {
+                // The runtime also sends `+initialize` to subclasses that don't
+                // implement it themselves, so we must check that this is
+                // actually our class, to only run the hook once.
+                if $crate::__macro_helpers::ptr_eq(
+                    __objc2_cls,
+                    <Self as $crate::ClassType>::class(),
+                ) {
+                    $crate::__macro_helpers::method_boundary(|| $body)
+                }
+            }
+        }
+    };
+
+    {
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        ($($__ret:ty)?)
+        ($__body:block)
+
+        ($__builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+
+        (#[initialize])
+        ($($__m_optional:tt)*)
+        ($($m_checked:tt)*)
+
+        ($($__args_converted:tt)*)
+        ($($__body_prefix:tt)*)
     } => {
         $($m_checked)*
-        $($qualifiers)* extern "C" fn $name(
-            $($args_prefix)*
-            $($args_converted)*
-        ) -> $crate::declare::__IdReturnValue {
-            $($body_prefix)*
-
-            let __objc2_result = $body;
-
-            #[allow(unreachable_code)]
-            <$crate::__macro_helpers::RetainSemantics<{
-                $crate::__macro_helpers::retain_semantics(
-                    $crate::__sel_helper! {
-                        @()
-                        $($sel)*
-                    }
-                )
-            }> as $crate::__macro_helpers::MessageRecieveId<
-                $receiver_ty,
-                $ret,
-            >>::into_return(__objc2_result)
+        $($qualifiers)* extern "C" fn $name() {
+            compile_error!("`#[initialize]` must be an associated function without arguments or a return type")
         }
     };
 
@@ -1095,10 +1751,10 @@ macro_rules! __declare_class_method_out_inner {
 #[macro_export]
 macro_rules! __convert_result {
     ($body:block) => {
-        $body
+        $crate::__macro_helpers::method_boundary(|| $body)
     };
     ($body:block; $ret:ty) => {
-        let __objc2_result = $body;
+        let __objc2_result = $crate::__macro_helpers::method_boundary(|| $body);
         #[allow(unreachable_code)]
         <$ret as $crate::encode::__unstable::EncodeConvertReturn>::__into_declared_return(
             __objc2_result,
@@ -1109,6 +1765,76 @@ macro_rules! __convert_result {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __declare_class_register_out {
+    // #[method(...)], returning `Result`
+    {
+        ($builder:ident)
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        (@result $__ok:ty, $__err:ty)
+        ($__body:block)
+
+        ($builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+        ($($args_rest:tt)*)
+
+        (#[method($($sel:tt)*)])
+        () // No optional
+        ($($m_checked:tt)*)
+    } => {
+        $crate::__extract_and_apply_cfg_attributes! {
+            @($($m_checked)*)
+            @(
+                $builder.$builder_method(
+                    $crate::sel!($($sel)*),
+                    // The error out parameter is added as the last
+                    // argument; since all the arguments are `_`, it
+                    // doesn't matter where we put it here.
+                    Self::$name as $crate::__fn_ptr! {
+                        ($($qualifiers)*)
+                        (_, _, _,)
+                        $($args_rest)*
+                    },
+                );
+            )
+        }
+    };
+
+    // #[method_id(...)], returning `Result`
+    {
+        ($builder:ident)
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        (@result $__ok:ty, $__err:ty)
+        ($__body:block)
+
+        ($builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+        ($($args_rest:tt)*)
+
+        (#[method_id($($sel:tt)*)])
+        () // No optional
+        ($($m_checked:tt)*)
+    } => {
+        $crate::__extract_and_apply_cfg_attributes! {
+            @($($m_checked)*)
+            @(
+                $builder.$builder_method(
+                    $crate::__get_method_id_sel!($($sel)*),
+                    // Same as above
+                    Self::$name as $crate::__fn_ptr! {
+                        ($($qualifiers)*)
+                        (_, _, _,)
+                        $($args_rest)*
+                    },
+                );
+            )
+        }
+    };
+
     // #[method(dealloc)]
     {
         ($builder:ident)
@@ -1168,6 +1894,57 @@ macro_rules! __declare_class_register_out {
         }
     };
 
+    // #[initialize]
+    {
+        ($builder:ident)
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        ()
+        ($__body:block)
+
+        (add_class_method)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+        ()
+
+        (#[initialize])
+        () // No optional
+        ($($m_checked:tt)*)
+    } => {
+        $crate::__extract_and_apply_cfg_attributes! {
+            @($($m_checked)*)
+            @(
+                $builder.add_class_method(
+                    $crate::sel!(initialize),
+                    Self::$name as $crate::__fn_ptr! {
+                        ($($qualifiers)*)
+                        (_, _,)
+                    },
+                );
+            )
+        }
+    };
+    {
+        ($builder:ident)
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        ($($__ret:ty)?)
+        ($__body:block)
+
+        ($builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+        ($($args_rest:tt)*)
+
+        (#[initialize])
+        () // No optional
+        ($($m_checked:tt)*)
+    } => {
+        // Error is emitted in `__declare_class_method_out_inner`
+    };
+
     // #[method_id(...)]
     {
         ($builder:ident)
@@ -1261,6 +2038,39 @@ macro_rules! __get_method_id_sel {
     };
 }
 
+/// Add the ABI to a method implementation, or a function pointer type.
+///
+/// With the `"panic-to-exception"` feature, `method_boundary` throws an
+/// exception from the method, which is only sound if the method uses the
+/// `"C-unwind"` ABI.
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(all(feature = "panic-to-exception", feature = "unstable-c-unwind")))]
+macro_rules! __declare_class_method_abi {
+    (
+        $(#[$($m:tt)*])*
+        ($($qualifiers:tt)*)
+        fn $($rest:tt)*
+    ) => {
+        $(#[$($m)*])*
+        $($qualifiers)* extern "C" fn $($rest)*
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(feature = "panic-to-exception", feature = "unstable-c-unwind"))]
+macro_rules! __declare_class_method_abi {
+    (
+        $(#[$($m:tt)*])*
+        ($($qualifiers:tt)*)
+        fn $($rest:tt)*
+    ) => {
+        $(#[$($m)*])*
+        $($qualifiers)* extern "C-unwind" fn $($rest)*
+    };
+}
+
 /// Create function pointer type with inferred arguments.
 #[doc(hidden)]
 #[macro_export]
@@ -1270,7 +2080,10 @@ macro_rules! __fn_ptr {
         ($($output:tt)*)
         $(,)?
     ) => {
-        $($qualifiers)* extern "C" fn($($output)*) -> _
+        $crate::__declare_class_method_abi! {
+            ($($qualifiers)*)
+            fn($($output)*) -> _
+        }
     };
     (
         ($($qualifiers:tt)*)
@@ -0,0 +1,612 @@
+/// Declare a new Objective-C protocol, and create a trait to represent it.
+///
+/// This is similar to a `@protocol` declaration in Objective-C, in that it
+/// defines a new protocol, instead of describing an existing one like
+/// [`extern_protocol!`] does. The protocol is registered with the runtime
+/// the first time [`ProtocolType::protocol`] is called, which includes when
+/// a class implementing it in [`declare_class!`] is registered.
+///
+/// This is useful for defining interfaces in Rust that Objective-C code can
+/// implement or consume, for example the interface of a plugin.
+///
+/// [`extern_protocol!`]: crate::extern_protocol
+/// [`ProtocolType::protocol`]: crate::ProtocolType::protocol
+/// [`declare_class!`]: crate::declare_class
+///
+///
+/// # Specification
+///
+/// The syntax is the same as in [`extern_protocol!`], and the generated
+/// trait works the same way; it is implemented for
+/// [`ProtocolObject<dyn T>`], and its methods have default implementations
+/// that send the specified message.
+///
+/// Each method is added to the protocol with a type-encoding derived from
+/// its signature, as either an instance method or a class method, depending
+/// on whether it takes `self`. Methods are required, unless marked with
+/// `#[optional]`. Like in [`extern_methods!`], a selector ending with "_", as
+/// in `#[method(my:error:_)]`, declares a method that takes an implicit
+/// `NSError**` parameter, and returns a [`Result`].
+///
+/// Instance methods that take no arguments can additionally be marked with
+/// `#[property(name, ...)]`, which declares a property `name` on the
+/// protocol with the return type of the method. The attributes are the same
+/// as in [`declare_class!`]; the ownership defaults to `strong` for
+/// `#[method_id(...)]` and `assign` for `#[method(...)]`. The setter, if
+/// any, must be declared as a separate method.
+///
+/// Protocols that this protocol inherits from are specified as supertraits,
+/// and must be protocols that implement [`ProtocolType`], for example
+/// [`NSObjectProtocol`] or other protocols declared with this macro.
+///
+/// Argument and return types cannot refer to `Self`.
+///
+/// [`ProtocolObject<dyn T>`]: crate::runtime::ProtocolObject
+/// [`extern_methods!`]: crate::extern_methods
+/// [`ProtocolType`]: crate::ProtocolType
+/// [`NSObjectProtocol`]: crate::runtime::NSObjectProtocol
+///
+///
+/// # Panics
+///
+/// Registering the protocol panics if a protocol with the same name already
+/// exists. As with classes declared with [`declare_class!`], the name should
+/// therefore be unique across the entire application.
+///
+///
+/// # Safety
+///
+/// The following are required for using the macro itself:
+/// - Any `unsafe` methods must be correctly specified, such that the default
+///   implementations are sound.
+///
+/// While the following are required when implementing the `unsafe` trait for
+/// a new type:
+/// - The type must represent an object that implements the protocol. This is
+///   upheld when the trait is implemented in [`declare_class!`].
+///
+///
+/// # Examples
+///
+/// Declare a protocol for plugins, and implement it on a class.
+///
+/// ```
+/// use objc2::rc::Id;
+/// use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+/// use objc2::{declare_class, declare_protocol, msg_send_id, mutability};
+/// use objc2::{ClassType, ProtocolType};
+///
+/// declare_protocol!(
+///     /// The interface that plugins must implement.
+///     pub unsafe trait MyPlugin: NSObjectProtocol {
+///         #[method(version)]
+///         fn version(&self) -> u32;
+///
+///         #[method(canHandleKind:)]
+///         fn canHandleKind(&self, kind: u32) -> bool;
+///
+///         // Declares `@property (readonly) BOOL isEnabled`
+///         #[optional]
+///         #[property(isEnabled, readonly)]
+///         #[method(isEnabled)]
+///         fn isEnabled(&self) -> bool;
+///
+///         #[method_id(defaultPlugin)]
+///         fn defaultPlugin() -> Id<NSObject>;
+///     }
+///
+///     unsafe impl ProtocolType for dyn MyPlugin {
+///         // Remember to make the name unique
+///         const NAME: &'static str = "MyCrateMyPlugin";
+///     }
+/// );
+///
+/// declare_class!(
+///     struct MyPluginImpl;
+///
+///     unsafe impl ClassType for MyPluginImpl {
+///         type Super = NSObject;
+///         type Mutability = mutability::Immutable;
+///         const NAME: &'static str = "MyCrateMyPluginImpl";
+///     }
+///
+///     unsafe impl NSObjectProtocol for MyPluginImpl {}
+///
+///     unsafe impl MyPlugin for MyPluginImpl {
+///         #[method(version)]
+///         fn version(&self) -> u32 {
+///             2
+///         }
+///
+///         #[method(canHandleKind:)]
+///         fn canHandleKind(&self, kind: u32) -> bool {
+///             kind < 3
+///         }
+///
+///         #[method_id(defaultPlugin)]
+///         fn defaultPlugin() -> Id<NSObject> {
+///             NSObject::new()
+///         }
+///     }
+/// );
+///
+/// let protocol = <dyn MyPlugin>::protocol().unwrap();
+/// assert!(MyPluginImpl::class().conforms_to(protocol));
+///
+/// let obj: Id<MyPluginImpl> = unsafe { msg_send_id![MyPluginImpl::class(), new] };
+/// let plugin: &ProtocolObject<dyn MyPlugin> = ProtocolObject::from_ref(&*obj);
+/// assert_eq!(plugin.version(), 2);
+/// assert!(plugin.canHandleKind(1));
+/// ```
+#[doc(alias = "@protocol")]
+#[macro_export]
+macro_rules! declare_protocol {
+    (
+        $(#[$m:meta])*
+        $v:vis unsafe trait $name:ident $(: $conforms_to:ident $(+ $conforms_to_rest:ident)*)? {
+            $($methods:tt)*
+        }
+
+        $(#[$impl_m:meta])*
+        unsafe impl ProtocolType for dyn $for:ident {
+            $(const NAME: &'static str = $name_const:expr;)?
+        }
+    ) => {
+        $(#[$m])*
+        $v unsafe trait $name $(: $conforms_to $(+ $conforms_to_rest)*)? {
+            $crate::__declare_protocol_rewrite_methods! {
+                ($crate::__declare_protocol_method_out)
+                ()
+
+                $($methods)*
+            }
+        }
+
+        $crate::__inner_extern_protocol!(
+            ($(#[$impl_m])*)
+            ($name)
+            (dyn $for)
+            ($crate::__select_name!($name; $($name_const)?))
+            (
+                fn protocol() -> $crate::__macro_helpers::Option<&'static $crate::runtime::AnyProtocol> {
+                    // TODO: Use `core::cell::LazyCell`
+                    static REGISTER_PROTOCOL: $crate::__macro_helpers::Once = $crate::__macro_helpers::Once::new();
+
+                    REGISTER_PROTOCOL.call_once(|| {
+                        let mut __objc2_builder = $crate::declare::ProtocolBuilder::new(
+                            <Self as $crate::ProtocolType>::NAME,
+                        ).unwrap_or_else(|| {
+                            $crate::__macro_helpers::panic!(
+                                "could not create new protocol {}. Perhaps a protocol with that name already exists?",
+                                <Self as $crate::ProtocolType>::NAME,
+                            )
+                        });
+
+                        $(
+                            $crate::__macro_helpers::add_inherited_protocol::<dyn $conforms_to>(
+                                &mut __objc2_builder,
+                            );
+                            $(
+                                $crate::__macro_helpers::add_inherited_protocol::<dyn $conforms_to_rest>(
+                                    &mut __objc2_builder,
+                                );
+                            )*
+                        )?
+
+                        $crate::__declare_protocol_rewrite_methods! {
+                            ($crate::__declare_protocol_register_method)
+                            (__objc2_builder)
+
+                            $($methods)*
+                        }
+
+                        let _ = __objc2_builder.register();
+                    });
+
+                    $crate::runtime::AnyProtocol::get(<Self as $crate::ProtocolType>::NAME)
+                }
+            )
+        );
+    };
+}
+
+/// tt-munch each protocol method, and split out the `#[property(...)]`
+/// attribute.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_rewrite_methods {
+    // Base case
+    {
+        ($out_macro:path)
+        ($($macro_args:tt)*)
+    } => {};
+
+    // Unsafe variant
+    {
+        ($out_macro:path)
+        ($($macro_args:tt)*)
+
+        $(#[$($m:tt)*])*
+        $v:vis unsafe fn $name:ident($($args:tt)*) $(-> $ret:ty)?
+        // TODO: Handle where bounds better
+        $(where $($where:ty : $bound:path),+ $(,)?)?;
+
+        $($rest:tt)*
+    } => {
+        $crate::__extract_property_attribute! {
+            ($(#[$($m)*])*)
+            ()
+            ()
+
+            ($out_macro)
+            ($($macro_args)*)
+            ($v unsafe fn $name($($args)*) $(-> $ret)? $(where $($where : $bound),+)?;)
+            ($name)
+            ($($args)*)
+            ($($ret)?)
+        }
+
+        $crate::__declare_protocol_rewrite_methods! {
+            ($out_macro)
+            ($($macro_args)*)
+
+            $($rest)*
+        }
+    };
+
+    // Safe variant
+    {
+        ($out_macro:path)
+        ($($macro_args:tt)*)
+
+        $(#[$($m:tt)*])*
+        $v:vis fn $name:ident($($args:tt)*) $(-> $ret:ty)?
+        // TODO: Handle where bounds better
+        $(where $($where:ty : $bound:path),+ $(,)?)?;
+
+        $($rest:tt)*
+    } => {
+        $crate::__extract_property_attribute! {
+            ($(#[$($m)*])*)
+            ()
+            ()
+
+            ($out_macro)
+            ($($macro_args)*)
+            ($v fn $name($($args)*) $(-> $ret)? $(where $($where : $bound),+)?;)
+            ($name)
+            ($($args)*)
+            ($($ret)?)
+        }
+
+        $crate::__declare_protocol_rewrite_methods! {
+            ($out_macro)
+            ($($macro_args)*)
+
+            $($rest)*
+        }
+    };
+}
+
+/// Emit the trait method, same as in `extern_protocol!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_method_out {
+    {
+        ()
+        ($($function:tt)*)
+        ($__name:ident)
+        ($($__args:tt)*)
+        ($($__ret:ty)?)
+
+        ($($m_kept:tt)*)
+        ($($__property:tt)*)
+    } => {
+        $crate::__extern_protocol_rewrite_methods! {
+            $($m_kept)*
+            $($function)*
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_register_method {
+    {
+        ($builder:ident)
+        ($($__function:tt)*)
+        ($name:ident)
+        ($($args:tt)*)
+        ($($ret:ty)?)
+
+        ($($m_kept:tt)*)
+        ($($property:tt)*)
+    } => {
+        $crate::__rewrite_self_arg! {
+            ($($args)*)
+
+            ($crate::__extract_custom_attributes)
+            ($($m_kept)*)
+            ($name)
+
+            ($crate::__declare_protocol_register_out)
+            ($builder)
+            ($crate::__declare_protocol_return_type!($($ret)?))
+            ($($property)*)
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_register_out {
+    // #[method(...)]
+    {
+        ($builder:ident)
+        ($ret:ty)
+        ($($property:tt)*)
+
+        ($builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+        ($($args_rest:tt)*)
+
+        (#[method($($sel:tt)*)])
+        ($($m_optional:tt)*)
+        ($($m_checked:tt)*)
+    } => {
+        $crate::__extract_and_apply_cfg_attributes! {
+            @($($m_checked)*)
+            @(
+                $builder.__add_method_description::<
+                    $crate::__declare_protocol_arg_types!(() $($args_rest)*)
+                >(
+                    $crate::__declare_protocol_sel!(() $($sel)*),
+                    <$ret as $crate::__macro_helpers::ProtocolMethodReturn>::ENCODING_RETURN,
+                    <$ret as $crate::__macro_helpers::ProtocolMethodReturn>::HAS_ERROR_PARAMETER,
+                    $crate::__declare_protocol_is_required!($($m_optional)*),
+                    $crate::__declare_protocol_is_instance!($builder_method),
+                );
+
+                $crate::__declare_protocol_property! {
+                    ($builder)
+                    ($($property)*)
+                    (<$ret as $crate::__macro_helpers::ProtocolMethodReturn>::ENCODING_RETURN)
+                    (Assign)
+                    ($crate::__declare_protocol_is_required!($($m_optional)*))
+                    ($builder_method)
+                    ($($args_rest)*)
+                }
+            )
+        }
+    };
+
+    // #[method_id(...)]
+    {
+        ($builder:ident)
+        ($ret:ty)
+        ($($property:tt)*)
+
+        ($builder_method:ident)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__args_prefix:tt)*)
+        ($($args_rest:tt)*)
+
+        (#[method_id($($sel:tt)*)])
+        ($($m_optional:tt)*)
+        ($($m_checked:tt)*)
+    } => {
+        $crate::__extract_and_apply_cfg_attributes! {
+            @($($m_checked)*)
+            @(
+                $builder.__add_method_description::<
+                    $crate::__declare_protocol_arg_types!(() $($args_rest)*)
+                >(
+                    $crate::__declare_protocol_sel!(() $($sel)*),
+                    <$ret as $crate::__macro_helpers::ProtocolMethodIdReturn>::ENCODING_RETURN,
+                    <$ret as $crate::__macro_helpers::ProtocolMethodIdReturn>::HAS_ERROR_PARAMETER,
+                    $crate::__declare_protocol_is_required!($($m_optional)*),
+                    $crate::__declare_protocol_is_instance!($builder_method),
+                );
+
+                $crate::__declare_protocol_property! {
+                    ($builder)
+                    ($($property)*)
+                    (<$ret as $crate::__macro_helpers::ProtocolMethodIdReturn>::ENCODING_RETURN)
+                    (Strong)
+                    ($crate::__declare_protocol_is_required!($($m_optional)*))
+                    ($builder_method)
+                    ($($args_rest)*)
+                }
+            )
+        }
+    };
+}
+
+/// Create the selector, without the trailing `_` of methods returning
+/// `Result`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_sel {
+    (($($parsed:tt)*) _) => {
+        $crate::sel!($($parsed)*)
+    };
+    (($($parsed:tt)*)) => {
+        $crate::sel!($($parsed)*)
+    };
+    (($($parsed:tt)*) $t:tt $($rest:tt)*) => {
+        $crate::__declare_protocol_sel!(($($parsed)* $t) $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_return_type {
+    () => {
+        ()
+    };
+    ($ret:ty) => {
+        $ret
+    };
+}
+
+/// Convert the arguments of a method to a tuple of their types.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_arg_types {
+    (($($output:ty,)*) $(,)?) => {
+        ($($output,)*)
+    };
+    (($($output:ty,)*) $_param:tt : $param_ty:ty $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_arg_types!(($($output,)* $param_ty,) $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_is_required {
+    () => {
+        true
+    };
+    (#[optional]) => {
+        false
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_is_instance {
+    (add_method) => {
+        true
+    };
+    (add_class_method) => {
+        false
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_property {
+    // No `#[property(...)]` attribute
+    (
+        ($builder:ident)
+        ()
+        ($($__rest:tt)*)
+        ($__ownership:ident)
+        ($__required:expr)
+        ($__builder_method:ident)
+        ($($__args:tt)*)
+    ) => {};
+
+    (
+        ($builder:ident)
+        ($name:ident $(, $($flags:tt)*)?)
+        ($encoding:expr)
+        ($ownership:ident)
+        ($required:expr)
+        (add_method)
+        ()
+    ) => {
+        $builder.add_property(
+            $crate::__macro_helpers::stringify!($name),
+            &$encoding,
+            $crate::__declare_protocol_property_attributes!(
+                ($crate::declare::PropertyAttributes::new()
+                    .ownership($crate::declare::PropertyOwnership::$ownership))
+                $($($flags)*)?
+            ),
+            $required,
+        );
+    };
+
+    (
+        ($builder:ident)
+        ($($property:tt)*)
+        ($encoding:expr)
+        ($ownership:ident)
+        ($required:expr)
+        (add_method)
+        ()
+    ) => {
+        $crate::__macro_helpers::compile_error!($crate::__macro_helpers::concat!(
+            "invalid property attribute `",
+            $crate::__macro_helpers::stringify!($($property)*),
+            "`. Expected `#[property(name, ...)]`.",
+        ))
+    };
+
+    (
+        ($builder:ident)
+        ($($property:tt)*)
+        ($encoding:expr)
+        ($ownership:ident)
+        ($required:expr)
+        ($builder_method:ident)
+        ($($args:tt)*)
+    ) => {
+        $crate::__macro_helpers::compile_error!(
+            "`#[property(...)]` can only be used on instance methods without arguments"
+        )
+    };
+}
+
+/// Apply each of the attributes in `#[property(name, ...)]` to a
+/// `PropertyAttributes`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_protocol_property_attributes {
+    (($attributes:expr) $(,)?) => {
+        $attributes
+    };
+    (($attributes:expr) readonly $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.readonly(true))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) atomic $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.atomic(true))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) nonatomic $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.atomic(false))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) strong $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.ownership($crate::declare::PropertyOwnership::Strong))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) copy $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.ownership($crate::declare::PropertyOwnership::Copy))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) weak $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.ownership($crate::declare::PropertyOwnership::Weak))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) assign $(, $($rest:tt)*)?) => {
+        $crate::__declare_protocol_property_attributes!(
+            ($attributes.ownership($crate::declare::PropertyOwnership::Assign))
+            $($($rest)*)?
+        )
+    };
+    (($attributes:expr) $($rest:tt)*) => {
+        $crate::__macro_helpers::compile_error!($crate::__macro_helpers::concat!(
+            "unknown property attribute `",
+            $crate::__macro_helpers::stringify!($($rest)*),
+            "`",
+        ))
+    };
+}
@@ -43,11 +43,13 @@
 /// future when implementing protocols in [`declare_class!`].
 ///
 /// This macro otherwise shares similarities with [`extern_class!`] and
-/// [`extern_methods!`].
+/// [`extern_methods!`]. To define a new protocol instead, use
+/// [`declare_protocol!`].
 ///
 /// [`ProtocolObject<dyn T>`]: crate::runtime::ProtocolObject
 /// [`ProtocolType`]: crate::ProtocolType
 /// [`declare_class!`]: crate::declare_class
+/// [`declare_protocol!`]: crate::declare_protocol
 /// [`extern_class!`]: crate::extern_class
 /// [`extern_methods!`]: crate::extern_methods
 ///
@@ -180,6 +182,22 @@ macro_rules! __inner_extern_protocol {
         ($name:ident)
         (dyn $for:ident)
         ($name_str:expr)
+    ) => {
+        $crate::__inner_extern_protocol!(
+            ($(#[$impl_m])*)
+            ($name)
+            (dyn $for)
+            ($name_str)
+            ()
+        );
+    };
+    (
+        ($(#[$impl_m:meta])*)
+        ($name:ident)
+        (dyn $for:ident)
+        ($name_str:expr)
+        // Overrides of `ProtocolType`'s provided methods
+        ($($protocol_fns:tt)*)
     ) => {
         $(#[$impl_m])*
         unsafe impl<T> $name for $crate::runtime::ProtocolObject<T>
@@ -193,6 +211,8 @@ macro_rules! __inner_extern_protocol {
         unsafe impl ProtocolType for dyn $for {
             const NAME: &'static $crate::__macro_helpers::str = $name_str;
             const __INNER: () = ();
+
+            $($protocol_fns)*
         }
 
         // SAFETY: Anything that implements the protocol is valid to convert
@@ -4,6 +4,7 @@ mod __method_msg_send;
 mod __msg_send_parse;
 mod __rewrite_self_arg;
 mod declare_class;
+mod declare_protocol;
 mod extern_class;
 mod extern_methods;
 mod extern_protocol;
@@ -589,9 +590,60 @@ macro_rules! __inner_statics {
     };
 }
 
+// See `__macro_helpers::static_gnustep` for details on the GNUStep 2.x ABI.
 #[doc(hidden)]
 #[macro_export]
-#[cfg(not(feature = "apple"))]
+#[cfg(all(
+    not(feature = "apple"),
+    feature = "gnustep-2-0",
+    not(target_os = "windows")
+))]
+macro_rules! __inner_statics {
+    (@image_info $hash:expr) => {
+        /// Make sure that the function that registers the selectors with
+        /// the runtime is linked into the final binary.
+        #[used]
+        static _OBJC_LOAD: &$crate::__macro_helpers::GNUstepLoad =
+            &$crate::__macro_helpers::__OBJC_LOAD;
+    };
+    (@sel $data:expr, $hash:expr) => {
+        use $crate::__macro_helpers::{u8, GNUstepSelector, GNUstepSelectorRef};
+
+        const X: &[u8] = $data.as_bytes();
+
+        static NAME_DATA: [u8; X.len()] = $crate::__inner_statics_apple_generic! {
+            @string_to_known_length_bytes;
+            X;
+        };
+
+        /// The selector, which the runtime registers when the image is
+        /// loaded.
+        #[link_section = "__objc_selectors"]
+        static SEL: GNUstepSelector = GNUstepSelector::new(NAME_DATA.as_ptr());
+
+        static REF: GNUstepSelectorRef = GNUstepSelectorRef::new(&SEL);
+    };
+    (@class $name:expr, $hash:expr) => {
+        use $crate::__macro_helpers::UnsafeCell;
+        use $crate::runtime::AnyClass;
+
+        extern "C" {
+            /// Link to the class reference that is emitted alongside the
+            /// class' implementation, and which the runtime keeps updated.
+            ///
+            /// Unknown classes are a linker error.
+            #[link_name = $crate::__macro_helpers::concat!("._OBJC_REF_CLASS_", $name)]
+            static REF: UnsafeCell<&'static AnyClass>;
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(all(
+    not(feature = "apple"),
+    not(all(feature = "gnustep-2-0", not(target_os = "windows")))
+))]
 macro_rules! __inner_statics {
     (@image_info $($args:tt)*) => {
         // TODO
@@ -599,13 +651,13 @@ macro_rules! __inner_statics {
     (@sel $($args:tt)*) => {
         // TODO
         $crate::__macro_helpers::compile_error!(
-            "The `\"unstable-static-sel\"` feature is not yet supported on GNUStep!"
+            "The `\"unstable-static-sel\"` feature requires `\"gnustep-2-0\"` on GNUStep, and is not yet supported on Windows!"
         )
     };
     (@class $($args:tt)*) => {
         // TODO
         $crate::__macro_helpers::compile_error!(
-            "The `\"unstable-static-class\"` feature is not yet supported on GNUStep!"
+            "The `\"unstable-static-class\"` feature requires `\"gnustep-2-0\"` on GNUStep, and is not yet supported on Windows!"
         )
     };
 }
@@ -691,6 +743,81 @@ macro_rules! __class_inner {
     }};
 }
 
+/// Create a class name that is unique to the current crate version and the
+/// place it was written.
+///
+/// The Objective-C runtime has a single, global namespace for classes, so
+/// the [`ClassType::NAME`] of a class declared with [`declare_class!`] must
+/// be unique in the whole process. This can be hard to guarantee for
+/// libraries, since two versions of the same crate may be linked into the
+/// same process, in which case registering the class the second time would
+/// panic.
+///
+/// This macro takes the desired name as a string literal, and appends an
+/// underscore followed by a hash of the name of the crate it is used in, the
+/// crate's version, and the location where the macro was invoked. The result
+/// is a `&'static str`, so it can be used directly as the class' `NAME`,
+/// which then contains the name that the class is actually registered with
+/// in the runtime.
+///
+/// Note that the hash only depends on the source, so it does _not_ help if
+/// the exact same version of the crate is linked into the process several
+/// times, such as when it is statically linked into multiple plugins that
+/// are loaded by the same application. In that case, the class should be
+/// registered once and shared, or be given a name that is unique to each
+/// plugin.
+///
+/// The hash is not stable, so you should not rely on the exact class name,
+/// e.g. by looking it up with [`class!`] or [`AnyClass::get`]. Use
+/// [`ClassType::class`] or [`ClassType::NAME`] instead.
+///
+/// This requires the `"objc2-proc-macros"` feature to be enabled.
+///
+/// [`ClassType::NAME`]: crate::ClassType::NAME
+/// [`ClassType::class`]: crate::ClassType::class
+/// [`declare_class!`]: crate::declare_class
+/// [`class!`]: crate::class
+/// [`AnyClass::get`]: crate::runtime::AnyClass::get
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::runtime::NSObject;
+/// use objc2::{declare_class, mutability, unique_class_name, ClassType};
+///
+/// declare_class!(
+///     struct MyDelegate;
+///
+///     unsafe impl ClassType for MyDelegate {
+///         type Super = NSObject;
+///         type Mutability = mutability::InteriorMutable;
+///         const NAME: &'static str = unique_class_name!("MyDelegate");
+///     }
+/// );
+///
+/// assert!(MyDelegate::NAME.starts_with("MyDelegate_"));
+/// assert_eq!(MyDelegate::class().name(), MyDelegate::NAME);
+/// ```
+#[macro_export]
+#[cfg(feature = "objc2-proc-macros")]
+macro_rules! unique_class_name {
+    ($name:literal) => {
+        $crate::__macro_helpers::concat!($name, "_", $crate::__hash_class_name!($name))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(feature = "objc2-proc-macros"))]
+macro_rules! unique_class_name {
+    ($name:literal) => {
+        $crate::__macro_helpers::compile_error!(
+            "`unique_class_name!` requires the `objc2-proc-macros` feature"
+        )
+    };
+}
+
 /// Send a message to an object or class.
 ///
 /// This is wildly `unsafe`, even more so than sending messages in
@@ -737,6 +864,13 @@ macro_rules! __class_inner {
 /// as the second argument. If no specific superclass is specified, the
 /// direct superclass is retrieved from [`ClassType`].
 ///
+/// Inside an `impl` of a type that implements [`ClassType`] (e.g. in a class
+/// method in [`declare_class!`]), `super(cls, Self)` can be used to send a
+/// message to the superclass' implementation of a class method, where `cls`
+/// is the class that received the message. Since the superclass is determined
+/// statically from `Self`, this also works when the method is inherited by
+/// subclasses (in which case `cls` is the subclass).
+///
 /// All arguments, as well as the return type, must implement [`Encode`] (bar
 /// the exceptions below).
 ///
@@ -755,6 +889,7 @@ macro_rules! __class_inner {
 /// [`MessageReceiver`]: crate::MessageReceiver
 /// [`rc::Id`]: crate::rc::Id
 /// [`ClassType`]: crate::ClassType
+/// [`declare_class!`]: crate::declare_class
 /// [`Encode`]: crate::Encode
 /// [`sel!`]: crate::sel
 /// [`MessageReceiver::send_message`]: crate::MessageReceiver::send_message
@@ -939,6 +1074,33 @@ macro_rules! __class_inner {
 /// let arg3: u32 = unsafe { msg_send![super(obj, superclass), getArg3] };
 /// ```
 ///
+/// Sending messages to the superclass' implementation of a class method.
+///
+/// ```no_run
+/// use objc2::msg_send;
+/// #
+/// # use objc2::runtime::{AnyClass, NSObject};
+/// # use objc2::{declare_class, mutability, ClassType};
+///
+/// declare_class!(
+///     struct MyObject;
+///
+///     unsafe impl ClassType for MyObject {
+///         type Super = NSObject;
+///         type Mutability = mutability::InteriorMutable;
+///         const NAME: &'static str = "MyObject";
+///     }
+///
+///     unsafe impl MyObject {
+///         #[method(version)]
+///         fn version(cls: &AnyClass) -> isize {
+///             let version: isize = unsafe { msg_send![super(cls, Self), version] };
+///             version + 1
+///         }
+///     }
+/// );
+/// ```
+///
 /// Sending a message with automatic error handling.
 ///
 /// ```no_run
@@ -985,6 +1147,15 @@ macro_rules! __class_inner {
 /// ```
 #[macro_export]
 macro_rules! msg_send {
+    [super($cls:expr, Self), $($selector_and_arguments:tt)+] => {
+        $crate::msg_send![
+            super(
+                $cls,
+                $crate::__macro_helpers::static_super_metaclass::<Self>()
+            ),
+            $($selector_and_arguments)+
+        ]
+    };
     [super($obj:expr), $($selector_and_arguments:tt)+] => {
         $crate::__msg_send_parse! {
             ($crate::__msg_send_helper)
@@ -1147,7 +1318,13 @@ macro_rules! msg_send_bool {
 /// Though as a special case, if the last argument is the marker `_`, the
 /// macro will return a `Result<Id<T>, Id<E>>`, see below.
 ///
-/// This macro doesn't support super methods yet, see [#173].
+/// Super methods are supported with `msg_send_id![super(obj), ...]`,
+/// `msg_send_id![super(obj, superclass), ...]` and (for class methods)
+/// `msg_send_id![super(cls, Self), ...]`, except for the `alloc` family. For the
+/// `init` family, the receiver must be a [`PartialInit<T>`] as returned from
+/// [`Allocated::set_ivars`], so that the instance variables of the declared
+/// class are initialized before the superclass' initializer is run.
+///
 /// The `retain`, `release` and `autorelease` selectors are not supported, use
 /// [`Id::retain`], [`Id::drop`] and [`Id::autorelease`] for that.
 ///
@@ -1155,7 +1332,8 @@ macro_rules! msg_send_bool {
 /// [`MessageReceiver`]: crate::MessageReceiver
 /// [`Id::retain_autoreleased`]: crate::rc::Id::retain_autoreleased
 /// [arc-retainable]: https://clang.llvm.org/docs/AutomaticReferenceCounting.html#retainable-object-pointers-as-operands-and-arguments
-/// [#173]: https://github.com/madsmtm/objc2/pull/173
+/// [`PartialInit<T>`]: crate::rc::PartialInit
+/// [`Allocated::set_ivars`]: crate::rc::Allocated::set_ivars
 /// [`Id::retain`]: crate::rc::Id::retain
 /// [`Id::drop`]: crate::rc::Id::drop
 /// [`Id::autorelease`]: crate::rc::Id::autorelease
@@ -1222,6 +1400,45 @@ macro_rules! msg_send_bool {
 /// ```
 #[macro_export]
 macro_rules! msg_send_id {
+    [super($cls:expr, Self), $($selector_and_arguments:tt)+] => {
+        $crate::msg_send_id![
+            super(
+                $cls,
+                $crate::__macro_helpers::static_super_metaclass::<Self>()
+            ),
+            $($selector_and_arguments)+
+        ]
+    };
+    [super($obj:expr), $($selector_and_arguments:tt)+] => ({
+        let __objc2_obj = $obj;
+        let __objc2_superclass = $crate::__macro_helpers::static_superclass(&__objc2_obj);
+        $crate::__msg_send_parse! {
+            ($crate::__msg_send_id_helper)
+            @(send_super_message_id_error)
+            @()
+            @()
+            @($($selector_and_arguments)+)
+            @(send_super_message_id)
+
+            @(MsgSendSuperId)
+            @(__objc2_obj, __objc2_superclass)
+            @()
+        }
+    });
+    [super($obj:expr, $superclass:expr), $($selector_and_arguments:tt)+] => {
+        $crate::__msg_send_parse! {
+            ($crate::__msg_send_id_helper)
+            @(send_super_message_id_error)
+            @()
+            @()
+            @($($selector_and_arguments)+)
+            @(send_super_message_id)
+
+            @(MsgSendSuperId)
+            @($obj, $superclass)
+            @()
+        }
+    };
     [$obj:expr, new $(,)?] => ({
         let sel = $crate::sel!(new);
         let result;
@@ -1249,6 +1466,7 @@ macro_rules! msg_send_id {
             @($($selector_and_arguments)+)
             @(send_message_id)
 
+            @(MsgSendId)
             @($obj)
             @()
         }
@@ -1261,7 +1479,8 @@ macro_rules! msg_send_id {
 macro_rules! __msg_send_id_helper {
     {
         @($fn:ident)
-        @($obj:expr)
+        @($trait:ident)
+        @($($obj:expr),+)
         @($($retain_semantics:ident)?)
         @(retain)
         @()
@@ -1272,7 +1491,8 @@ macro_rules! __msg_send_id_helper {
     }};
     {
         @($fn:ident)
-        @($obj:expr)
+        @($trait:ident)
+        @($($obj:expr),+)
         @($($retain_semantics:ident)?)
         @(release)
         @()
@@ -1283,7 +1503,8 @@ macro_rules! __msg_send_id_helper {
     }};
     {
         @($fn:ident)
-        @($obj:expr)
+        @($trait:ident)
+        @($($obj:expr),+)
         @($($retain_semantics:ident)?)
         @(autorelease)
         @()
@@ -1294,7 +1515,8 @@ macro_rules! __msg_send_id_helper {
     }};
     {
         @($fn:ident)
-        @($obj:expr)
+        @($trait:ident)
+        @($($obj:expr),+)
         @($($retain_semantics:ident)?)
         @(dealloc)
         @()
@@ -1305,20 +1527,22 @@ macro_rules! __msg_send_id_helper {
     }};
     {
         @($fn:ident)
-        @($obj:expr)
+        @($trait:ident)
+        @($($obj:expr),+)
         @($retain_semantics:ident)
         @($($selector:tt)*)
         @($($argument:expr,)*)
     } => ({
-        <$crate::__macro_helpers::$retain_semantics as $crate::__macro_helpers::MsgSendId<_, _>>::$fn::<_, _>(
-            $obj,
+        <$crate::__macro_helpers::$retain_semantics as $crate::__macro_helpers::$trait<_, _>>::$fn::<_, _>(
+            $($obj,)+
             $crate::sel!($($selector)*),
             ($($argument,)*),
         )
     });
     {
         @($fn:ident)
-        @($obj:expr)
+        @($trait:ident)
+        @($($obj:expr),+)
         @()
         @($($selector:tt)*)
         @($($argument:expr,)*)
@@ -1330,8 +1554,8 @@ macro_rules! __msg_send_id_helper {
         let result;
         result = <$crate::__macro_helpers::RetainSemantics<{
             $crate::__macro_helpers::retain_semantics(__SELECTOR_DATA)
-        }> as $crate::__macro_helpers::MsgSendId<_, _>>::$fn::<_, _>(
-            $obj,
+        }> as $crate::__macro_helpers::$trait<_, _>>::$fn::<_, _>(
+            $($obj,)+
             $crate::__sel_inner!(
                 __SELECTOR_DATA,
                 $crate::__hash_idents!($($selector)*)
@@ -6,6 +6,7 @@
 //!   [`UnsafeCell`])?
 //! - Does it access global statics in such a way that the type is only safe
 //!   to use from the main thread?
+//! - Is it only safe to use from the thread that created it?
 //!
 //! The answer to these facts influence the final capabilities the type has,
 //! as encoded in [the traits in this module](#traits).
@@ -203,6 +204,33 @@ pub struct MainThreadOnly {
     inner: Never,
 }
 
+/// Marker type for classes whose instances must stay on the thread they
+/// were created on.
+///
+/// This is effectively the same as [`InteriorMutable`], except that
+/// `Id<T>` is never [`Send`] nor [`Sync`], regardless of whether `T` is.
+///
+/// This is useful for classes like `NSRunLoop` or `NSAutoreleasePool`, and
+/// for things like `NSManagedObjectContext` with thread confinement, which
+/// can be created on any thread, but must only be used on that thread
+/// afterwards.
+///
+/// Functionality that is provided with this:
+/// - [`IsRetainable`] -> [`ClassType::retain`].
+/// - [`IsIdCloneable`] -> [`Id::clone`][crate::rc::Id#impl-Clone-for-Id<T>].
+/// - [`IsAllocableAnyThread`] -> [`ClassType::alloc`].
+///
+///
+/// # Safety notice
+///
+/// Since only `Id<T>` is prevented from crossing threads, you must not
+/// implement [`Send`] or [`Sync`] for classes that specify this (as that
+/// would allow sending `&T` to other threads).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ThreadConfined {
+    inner: Never,
+}
+
 mod private {
     use super::*;
 
@@ -214,6 +242,7 @@ mod private {
     impl<IS: ?Sized> Sealed for MutableWithImmutableSuperclass<IS> {}
     impl Sealed for InteriorMutable {}
     impl Sealed for MainThreadOnly {}
+    impl Sealed for ThreadConfined {}
 
     pub trait MutabilityIsIdCloneable: Mutability {}
     impl MutabilityIsIdCloneable for Root {}
@@ -221,11 +250,13 @@ mod private {
     impl<MS: ?Sized> MutabilityIsIdCloneable for ImmutableWithMutableSubclass<MS> {}
     impl MutabilityIsIdCloneable for InteriorMutable {}
     impl MutabilityIsIdCloneable for MainThreadOnly {}
+    impl MutabilityIsIdCloneable for ThreadConfined {}
 
     pub trait MutabilityIsRetainable: MutabilityIsIdCloneable {}
     impl MutabilityIsRetainable for Immutable {}
     impl MutabilityIsRetainable for InteriorMutable {}
     impl MutabilityIsRetainable for MainThreadOnly {}
+    impl MutabilityIsRetainable for ThreadConfined {}
 
     pub trait MutabilityIsAllocableAnyThread: Mutability {}
     impl MutabilityIsAllocableAnyThread for Root {}
@@ -234,6 +265,7 @@ mod private {
     impl<MS: ?Sized> MutabilityIsAllocableAnyThread for ImmutableWithMutableSubclass<MS> {}
     impl<IS: ?Sized> MutabilityIsAllocableAnyThread for MutableWithImmutableSuperclass<IS> {}
     impl MutabilityIsAllocableAnyThread for InteriorMutable {}
+    impl MutabilityIsAllocableAnyThread for ThreadConfined {}
 
     pub trait MutabilityIsMutable: Mutability {}
     impl MutabilityIsMutable for Mutable {}
@@ -255,6 +287,7 @@ impl<MS: ?Sized> Mutability for ImmutableWithMutableSubclass<MS> {}
 impl<IS: ?Sized> Mutability for MutableWithImmutableSuperclass<IS> {}
 impl Mutability for InteriorMutable {}
 impl Mutability for MainThreadOnly {}
+impl Mutability for ThreadConfined {}
 
 /// Marker trait for classes where [`Id::clone`][clone-id] is safe.
 ///
@@ -264,6 +297,7 @@ impl Mutability for MainThreadOnly {}
 /// - [`ImmutableWithMutableSubclass`].
 /// - [`InteriorMutable`].
 /// - [`MainThreadOnly`].
+/// - [`ThreadConfined`].
 ///
 /// [clone-id]: crate::rc::Id#impl-Clone-for-Id<T>
 pub trait IsIdCloneable: ClassType {}
@@ -276,6 +310,7 @@ impl<T: ?Sized + ClassType> IsIdCloneable for T where T::Mutability: private::Mu
 /// - [`Immutable`].
 /// - [`InteriorMutable`].
 /// - [`MainThreadOnly`].
+/// - [`ThreadConfined`].
 ///
 /// [`retain`]: ClassType::retain
 pub trait IsRetainable: IsIdCloneable {}
@@ -290,6 +325,7 @@ impl<T: ?Sized + ClassType> IsRetainable for T where T::Mutability: private::Mut
 /// - [`ImmutableWithMutableSubclass`].
 /// - [`MutableWithImmutableSuperclass`].
 /// - [`InteriorMutable`].
+/// - [`ThreadConfined`].
 pub trait IsAllocableAnyThread: ClassType {}
 impl<T: ?Sized + ClassType> IsAllocableAnyThread for T where
     T::Mutability: private::MutabilityIsAllocableAnyThread
@@ -331,6 +367,7 @@ mod tests {
         assert_traits::<MutableWithImmutableSuperclass<()>>();
         assert_traits::<InteriorMutable>();
         assert_traits::<MainThreadOnly>();
+        assert_traits::<ThreadConfined>();
 
         #[allow(unused)]
         fn test_mutability_implies_sized<M: ?Sized + Mutability>() {
@@ -4,17 +4,18 @@ use crate::runtime::AnyProtocol;
 ///
 /// This is the protocol equivalent of [`ClassType`].
 ///
-/// This is implemented automatically by the [`extern_protocol!`] macro for
-/// `dyn T`, where `T` is the protocol.
+/// This is implemented automatically by the [`extern_protocol!`] and
+/// [`declare_protocol!`] macros for `dyn T`, where `T` is the protocol.
 ///
 /// [`ClassType`]: crate::ClassType
 /// [`extern_protocol!`]: crate::extern_protocol
+/// [`declare_protocol!`]: crate::declare_protocol
 ///
 ///
 /// # Safety
 ///
 /// This is meant to be a sealed trait, and should not be implemented outside
-/// of the [`extern_protocol!`] macro.
+/// of the [`extern_protocol!`] and [`declare_protocol!`] macros.
 ///
 ///
 /// # Examples
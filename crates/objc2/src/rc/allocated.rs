@@ -4,7 +4,7 @@ use core::mem::{self, ManuallyDrop};
 use core::ptr::NonNull;
 
 use crate::ffi;
-use crate::Message;
+use crate::{DeclaredClass, Message};
 
 /// A marker type that can be used to indicate that the object has been
 /// allocated but not initialized.
@@ -70,6 +70,44 @@ impl<T: ?Sized + Message> Allocated<T> {
     }
 }
 
+impl<T: DeclaredClass> Allocated<T> {
+    /// Initialize the instance variables of the class.
+    ///
+    /// This consumes the allocated object, and returns a [`PartialInit`],
+    /// which must then be passed to the superclass' initializer with
+    /// [`msg_send_id![super(this), init]`][crate::msg_send_id], usually
+    /// inside an `init` method in [`declare_class!`][crate::declare_class].
+    ///
+    /// Since every field of [`DeclaredClass::Ivars`] must be specified, this
+    /// ensures at compile-time that all the instance variables have been
+    /// initialized before the object is used.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// #[method_id(initWithFoo:)]
+    /// fn init_with_foo(this: Allocated<Self>, foo: u8) -> Option<Id<Self>> {
+    ///     let this = this.set_ivars(ivars::Ivars {
+    ///         foo,
+    ///         object: NSObject::new(),
+    ///     });
+    ///     unsafe { msg_send_id![super(this), init] }
+    /// }
+    /// ```
+    #[inline]
+    pub fn set_ivars(self, ivars: T::Ivars) -> PartialInit<T> {
+        // SAFETY: The object has just been allocated, so its instance
+        // variables are zero-initialized, and have not yet been written to.
+        unsafe { T::__write_ivars(self.ptr, ivars) };
+        let this = ManuallyDrop::new(self);
+        PartialInit {
+            ptr: this.ptr,
+            p: PhantomData,
+        }
+    }
+}
+
 impl<T: ?Sized> Drop for Allocated<T> {
     #[inline]
     fn drop(&mut self) {
@@ -87,3 +125,42 @@ impl<T: ?Sized> fmt::Pointer for Allocated<T> {
         fmt::Pointer::fmt(&self.ptr.as_ptr(), f)
     }
 }
+
+/// An allocated object whose instance variables have been initialized, but
+/// whose superclass has not.
+///
+/// This is returned from [`Allocated::set_ivars`], and is meant to be passed
+/// to the superclass' initializer with
+/// [`msg_send_id![super(this), init]`][crate::msg_send_id].
+#[repr(transparent)]
+#[derive(Debug)]
+pub struct PartialInit<T: ?Sized> {
+    /// The partially initialized object.
+    ///
+    /// Variance is same as `Id`.
+    ptr: NonNull<T>,
+    /// Necessary for dropck, as with `Id`.
+    p: PhantomData<T>,
+}
+
+impl<T: ?Sized + Message> PartialInit<T> {
+    #[inline]
+    pub(crate) fn into_ptr(this: Self) -> *mut T {
+        ManuallyDrop::new(this).ptr.as_ptr()
+    }
+}
+
+impl<T: ?Sized> Drop for PartialInit<T> {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: Same as for `Allocated`. The instance variables have been
+        // initialized, so `dealloc` will drop them correctly.
+        unsafe { ffi::objc_release(self.ptr.as_ptr().cast()) };
+    }
+}
+
+impl<T: ?Sized> fmt::Pointer for PartialInit<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr.as_ptr(), f)
+    }
+}
@@ -0,0 +1,262 @@
+use core::fmt;
+use core::ptr;
+use std::sync::{Mutex, PoisonError};
+
+use super::Id;
+use crate::mutability::IsIdCloneable;
+use crate::Message;
+
+/// A thread-safe slot containing an optional [`Id`], that can be loaded
+/// from and stored to through a shared reference.
+///
+/// This is a plain wrapper around a `Mutex<Option<Id<T>>>`, and is _not_
+/// lock-free; the name only reflects that it has the same semantics as an
+/// `atomic, strong` Objective-C property. Loading retains the object while
+/// holding the lock, so that a concurrent store cannot release it in the
+/// meantime, while the previous value of a store is released after the lock
+/// is released, so that the lock is never held while running `dealloc`.
+///
+/// `Send`, `Sync` and the unwind safety traits follow from the inner
+/// [`Mutex`]; poisoning is ignored, since the slot always contains a valid
+/// value.
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::rc::AtomicId;
+/// use objc2::runtime::NSObject;
+///
+/// let slot = AtomicId::new(None);
+/// assert!(slot.load().is_none());
+///
+/// let obj = NSObject::new();
+/// let old = slot.swap(Some(obj.clone()));
+/// assert!(old.is_none());
+/// assert_eq!(slot.load().as_deref(), Some(&*obj));
+/// ```
+pub struct AtomicId<T: ?Sized> {
+    value: Mutex<Option<Id<T>>>,
+}
+
+impl<T: ?Sized> AtomicId<T> {
+    /// Create a new slot containing the given object.
+    #[inline]
+    pub fn new(value: Option<Id<T>>) -> Self {
+        Self {
+            value: Mutex::new(value),
+        }
+    }
+
+    /// Run the given closure with exclusive access to the value.
+    ///
+    /// The closure must not run arbitrary user code, other than retaining
+    /// an object.
+    #[inline]
+    fn with_lock<R>(&self, f: impl FnOnce(&mut Option<Id<T>>) -> R) -> R {
+        // The slot always contains a valid value, so poisoning can be
+        // ignored.
+        let mut value = self.value.lock().unwrap_or_else(PoisonError::into_inner);
+        f(&mut value)
+    }
+
+    /// Store the given object in the slot, releasing the previous one.
+    #[doc(alias = "objc_storeStrong")]
+    #[doc(alias = "objc_setProperty")]
+    #[inline]
+    pub fn store(&self, value: Option<Id<T>>) {
+        // The previous value is dropped after the lock has been released.
+        let _ = self.swap(value);
+    }
+
+    /// Store the given object in the slot, returning the previous one.
+    #[inline]
+    pub fn swap(&self, value: Option<Id<T>>) -> Option<Id<T>> {
+        self.with_lock(|current| core::mem::replace(current, value))
+    }
+
+    /// Store `new` in the slot if it currently contains `current` (compared
+    /// by pointer).
+    ///
+    /// On success, the previous value is returned in `Ok`. On failure, the
+    /// slot is left untouched, and `new` is handed back in `Err`.
+    pub fn compare_exchange(
+        &self,
+        current: Option<&T>,
+        new: Option<Id<T>>,
+    ) -> Result<Option<Id<T>>, Option<Id<T>>> {
+        let current = current.map_or(ptr::null(), |obj| (obj as *const T).cast::<u8>());
+        self.with_lock(|value| {
+            let value_ptr = value
+                .as_ref()
+                .map_or(ptr::null(), |obj| (&**obj as *const T).cast::<u8>());
+            if value_ptr == current {
+                Ok(core::mem::replace(value, new))
+            } else {
+                Err(new)
+            }
+        })
+    }
+
+    /// Get a mutable reference to the contained object.
+    ///
+    /// This doesn't need to lock, since the mutable reference guarantees
+    /// that no other threads are accessing the slot.
+    #[inline]
+    pub fn get_mut(&mut self) -> &mut Option<Id<T>> {
+        self.value.get_mut().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Consume the slot, returning the contained object.
+    #[inline]
+    pub fn into_inner(self) -> Option<Id<T>> {
+        self.value
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: Message> AtomicId<T> {
+    /// Load the object currently in the slot, retaining it.
+    #[doc(alias = "objc_getProperty")]
+    #[inline]
+    pub fn load(&self) -> Option<Id<T>>
+    where
+        T: IsIdCloneable,
+    {
+        self.with_lock(|value| {
+            value.as_ref().map(|obj| {
+                // SAFETY: The object is cloneable, and we retain it while
+                // holding the lock, so it cannot be released in the
+                // meantime.
+                let obj = unsafe { Id::retain(Id::as_ptr(obj) as *mut T) };
+                // SAFETY: `objc_retain` always returns the same object
+                // pointer, and the pointer is guaranteed non-null.
+                unsafe { obj.unwrap_unchecked() }
+            })
+        })
+    }
+}
+
+impl<T: ?Sized> Default for AtomicId<T> {
+    /// Create an empty slot.
+    #[inline]
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl<T: ?Sized> From<Id<T>> for AtomicId<T> {
+    #[inline]
+    fn from(obj: Id<T>) -> Self {
+        Self::new(Some(obj))
+    }
+}
+
+impl<T: ?Sized> From<Option<Id<T>>> for AtomicId<T> {
+    #[inline]
+    fn from(obj: Option<Id<T>>) -> Self {
+        Self::new(obj)
+    }
+}
+
+impl<T: Message + IsIdCloneable + fmt::Debug> fmt::Debug for AtomicId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AtomicId").field(&self.load()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use std::sync::Arc;
+    use std::thread;
+
+    use super::*;
+    use crate::rc::{__RcTestObject, __ThreadTestData};
+    use crate::runtime::NSObject;
+
+    #[test]
+    fn test_auto_traits() {
+        use core::panic::{RefUnwindSafe, UnwindSafe};
+        use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+        use crate::runtime::AnyObject;
+
+        assert_impl_all!(AtomicId<__RcTestObject>: Send, Sync, Unpin, UnwindSafe, RefUnwindSafe);
+        assert_not_impl_any!(AtomicId<AnyObject>: Send, Sync);
+    }
+
+    #[test]
+    fn test_load_store() {
+        let obj = __RcTestObject::new();
+        let slot = AtomicId::new(Some(obj.clone()));
+        let mut expected = __ThreadTestData::current();
+
+        let loaded = slot.load().unwrap();
+        expected.retain += 1;
+        expected.assert_current();
+        assert!(ptr::eq(&*loaded, &*obj));
+        drop(loaded);
+        expected.release += 1;
+        expected.assert_current();
+
+        slot.store(None);
+        expected.release += 1;
+        expected.assert_current();
+        assert!(slot.load().is_none());
+
+        drop(obj);
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+    }
+
+    #[test]
+    fn test_compare_exchange() {
+        let obj1 = NSObject::new();
+        let obj2 = NSObject::new();
+        let slot = AtomicId::from(obj1.clone());
+
+        let res = slot.compare_exchange(Some(&obj2), None);
+        assert!(res.unwrap_err().is_none());
+        assert_eq!(slot.load().as_deref(), Some(&*obj1));
+
+        let res = slot.compare_exchange(Some(&obj1), Some(obj2.clone()));
+        assert_eq!(res.unwrap().as_deref(), Some(&*obj1));
+        assert_eq!(slot.load().as_deref(), Some(&*obj2));
+
+        let res = slot.compare_exchange(None, Some(obj1.clone()));
+        assert!(res.is_err());
+        slot.store(None);
+        let res = slot.compare_exchange(None, Some(obj1.clone()));
+        assert!(res.unwrap().is_none());
+        assert_eq!(slot.into_inner().as_deref(), Some(&*obj1));
+    }
+
+    #[test]
+    fn test_threads() {
+        let slot = Arc::new(AtomicId::<__RcTestObject>::default());
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let slot = Arc::clone(&slot);
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        slot.store(Some(__RcTestObject::new()));
+                        assert!(slot.load().is_some());
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        assert!(slot.load().is_some());
+    }
+
+    #[test]
+    fn auto_traits() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AtomicId<__RcTestObject>>();
+    }
+}
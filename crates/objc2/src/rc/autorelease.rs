@@ -1,8 +1,12 @@
 use core::ffi::c_void;
 #[cfg(not(all(debug_assertions, not(feature = "unstable-autoreleasesafe"))))]
 use core::marker::PhantomData;
-#[cfg(all(debug_assertions, not(feature = "unstable-autoreleasesafe")))]
-use std::{cell::RefCell, thread_local, vec::Vec};
+#[cfg(debug_assertions)]
+use std::{
+    cell::{Cell, RefCell},
+    eprintln, thread_local,
+    vec::Vec,
+};
 
 use crate::ffi;
 
@@ -35,8 +39,13 @@ impl Pool {
     #[inline]
     unsafe fn new() -> Self {
         let context = unsafe { ffi::objc_autoreleasePoolPush() };
-        #[cfg(all(debug_assertions, not(feature = "unstable-autoreleasesafe")))]
-        POOLS.with(|c| c.borrow_mut().push(context));
+        #[cfg(debug_assertions)]
+        POOLS.with(|c| {
+            c.borrow_mut().push(PoolInfo {
+                context,
+                autoreleased: 0,
+            })
+        });
         Self { context }
     }
 
@@ -63,6 +72,14 @@ impl Pool {
     /// [revision `551.1`]: https://github.com/apple-oss-distributions/objc4/blob/objc4-551.1/runtime/objc-exception.mm#L516
     #[inline]
     unsafe fn drain(self) {
+        #[cfg(debug_assertions)]
+        if let Some((stats, threshold)) = drain_warning() {
+            eprintln!(
+                "objc2: autorelease pool at depth {} drained {} objects, \
+                 which is more than the warning threshold of {}",
+                stats.depth, stats.autoreleased, threshold,
+            );
+        }
         unsafe { ffi::objc_autoreleasePoolPop(self.context) }
     }
 }
@@ -70,10 +87,10 @@ impl Pool {
 impl Drop for Pool {
     #[inline]
     fn drop(&mut self) {
-        #[cfg(all(debug_assertions, not(feature = "unstable-autoreleasesafe")))]
+        #[cfg(debug_assertions)]
         POOLS.with(|c| {
             assert_eq!(
-                c.borrow_mut().pop(),
+                c.borrow_mut().pop().map(|pool| pool.context),
                 Some(self.context),
                 "popped pool that was not the innermost pool"
             )
@@ -159,11 +176,49 @@ pub struct AutoreleasePool<'pool> {
     inner: PhantomData<&'pool Pool>,
 }
 
-#[cfg(all(debug_assertions, not(feature = "unstable-autoreleasesafe")))]
+#[cfg(debug_assertions)]
+#[derive(Debug)]
+struct PoolInfo {
+    context: *mut c_void,
+    /// The number of objects autoreleased with `Id::autorelease` while this
+    /// was the innermost pool.
+    autoreleased: usize,
+}
+
+#[cfg(debug_assertions)]
 thread_local! {
     /// We track the thread's pools to verify that object lifetimes are only
-    /// taken from the innermost pool.
-    static POOLS: RefCell<Vec<*mut c_void>> = RefCell::new(Vec::new());
+    /// taken from the innermost pool, and for `AutoreleasePoolStats`.
+    static POOLS: RefCell<Vec<PoolInfo>> = RefCell::new(Vec::new());
+
+    /// See `AutoreleasePoolStats::set_drain_warning_threshold`.
+    static DRAIN_WARNING_THRESHOLD: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// The statistics and the threshold, if draining the innermost pool should
+/// emit a warning.
+#[cfg(debug_assertions)]
+fn drain_warning() -> Option<(AutoreleasePoolStats, usize)> {
+    let threshold = DRAIN_WARNING_THRESHOLD.with(Cell::get)?;
+    let stats = AutoreleasePoolStats::current();
+    if stats.autoreleased > threshold {
+        Some((stats, threshold))
+    } else {
+        None
+    }
+}
+
+/// Record that an object was autoreleased into the innermost pool.
+#[inline]
+pub(crate) fn record_autorelease() {
+    #[cfg(debug_assertions)]
+    // Ignore errors, the thread-local may have been destroyed if we're
+    // autoreleasing in a thread-local destructor.
+    let _ = POOLS.try_with(|c| {
+        if let Some(pool) = c.borrow_mut().last_mut() {
+            pool.autoreleased += 1;
+        }
+    });
 }
 
 impl<'pool> AutoreleasePool<'pool> {
@@ -184,8 +239,8 @@ impl<'pool> AutoreleasePool<'pool> {
         if let Some(pool) = &self.inner {
             POOLS.with(|c| {
                 assert_eq!(
-                    c.borrow().last(),
-                    Some(&pool.context),
+                    c.borrow().last().map(|pool| pool.context),
+                    Some(pool.context),
                     "tried to use lifetime from pool that was not innermost"
                 )
             });
@@ -233,6 +288,93 @@ impl<'pool> AutoreleasePool<'pool> {
     }
 }
 
+/// Statistics about the current thread's autorelease pools.
+///
+/// This is intended for debugging the performance of code that creates
+/// many temporary objects, such as tight loops that would benefit from an
+/// inner [`autoreleasepool`].
+///
+/// The statistics are only tracked when `debug_assertions` are enabled;
+/// otherwise, they are always zero.
+///
+/// Note that only pools created with [`autoreleasepool`], and objects
+/// autoreleased with [`Id::autorelease`] or [`Id::autorelease_mut`] are
+/// counted. Objects that are autoreleased internally by Objective-C code, or
+/// returned to Objective-C with [`Id::autorelease_return`], are not visible
+/// to Rust.
+///
+/// [`Id::autorelease`]: crate::rc::Id::autorelease
+/// [`Id::autorelease_mut`]: crate::rc::Id::autorelease_mut
+/// [`Id::autorelease_return`]: crate::rc::Id::autorelease_return
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::rc::{autoreleasepool, AutoreleasePoolStats, Id};
+/// use objc2::runtime::NSObject;
+///
+/// autoreleasepool(|pool| {
+///     let _obj = Id::autorelease(NSObject::new(), pool);
+///
+///     let stats = AutoreleasePoolStats::current();
+///     if cfg!(debug_assertions) {
+///         assert_eq!(stats.autoreleased, 1);
+///     }
+/// });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct AutoreleasePoolStats {
+    /// The number of active pools on the current thread.
+    pub depth: usize,
+    /// The number of objects that have been autoreleased into the innermost
+    /// pool.
+    pub autoreleased: usize,
+}
+
+impl AutoreleasePoolStats {
+    /// Get the statistics for the current thread.
+    #[inline]
+    pub fn current() -> Self {
+        #[cfg(debug_assertions)]
+        {
+            POOLS.with(|c| {
+                let pools = c.borrow();
+                Self {
+                    depth: pools.len(),
+                    autoreleased: pools.last().map_or(0, |pool| pool.autoreleased),
+                }
+            })
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            Self::default()
+        }
+    }
+
+    /// Print a warning to stderr whenever a pool on the current thread is
+    /// drained with more than `threshold` autoreleased objects, or disable
+    /// the warning with `None`.
+    ///
+    /// The warning is disabled by default; `objc2` never writes to stderr
+    /// unless you opt in with this function. It only applies to the current
+    /// thread, and does nothing unless `debug_assertions` are enabled.
+    ///
+    /// Returns the previous threshold.
+    pub fn set_drain_warning_threshold(threshold: Option<usize>) -> Option<usize> {
+        #[cfg(debug_assertions)]
+        {
+            DRAIN_WARNING_THRESHOLD.with(|c| c.replace(threshold))
+        }
+        #[cfg(not(debug_assertions))]
+        {
+            let _ = threshold;
+            None
+        }
+    }
+}
+
 /// We use a macro here so that the documentation is included whether the
 /// feature is enabled or not.
 #[cfg(not(feature = "unstable-autoreleasesafe"))]
@@ -524,8 +666,70 @@ mod tests {
 
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
-    use super::{AutoreleasePool, AutoreleaseSafe};
-    use crate::runtime::AnyObject;
+    use super::{autoreleasepool, AutoreleasePool, AutoreleasePoolStats, AutoreleaseSafe};
+    use crate::rc::{autoreleasepool_leaking, Id};
+    use crate::runtime::{AnyObject, NSObject};
+
+    #[test]
+    #[cfg_attr(not(debug_assertions), ignore = "only tracked with debug assertions")]
+    fn test_stats() {
+        let initial = AutoreleasePoolStats::current();
+        autoreleasepool(|outer| {
+            let _obj = Id::autorelease(NSObject::new(), outer);
+            let stats = AutoreleasePoolStats::current();
+            assert_eq!(stats.depth, initial.depth + 1);
+            assert_eq!(stats.autoreleased, 1);
+
+            autoreleasepool(|inner| {
+                let stats = AutoreleasePoolStats::current();
+                assert_eq!(stats.depth, initial.depth + 2);
+                assert_eq!(stats.autoreleased, 0);
+                for _ in 0..3 {
+                    let _obj = Id::autorelease(NSObject::new(), inner);
+                }
+                assert_eq!(AutoreleasePoolStats::current().autoreleased, 3);
+            });
+
+            // Leaking pools are not counted as a separate pool.
+            autoreleasepool_leaking(|_| {
+                let _obj = Id::autorelease(NSObject::new(), outer);
+            });
+            let stats = AutoreleasePoolStats::current();
+            assert_eq!(stats.depth, initial.depth + 1);
+            assert_eq!(stats.autoreleased, 2);
+        });
+        assert_eq!(AutoreleasePoolStats::current(), initial);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_drain_warning_threshold() {
+        use super::drain_warning;
+
+        struct RestoreThreshold(Option<usize>);
+
+        impl Drop for RestoreThreshold {
+            fn drop(&mut self) {
+                AutoreleasePoolStats::set_drain_warning_threshold(self.0);
+            }
+        }
+
+        autoreleasepool(|pool| {
+            let _obj1 = Id::autorelease(NSObject::new(), pool);
+            let _obj2 = Id::autorelease(NSObject::new(), pool);
+            assert_eq!(drain_warning(), None);
+
+            let _guard =
+                RestoreThreshold(AutoreleasePoolStats::set_drain_warning_threshold(Some(2)));
+            assert_eq!(drain_warning(), None);
+
+            let _obj3 = Id::autorelease(NSObject::new(), pool);
+            let (stats, threshold) = drain_warning().unwrap();
+            assert_eq!(stats, AutoreleasePoolStats::current());
+            assert_eq!(stats.autoreleased, 3);
+            assert_eq!(threshold, 2);
+        });
+    }
 
     #[test]
     fn auto_traits() {
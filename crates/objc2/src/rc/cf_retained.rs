@@ -0,0 +1,514 @@
+use core::ffi::c_void;
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::ManuallyDrop;
+use core::ops::Deref;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+use core::ptr::NonNull;
+
+use super::Id;
+use crate::Message;
+
+// These are defined in CoreFoundation.
+//
+// On GNUstep, they're provided by `gnustep-corebase`, which is an optional
+// part of GNUstep. So we only link either of them when explicitly requested;
+// otherwise, they must be linked by the crate that provides the types.
+#[cfg_attr(
+    all(
+        feature = "link-core-foundation",
+        feature = "apple",
+        not(feature = "unstable-compiler-rt")
+    ),
+    link(name = "CoreFoundation", kind = "framework")
+)]
+#[cfg_attr(
+    all(feature = "link-core-foundation", feature = "gnustep-1-7"),
+    link(name = "gnustep-corebase", kind = "dylib")
+)]
+extern "C" {
+    fn CFRetain(cf: *const c_void) -> *const c_void;
+    fn CFRelease(cf: *const c_void);
+}
+
+/// Types that are reference-counted like CoreFoundation types.
+///
+/// This is usually implemented for the opaque struct that a `CFTypeRef`
+/// style typedef points to, such as `__CFString` for `CFStringRef`.
+///
+/// By default, [`CFRetained`] uses `CFRetain` and `CFRelease` to manage the
+/// reference count, but types that have their own reference-counting
+/// functions (such as `CGColorRetain` and `CGColorRelease`) can override
+/// [`CFType::retain`] and [`CFType::release`].
+///
+///
+/// # Safety
+///
+/// The type must be a valid CoreFoundation type, or otherwise be correctly
+/// reference-counted by the (possibly overridden) `retain` and `release`
+/// functions.
+pub unsafe trait CFType: Sized {
+    /// Increment the reference count of the object.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a valid instance of the type.
+    #[doc(alias = "CFRetain")]
+    #[inline]
+    unsafe fn retain(ptr: NonNull<Self>) {
+        // SAFETY: Upheld by the caller
+        let _ = unsafe { CFRetain(ptr.as_ptr().cast()) };
+    }
+
+    /// Decrement the reference count of the object, possibly deallocating
+    /// it.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a valid instance of the type, that the caller
+    /// owns a reference count to.
+    #[doc(alias = "CFRelease")]
+    #[inline]
+    unsafe fn release(ptr: NonNull<Self>) {
+        // SAFETY: Upheld by the caller
+        unsafe { CFRelease(ptr.as_ptr().cast()) }
+    }
+}
+
+/// CoreFoundation types that are safe to use from any thread.
+///
+/// [`CFRetained<T>`] is only [`Send`] and [`Sync`] if `T` implements this.
+/// While CoreFoundation's reference counting is thread-safe, the types
+/// themselves often aren't; a `CFStringRef` may for example point to a
+/// `CFMutableString`. Since the opaque structs that such types are declared
+/// with are automatically `Send` and `Sync`, this has to be opted in to
+/// separately.
+///
+///
+/// # Safety
+///
+/// Every instance of the type must be safe to access, retain and release
+/// from any thread, also while other threads are doing the same.
+pub unsafe trait CFThreadSafe: CFType {}
+
+/// CoreFoundation types that are "toll-free bridged" to an Objective-C
+/// class, such as `CFString` and `NSString`.
+///
+/// This allows converting between [`CFRetained`] and [`Id`] without changing
+/// the reference count.
+///
+///
+/// # Safety
+///
+/// Every instance of the type must be a valid instance of `Self::Bridged`,
+/// and vice versa, and the type must use `CFRetain` and `CFRelease` as its
+/// reference-counting functions.
+pub unsafe trait TollFreeBridged: CFType {
+    /// The Objective-C class that this type is bridged to.
+    type Bridged: Message;
+}
+
+/// A reference counted pointer type for CoreFoundation objects.
+///
+/// This is the CoreFoundation equivalent of [`Id`], and like it, releases
+/// the object when dropped. The reference-counting functions that are used
+/// are determined by the [`CFType`] implementation.
+///
+/// Functions that follow the ["Create Rule"] (those with `Create` or `Copy`
+/// in their name, or annotated with `CF_RETURNS_RETAINED`) should be
+/// converted with [`CFRetained::new`], while functions that follow the ["Get
+/// Rule"] should be converted with [`CFRetained::retain`].
+///
+/// The default `CFRetain` and `CFRelease` functions are only linked with the
+/// `"link-core-foundation"` feature, which links CoreFoundation on Apple
+/// platforms, and `gnustep-corebase` on GNUstep. Otherwise, they must be
+/// linked in some other way, such as through `icrate`.
+///
+/// ["Create Rule"]: https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-103029
+/// ["Get Rule"]: https://developer.apple.com/library/archive/documentation/CoreFoundation/Conceptual/CFMemoryMgmt/Concepts/Ownership.html#//apple_ref/doc/uid/20001148-SW1
+///
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "apple", feature = "link-core-foundation"), doc = "```")]
+#[cfg_attr(
+    not(all(feature = "apple", feature = "link-core-foundation")),
+    doc = "```ignore"
+)]
+/// use core::ffi::c_void;
+/// use objc2::rc::{CFRetained, CFType};
+///
+/// #[repr(C)]
+/// pub struct __CFArray {
+///     _priv: [u8; 0],
+/// }
+///
+/// unsafe impl CFType for __CFArray {}
+///
+/// extern "C" {
+///     fn CFArrayCreate(
+///         allocator: *const c_void,
+///         values: *const *const c_void,
+///         num_values: isize,
+///         callbacks: *const c_void,
+///     ) -> *mut __CFArray;
+///     fn CFArrayGetCount(array: &__CFArray) -> isize;
+/// }
+///
+/// // SAFETY: `CFArrayCreate` follows the Create Rule.
+/// let array = unsafe {
+///     CFRetained::new(CFArrayCreate(
+///         core::ptr::null(),
+///         core::ptr::null(),
+///         0,
+///         core::ptr::null(),
+///     ))
+/// }
+/// .unwrap();
+/// assert_eq!(unsafe { CFArrayGetCount(&array) }, 0);
+/// ```
+#[repr(transparent)]
+pub struct CFRetained<T: CFType> {
+    ptr: NonNull<T>,
+    /// Necessary for dropck, since we own a reference to `T`.
+    item: PhantomData<T>,
+    /// Like `Id`, the object may contain interior mutability.
+    notunwindsafe: PhantomData<&'static mut ()>,
+}
+
+impl<T: CFType> CFRetained<T> {
+    /// Construct a [`CFRetained`] from a pointer that already has +1 retain
+    /// count, such as one returned from a `Create` function.
+    ///
+    /// Returns `None` if the pointer was NULL.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be a valid instance of `T`, and the caller must own
+    /// a reference count to it, which is transferred to the returned value.
+    #[inline]
+    pub unsafe fn new(ptr: *mut T) -> Option<Self> {
+        NonNull::new(ptr).map(|ptr| Self {
+            ptr,
+            item: PhantomData,
+            notunwindsafe: PhantomData,
+        })
+    }
+
+    /// Retain the pointer and construct a [`CFRetained`] from it, such as
+    /// one returned from a `Get` function.
+    ///
+    /// Returns `None` if the pointer was NULL.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be NULL or a valid instance of `T`.
+    #[doc(alias = "CFRetain")]
+    #[inline]
+    pub unsafe fn retain(ptr: *mut T) -> Option<Self> {
+        let ptr = NonNull::new(ptr)?;
+        // SAFETY: The pointer is valid
+        unsafe { T::retain(ptr) };
+        // SAFETY: We just retained the object
+        unsafe { Self::new(ptr.as_ptr()) }
+    }
+
+    /// Returns a raw pointer to the object.
+    ///
+    /// The pointer is valid for at least as long as the `CFRetained` is held.
+    ///
+    /// This is an associated method, and must be called as
+    /// `CFRetained::as_ptr(&obj)`.
+    #[inline]
+    pub fn as_ptr(this: &Self) -> *const T {
+        this.ptr.as_ptr()
+    }
+
+    /// Consumes the `CFRetained`, returning a raw pointer with +1 retain
+    /// count.
+    ///
+    /// This is useful when returning an object from a function that is
+    /// annotated with `CF_RETURNS_RETAINED`. The pointer should later be
+    /// converted back with [`CFRetained::new`] to avoid a leak.
+    ///
+    /// This is an associated method, and must be called as
+    /// `CFRetained::into_raw(obj)`.
+    #[inline]
+    pub fn into_raw(this: Self) -> *mut T {
+        ManuallyDrop::new(this).ptr.as_ptr()
+    }
+
+    /// Convert the type of the given object to another.
+    ///
+    /// This is an associated method, and must be called as
+    /// `CFRetained::cast(obj)`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must be a valid instance of `U`, such as when converting
+    /// from a `CFTypeRef` to a more specific type after checking its type
+    /// ID.
+    #[inline]
+    pub unsafe fn cast<U: CFType>(this: Self) -> CFRetained<U> {
+        let ptr = Self::into_raw(this).cast::<U>();
+        // SAFETY: The pointer is non-null and has +1 retain count, and the
+        // caller upholds that it is a valid instance of `U`.
+        unsafe { CFRetained::new(ptr).unwrap_unchecked() }
+    }
+}
+
+impl<T: TollFreeBridged> CFRetained<T> {
+    /// Convert the object to its Objective-C counterpart, transferring
+    /// ownership to the returned [`Id`].
+    ///
+    /// This is equivalent to `CFBridgingRelease` or a `__bridge_transfer`
+    /// cast in Objective-C.
+    ///
+    /// This is an associated method, and must be called as
+    /// `CFRetained::into_id(obj)`.
+    #[doc(alias = "CFBridgingRelease")]
+    #[doc(alias = "__bridge_transfer")]
+    #[inline]
+    pub fn into_id(this: Self) -> Id<T::Bridged> {
+        let ptr = Self::into_raw(this).cast::<T::Bridged>();
+        // SAFETY: The object is a valid instance of the bridged class, and
+        // the retain count is transferred to the `Id`. Toll-free bridged
+        // types use the same reference count in CoreFoundation and in the
+        // Objective-C runtime.
+        unsafe { Id::new(ptr).unwrap_unchecked() }
+    }
+
+    /// Convert an Objective-C object to its CoreFoundation counterpart,
+    /// transferring ownership to the returned [`CFRetained`].
+    ///
+    /// This is equivalent to `CFBridgingRetain` or a `__bridge_retained`
+    /// cast in Objective-C.
+    #[doc(alias = "CFBridgingRetain")]
+    #[doc(alias = "__bridge_retained")]
+    #[inline]
+    pub fn from_id(obj: Id<T::Bridged>) -> Self {
+        let ptr = Id::consume_as_ptr(ManuallyDrop::new(obj));
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(ptr, super::leak_tracker::TrackedEventKind::Release);
+        let ptr = ptr.cast::<T>();
+        // SAFETY: Same as in `into_id`.
+        unsafe { Self::new(ptr).unwrap_unchecked() }
+    }
+
+    /// Get a reference to the object as its Objective-C counterpart,
+    /// without changing the reference count.
+    ///
+    /// This is equivalent to a `__bridge` cast in Objective-C.
+    ///
+    /// This is an associated method, and must be called as
+    /// `CFRetained::as_bridged(&obj)`.
+    #[doc(alias = "__bridge")]
+    #[inline]
+    pub fn as_bridged(this: &Self) -> &T::Bridged {
+        // SAFETY: The object is a valid instance of the bridged class.
+        unsafe { &*Self::as_ptr(this).cast::<T::Bridged>() }
+    }
+}
+
+impl<T: CFType> Drop for CFRetained<T> {
+    /// Releases the object.
+    #[doc(alias = "CFRelease")]
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: We own a reference count to the object.
+        unsafe { T::release(self.ptr) }
+    }
+}
+
+impl<T: CFType> Clone for CFRetained<T> {
+    /// Retain the object, increasing its reference count.
+    #[doc(alias = "CFRetain")]
+    #[inline]
+    fn clone(&self) -> Self {
+        // SAFETY: The pointer is valid.
+        let obj = unsafe { Self::retain(self.ptr.as_ptr()) };
+        // SAFETY: The pointer is non-null.
+        unsafe { obj.unwrap_unchecked() }
+    }
+}
+
+impl<T: CFType> Deref for CFRetained<T> {
+    type Target = T;
+
+    /// Obtain an immutable reference to the object.
+    #[inline]
+    fn deref(&self) -> &T {
+        // SAFETY: The pointer's validity is verified when the type is
+        // created.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T: CFType> fmt::Pointer for CFRetained<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&self.ptr.as_ptr(), f)
+    }
+}
+
+impl<T: CFType> fmt::Debug for CFRetained<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CFRetained")
+            .field(&self.ptr.as_ptr())
+            .finish()
+    }
+}
+
+impl<T: TollFreeBridged> From<CFRetained<T>> for Id<T::Bridged> {
+    #[inline]
+    fn from(obj: CFRetained<T>) -> Self {
+        CFRetained::into_id(obj)
+    }
+}
+
+// SAFETY: CoreFoundation's reference counting is thread-safe, and
+// `CFThreadSafe` guarantees that the object itself may be used from any
+// thread.
+unsafe impl<T: CFThreadSafe> Send for CFRetained<T> {}
+
+// SAFETY: Same as above.
+unsafe impl<T: CFThreadSafe> Sync for CFRetained<T> {}
+
+impl<T: CFType> Unpin for CFRetained<T> {}
+
+impl<T: CFType + RefUnwindSafe> RefUnwindSafe for CFRetained<T> {}
+
+impl<T: CFType + RefUnwindSafe> UnwindSafe for CFRetained<T> {}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+    use core::mem;
+
+    use super::*;
+
+    /// A type with custom reference-counting functions, that doesn't need
+    /// CoreFoundation to be linked.
+    struct TestType {
+        retain_count: Cell<usize>,
+    }
+
+    unsafe impl CFType for TestType {
+        unsafe fn retain(ptr: NonNull<Self>) {
+            let obj = unsafe { ptr.as_ref() };
+            obj.retain_count.set(obj.retain_count.get() + 1);
+        }
+
+        unsafe fn release(ptr: NonNull<Self>) {
+            let obj = unsafe { ptr.as_ref() };
+            obj.retain_count.set(obj.retain_count.get() - 1);
+        }
+    }
+
+    #[test]
+    fn test_custom_retain_release() {
+        let mut obj = TestType {
+            retain_count: Cell::new(1),
+        };
+        let ptr: *mut TestType = &mut obj;
+
+        let retained = unsafe { CFRetained::new(ptr) }.unwrap();
+        assert_eq!(retained.retain_count.get(), 1);
+        assert_eq!(CFRetained::as_ptr(&retained), ptr as *const TestType);
+
+        let cloned = retained.clone();
+        assert_eq!(retained.retain_count.get(), 2);
+        drop(cloned);
+        assert_eq!(retained.retain_count.get(), 1);
+
+        let retained2 = unsafe { CFRetained::retain(ptr) }.unwrap();
+        assert_eq!(retained.retain_count.get(), 2);
+        drop(retained2);
+        assert_eq!(retained.retain_count.get(), 1);
+
+        let raw = CFRetained::into_raw(retained);
+        assert_eq!(raw, ptr);
+        assert_eq!(obj.retain_count.get(), 1);
+    }
+
+    #[test]
+    fn test_null() {
+        assert!(unsafe { CFRetained::<TestType>::new(core::ptr::null_mut()) }.is_none());
+        assert!(unsafe { CFRetained::<TestType>::retain(core::ptr::null_mut()) }.is_none());
+    }
+
+    #[test]
+    fn test_send_sync() {
+        use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+        #[repr(C)]
+        struct Opaque {
+            _priv: [u8; 0],
+        }
+
+        unsafe impl CFType for Opaque {}
+
+        #[repr(C)]
+        struct ThreadSafeOpaque {
+            _priv: [u8; 0],
+        }
+
+        unsafe impl CFType for ThreadSafeOpaque {}
+
+        unsafe impl CFThreadSafe for ThreadSafeOpaque {}
+
+        assert_impl_all!(Opaque: Send, Sync);
+        assert_not_impl_any!(CFRetained<Opaque>: Send, Sync);
+        assert_impl_all!(CFRetained<ThreadSafeOpaque>: Send, Sync);
+    }
+
+    #[test]
+    #[cfg(all(feature = "apple", feature = "link-core-foundation"))]
+    fn test_bridging() {
+        use crate::rc::{__RcTestObject, __ThreadTestData};
+
+        #[repr(C)]
+        struct BridgedTestType {
+            _priv: [u8; 0],
+        }
+
+        unsafe impl CFType for BridgedTestType {}
+
+        unsafe impl TollFreeBridged for BridgedTestType {
+            type Bridged = __RcTestObject;
+        }
+
+        let obj = __RcTestObject::new();
+        let ptr = Id::as_ptr(&obj);
+        let mut expected = __ThreadTestData::current();
+
+        // Converting does not change the retain count
+        let cf = CFRetained::<BridgedTestType>::from_id(obj);
+        expected.assert_current();
+        assert!(core::ptr::eq(CFRetained::as_bridged(&cf), ptr));
+        assert_eq!(CFRetained::as_ptr(&cf).cast(), ptr);
+
+        let obj: Id<__RcTestObject> = CFRetained::into_id(cf);
+        expected.assert_current();
+        assert_eq!(Id::as_ptr(&obj), ptr);
+
+        // `CFRelease` forwards to `-release`
+        let cf = CFRetained::<BridgedTestType>::from_id(obj);
+        drop(cf);
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+    }
+
+    #[test]
+    fn test_size_of() {
+        assert_eq!(
+            mem::size_of::<Option<CFRetained<TestType>>>(),
+            mem::size_of::<*const ()>()
+        );
+    }
+}
@@ -204,11 +204,20 @@ impl<T: ?Sized + Message> Id<T> {
     // Note: We don't take a reference as a parameter since it would be too
     // easy to accidentally create two aliasing mutable references.
     pub unsafe fn new(ptr: *mut T) -> Option<Self> {
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(ptr, super::leak_tracker::TrackedEventKind::New);
         // Should optimize down to a noop.
         // SAFETY: Upheld by the caller
         NonNull::new(ptr).map(|ptr| unsafe { Id::new_nonnull(ptr) })
     }
 
+    /// Same as [`Id::new`], but without recording the leak tracker event.
+    #[inline]
+    unsafe fn new_untracked(ptr: *mut T) -> Option<Self> {
+        // SAFETY: Upheld by the caller
+        NonNull::new(ptr).map(|ptr| unsafe { Id::new_nonnull(ptr) })
+    }
+
     /// Returns a raw pointer to the object.
     ///
     /// The pointer is valid for at least as long as the `Id` is held.
@@ -323,8 +332,10 @@ impl<T: Message> Id<T> {
         // SAFETY: The caller upholds that the pointer is valid
         let res: *mut T = unsafe { ffi::objc_retain(ptr.cast()) }.cast();
         debug_assert_eq!(res, ptr, "objc_retain did not return the same pointer");
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(res, super::leak_tracker::TrackedEventKind::Retain);
         // SAFETY: We just retained the object, so it has +1 retain count
-        unsafe { Self::new(res) }
+        unsafe { Self::new_untracked(res) }
     }
 
     /// Retains a previously autoreleased object pointer.
@@ -446,13 +457,22 @@ impl<T: Message> Id<T> {
             "objc_retainAutoreleasedReturnValue did not return the same pointer"
         );
 
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(
+            res,
+            super::leak_tracker::TrackedEventKind::RetainAutoreleased,
+        );
+
         // SAFETY: Same as `Id::retain`.
-        unsafe { Self::new(res) }
+        unsafe { Self::new_untracked(res) }
     }
 
     #[inline]
-    pub(super) fn autorelease_inner(this: Self) -> *mut T {
+    pub(crate) fn autorelease_inner(this: Self) -> *mut T {
         let ptr = ManuallyDrop::new(this).ptr.as_ptr();
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(ptr, super::leak_tracker::TrackedEventKind::Autorelease);
+        super::autorelease::record_autorelease();
         // SAFETY:
         // - The `ptr` is guaranteed to be valid and have at least one
         //   retain count.
@@ -513,6 +533,8 @@ impl<T: Message> Id<T> {
         let ptr: *mut T = this
             .map(|this| ManuallyDrop::new(this).ptr.as_ptr())
             .unwrap_or_else(ptr::null_mut);
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(ptr, super::leak_tracker::TrackedEventKind::Autorelease);
 
         // SAFETY: Same as `autorelease_inner`, this is just an optimization.
         let res: *mut T = unsafe { ffi::objc_autoreleaseReturnValue(ptr.cast()) }.cast();
@@ -648,6 +670,12 @@ impl<T: ?Sized> Drop for Id<T> {
         // but that would be confusing and inconsistent since we cannot really
         // guarantee that it is run if the `Id<T>` is passed to Objective-C.
 
+        #[cfg(feature = "leak-tracker")]
+        super::leak_tracker::record(
+            self.ptr.as_ptr(),
+            super::leak_tracker::TrackedEventKind::Release,
+        );
+
         // SAFETY: The `ptr` is guaranteed to be valid and have at least one
         // retain count.
         unsafe { ffi::objc_release(self.ptr.as_ptr().cast()) };
@@ -702,6 +730,12 @@ mod private {
 
     pub struct BoxLikeStorage<T: ?Sized>(T);
 
+    // Neither `Send` nor `Sync`, regardless of `T`.
+    pub struct ThreadConfinedStorage<T: ?Sized>(*const T);
+    impl<T: ?Sized + RefUnwindSafe> RefUnwindSafe for ThreadConfinedStorage<T> {}
+    impl<T: ?Sized + RefUnwindSafe> UnwindSafe for ThreadConfinedStorage<T> {}
+    impl<T: ?Sized> Unpin for ThreadConfinedStorage<T> {}
+
     use crate::mutability;
 
     #[doc(hidden)]
@@ -741,6 +775,10 @@ mod private {
         type EquivalentType = ArcLikeStorage<T>;
     }
 
+    impl<T: ?Sized> IdSendSyncHelper<T> for mutability::ThreadConfined {
+        type EquivalentType = ThreadConfinedStorage<T>;
+    }
+
     /// Helper struct for avoiding a gnarly ICE in `rustdoc` when generating
     /// documentation for `icrate` iterator helpers (in particular, it fails
     /// in generating auto trait implementations).
@@ -810,7 +848,7 @@ mod tests {
     use static_assertions::{assert_impl_all, assert_not_impl_any};
 
     use super::*;
-    use crate::mutability::{Immutable, Mutable};
+    use crate::mutability::{Immutable, Mutable, ThreadConfined};
     use crate::rc::{__RcTestObject, __ThreadTestData, autoreleasepool};
     use crate::runtime::{AnyObject, NSObject};
     use crate::{declare_class, msg_send};
@@ -863,6 +901,14 @@ mod tests {
         assert_not_impl_any!(Id<MutableSyncObject>: Send);
         assert_impl_all!(Id<MutableSyncObject>: Sync);
         assert_impl_all!(Id<MutableSendSyncObject>: Send, Sync);
+
+        helper!(ThreadConfinedObject, ThreadConfined);
+        helper!(ThreadConfinedSendSyncObject, ThreadConfined);
+        unsafe impl Send for ThreadConfinedSendSyncObject {}
+        unsafe impl Sync for ThreadConfinedSendSyncObject {}
+
+        assert_not_impl_any!(Id<ThreadConfinedObject>: Send, Sync);
+        assert_not_impl_any!(Id<ThreadConfinedSendSyncObject>: Send, Sync);
     }
 
     #[track_caller]
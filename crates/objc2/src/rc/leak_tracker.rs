@@ -0,0 +1,411 @@
+//! A reference-count leak tracker for [`Id`], for use in tests.
+//!
+//! Every time an `Id` is created (with [`Id::new`], [`Id::retain`],
+//! [`Id::retain_autoreleased`] or [`Clone::clone`]), autoreleased, or
+//! dropped, the event is recorded with a backtrace, if tracking is active.
+//!
+//! [`Id`]: super::Id
+//! [`Id::new`]: super::Id::new
+//! [`Id::retain`]: super::Id::retain
+//! [`Id::retain_autoreleased`]: super::Id::retain_autoreleased
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::sync::atomic::{AtomicUsize, Ordering};
+use std::backtrace::Backtrace;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread::{self, ThreadId};
+
+use crate::runtime::AnyObject;
+
+/// The kind of a [`TrackedEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TrackedEventKind {
+    /// An `Id` took over an existing +1 retain count, with `Id::new`.
+    New,
+    /// An `Id` was created by retaining the object, with `Id::retain`
+    /// or `Clone::clone`.
+    Retain,
+    /// An `Id` was created with `Id::retain_autoreleased`.
+    RetainAutoreleased,
+    /// An `Id` was autoreleased, with `Id::autorelease` or
+    /// `Id::autorelease_return`.
+    Autorelease,
+    /// An `Id` was dropped.
+    Release,
+}
+
+impl TrackedEventKind {
+    fn balance(self) -> isize {
+        match self {
+            Self::New | Self::Retain | Self::RetainAutoreleased => 1,
+            Self::Autorelease | Self::Release => -1,
+        }
+    }
+}
+
+/// A single recorded reference-counting operation on an object.
+#[derive(Debug)]
+pub struct TrackedEvent {
+    kind: TrackedEventKind,
+    thread: ThreadId,
+    backtrace: Backtrace,
+}
+
+impl TrackedEvent {
+    /// The kind of operation that was performed.
+    pub fn kind(&self) -> TrackedEventKind {
+        self.kind
+    }
+
+    /// The thread that the operation was performed on.
+    pub fn thread(&self) -> ThreadId {
+        self.thread
+    }
+
+    /// The backtrace of where the operation was performed.
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+#[derive(Debug)]
+struct Record {
+    seq: usize,
+    class_name: String,
+    balance: isize,
+    events: Vec<TrackedEvent>,
+}
+
+/// An object that had more references created than released inside
+/// [`track`].
+#[derive(Debug)]
+pub struct Leak {
+    ptr: usize,
+    class_name: String,
+    balance: isize,
+    events: Vec<TrackedEvent>,
+}
+
+impl Leak {
+    /// A pointer to the object.
+    ///
+    /// The object may have been deallocated since, so this should only be
+    /// used for identification purposes.
+    pub fn ptr(&self) -> *const AnyObject {
+        self.ptr as *const AnyObject
+    }
+
+    /// The name of the object's class, at the time it was first seen.
+    pub fn class_name(&self) -> &str {
+        &self.class_name
+    }
+
+    /// The number of retain counts that were acquired but not released.
+    pub fn leaked_count(&self) -> usize {
+        self.balance as usize
+    }
+
+    /// Every recorded operation on the object, in order.
+    pub fn events(&self) -> &[TrackedEvent] {
+        &self.events
+    }
+}
+
+impl fmt::Display for Leak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "object {:p} of class {} leaked {} retain count(s):",
+            self.ptr(),
+            self.class_name,
+            self.balance,
+        )?;
+        for event in &self.events {
+            writeln!(f, "  {:?} on {:?} at:", event.kind, event.thread)?;
+            writeln!(f, "{}", event.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+/// The result of [`track`].
+#[derive(Debug, Default)]
+#[must_use = "the report should be checked with `assert_no_leaks`"]
+pub struct LeakReport {
+    leaks: Vec<Leak>,
+}
+
+impl LeakReport {
+    /// Whether no leaks were found.
+    pub fn is_empty(&self) -> bool {
+        self.leaks.is_empty()
+    }
+
+    /// The leaked objects, in the order that they were first seen.
+    pub fn leaks(&self) -> &[Leak] {
+        &self.leaks
+    }
+
+    /// Panic with the details of every leak, if there were any.
+    #[track_caller]
+    pub fn assert_no_leaks(&self) {
+        if !self.is_empty() {
+            panic!("found {} leaked object(s):\n{}", self.leaks.len(), self);
+        }
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for leak in &self.leaks {
+            write!(f, "{leak}")?;
+        }
+        Ok(())
+    }
+}
+
+struct Scope {
+    id: usize,
+    thread: ThreadId,
+    next_seq: usize,
+    // Keyed by the object's address.
+    objects: HashMap<usize, Record>,
+}
+
+/// The number of active scopes, to make the common case of no tracking
+/// cheap.
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+static SCOPES: Mutex<Vec<Scope>> = Mutex::new(Vec::new());
+
+fn scopes() -> MutexGuard<'static, Vec<Scope>> {
+    // The lock is never held while running user code.
+    SCOPES.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[inline]
+pub(crate) fn record<T: ?Sized>(ptr: *const T, kind: TrackedEventKind) {
+    if ACTIVE.load(Ordering::Relaxed) != 0 && !ptr.is_null() {
+        record_slow(ptr.cast(), kind);
+    }
+}
+
+#[cold]
+fn record_slow(ptr: *const AnyObject, kind: TrackedEventKind) {
+    let thread = thread::current().id();
+    let mut scopes = scopes();
+    for scope in scopes.iter_mut() {
+        let key = ptr as usize;
+        // Objects are tracked from the thread that started the scope, but
+        // once tracked, operations on other threads (such as an `Id` that
+        // was sent elsewhere being dropped) are recorded as well.
+        let record = match scope.objects.get_mut(&key) {
+            Some(record) => record,
+            None if scope.thread == thread => {
+                let seq = scope.next_seq;
+                scope.next_seq += 1;
+                // SAFETY: The object is valid, since it is only recorded
+                // while an `Id` to it exists.
+                let class_name = unsafe { &*ptr }.class().name().to_string();
+                scope.objects.entry(key).or_insert(Record {
+                    seq,
+                    class_name,
+                    balance: 0,
+                    events: Vec::new(),
+                })
+            }
+            None => continue,
+        };
+        record.balance += kind.balance();
+        record.events.push(TrackedEvent {
+            kind,
+            thread,
+            backtrace: Backtrace::force_capture(),
+        });
+    }
+}
+
+/// Pops the scope when dropped, so that it doesn't stay around if the
+/// closure panics.
+struct ScopeGuard {
+    id: usize,
+}
+
+impl ScopeGuard {
+    fn take(&self) -> Option<Scope> {
+        let mut scopes = scopes();
+        let index = scopes.iter().position(|scope| scope.id == self.id)?;
+        let scope = scopes.remove(index);
+        ACTIVE.fetch_sub(1, Ordering::Relaxed);
+        Some(scope)
+    }
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        let _ = self.take();
+    }
+}
+
+/// Track reference-counting operations on [`Id`]s while running the given
+/// closure, and report the objects that were leaked.
+///
+/// An object is considered leaked if more [`Id`]s to it were created inside
+/// the closure than were dropped or autoreleased; for example because of
+/// [`mem::forget`], or because of a reference cycle. Each leaked object
+/// includes every recorded operation on it, along with a backtrace.
+///
+/// Objects are recorded when an `Id` to them is first created on the
+/// current thread; operations on them on other threads are recorded as
+/// well after that. Retains and releases done by Objective-C code, such as
+/// when inserting an object into an `NSArray`, are not visible to the
+/// tracker. Autoreleasing an `Id` is counted as releasing it.
+///
+/// Tracking can be nested; an operation is recorded in every enclosing
+/// scope.
+///
+/// This requires the `"leak-tracker"` feature, and is intended for use in
+/// tests, since it captures a backtrace on every operation while active.
+///
+/// [`Id`]: super::Id
+/// [`mem::forget`]: core::mem::forget
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::rc::track;
+/// use objc2::runtime::NSObject;
+///
+/// let report = track(|| {
+///     let obj = NSObject::new();
+///     let _clone = obj.clone();
+/// });
+/// report.assert_no_leaks();
+///
+/// let report = track(|| {
+///     let obj = NSObject::new();
+///     core::mem::forget(obj);
+/// });
+/// assert_eq!(report.leaks().len(), 1);
+/// assert_eq!(report.leaks()[0].class_name(), "NSObject");
+/// ```
+pub fn track(f: impl FnOnce()) -> LeakReport {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    scopes().push(Scope {
+        id,
+        thread: thread::current().id(),
+        next_seq: 0,
+        objects: HashMap::new(),
+    });
+    ACTIVE.fetch_add(1, Ordering::Relaxed);
+    let guard = ScopeGuard { id };
+
+    f();
+
+    let scope = guard.take().expect("leak tracker scope was removed");
+    let mut records: Vec<(usize, Record)> = scope
+        .objects
+        .into_iter()
+        .filter(|(_, record)| record.balance > 0)
+        .collect();
+    records.sort_by_key(|(_, record)| record.seq);
+    LeakReport {
+        leaks: records
+            .into_iter()
+            .map(|(ptr, record)| Leak {
+                ptr,
+                class_name: record.class_name,
+                balance: record.balance,
+                events: record.events,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc::Id;
+    use crate::rc::{__RcTestObject, autoreleasepool};
+    use crate::runtime::NSObject;
+    use core::mem;
+
+    #[test]
+    fn test_no_leaks() {
+        let report = track(|| {
+            let obj = NSObject::new();
+            let obj2 = obj.clone();
+            drop(obj);
+            autoreleasepool(|pool| {
+                let _ = Id::autorelease(obj2, pool);
+            });
+        });
+        report.assert_no_leaks();
+    }
+
+    #[test]
+    fn test_leak() {
+        let report = track(|| {
+            let obj = __RcTestObject::new();
+            let _obj2 = obj.clone();
+            mem::forget(obj);
+        });
+        assert_eq!(report.leaks().len(), 1);
+        let leak = &report.leaks()[0];
+        assert_eq!(leak.class_name(), "__RcTestObject");
+        assert_eq!(leak.leaked_count(), 1);
+        let kinds: Vec<_> = leak.events().iter().map(|event| event.kind()).collect();
+        assert_eq!(
+            kinds,
+            [
+                TrackedEventKind::New,
+                TrackedEventKind::Retain,
+                TrackedEventKind::Release
+            ]
+        );
+        assert!(report.to_string().contains("__RcTestObject"));
+    }
+
+    #[test]
+    fn test_existing_objects_not_reported() {
+        let obj = NSObject::new();
+        let report = track(|| {
+            let _obj2 = obj.clone();
+        });
+        report.assert_no_leaks();
+        // Dropped outside the scope
+        let obj2 = obj.clone();
+        let report = track(|| drop(obj2));
+        report.assert_no_leaks();
+    }
+
+    #[test]
+    fn test_dropped_on_other_thread() {
+        let report = track(|| {
+            let obj = __RcTestObject::new();
+            std::thread::spawn(move || drop(obj)).join().unwrap();
+        });
+        report.assert_no_leaks();
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut inner = None;
+        let outer = track(|| {
+            let obj = NSObject::new();
+            inner = Some(track(|| mem::forget(obj.clone())));
+        });
+        assert_eq!(inner.unwrap().leaks().len(), 1);
+        assert_eq!(outer.leaks().len(), 1);
+    }
+
+    #[test]
+    #[should_panic = "found 1 leaked object(s)"]
+    fn test_assert_no_leaks() {
+        track(|| mem::forget(NSObject::new())).assert_no_leaks();
+    }
+}
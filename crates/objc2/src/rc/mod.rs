@@ -9,7 +9,15 @@
 //!
 //! Weak references may be created using the [`WeakId`] struct; these will not
 //! retain the object, but one can attempt to load them and obtain an `Id`, or
-//! safely fail if the object has been deallocated.
+//! safely fail if the object has been deallocated. For the cases where
+//! neither is possible, such as `assign` properties, [`Unretained`] stores a
+//! pointer without retaining it.
+//!
+//! CoreFoundation types are reference-counted with `CFRetain` and
+//! `CFRelease` instead; [`CFRetained`] is the equivalent of `Id` for those.
+//!
+//! With the `"leak-tracker"` feature, `track` can be used in tests to find
+//! `Id`s that were never released.
 //!
 //! See [the clang documentation][clang-arc] and [the Apple article on memory
 //! management][mem-mgmt] (similar document exists [for Core Foundation][cf])
@@ -49,19 +57,32 @@
 //! ```
 
 mod allocated;
+mod atomic_id;
 mod autorelease;
+mod cf_retained;
 mod id;
 mod id_forwarding_impls;
 mod id_traits;
+#[cfg(feature = "leak-tracker")]
+// The feature documents that it requires `std::backtrace`.
+#[clippy::msrv = "1.65"]
+mod leak_tracker;
 mod test_object;
+mod unretained;
 mod weak_id;
 mod writeback;
 
-pub use self::allocated::Allocated;
+pub use self::allocated::{Allocated, PartialInit};
+pub use self::atomic_id::AtomicId;
 pub use self::autorelease::{
-    autoreleasepool, autoreleasepool_leaking, AutoreleasePool, AutoreleaseSafe,
+    autoreleasepool, autoreleasepool_leaking, AutoreleasePool, AutoreleasePoolStats,
+    AutoreleaseSafe,
 };
+pub use self::cf_retained::{CFRetained, CFThreadSafe, CFType, TollFreeBridged};
 pub use self::id::Id;
 pub use self::id_traits::{DefaultId, IdFromIterator, IdIntoIterator};
+#[cfg(feature = "leak-tracker")]
+pub use self::leak_tracker::{track, Leak, LeakReport, TrackedEvent, TrackedEventKind};
 pub use self::test_object::{__RcTestObject, __ThreadTestData};
+pub use self::unretained::Unretained;
 pub use self::weak_id::WeakId;
@@ -0,0 +1,357 @@
+#[cfg(all(debug_assertions, feature = "verify"))]
+use alloc::boxed::Box;
+#[cfg(all(debug_assertions, feature = "verify"))]
+use alloc::sync::Arc;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+use super::Id;
+use crate::mutability::IsRetainable;
+use crate::Message;
+
+/// A pointer to an Objective-C object that does not retain it.
+///
+/// This is the equivalent of an `unsafe_unretained` or `assign` property
+/// in Objective-C, as is often used for delegates and back-pointers, where
+/// the owner guarantees that the object outlives the pointer.
+///
+/// Since the object may be deallocated while the pointer is still around,
+/// accessing it is `unsafe`. To help find such errors, when the `"verify"`
+/// feature and `debug_assertions` are enabled, a sentinel object is
+/// associated with the object, which records when the runtime has destroyed
+/// it, and accessing a deallocated object panics instead. This check does not
+/// retain the object, and still allows accessing it from within `dealloc`.
+///
+/// Prefer [`WeakId`] where possible, which is safe to use.
+///
+/// [`WeakId`]: super::WeakId
+///
+///
+/// # Examples
+///
+/// ```
+/// use objc2::rc::Unretained;
+/// use objc2::runtime::NSObject;
+///
+/// let obj = NSObject::new();
+/// let unretained = Unretained::new(&*obj);
+/// // SAFETY: The object is still alive
+/// let obj_ref: &NSObject = unsafe { unretained.get() };
+/// ```
+pub struct Unretained<T: ?Sized> {
+    #[cfg(not(all(debug_assertions, feature = "verify")))]
+    ptr: NonNull<T>,
+    /// Boxed, since the layout of `Arc` is not guaranteed, and this must be
+    /// a single pointer to be usable in `IvarDrop`.
+    #[cfg(all(debug_assertions, feature = "verify"))]
+    #[allow(clippy::redundant_allocation)]
+    state: Box<Arc<verify::State>>,
+    /// Unretained pointers act like raw pointers, so they're not `Send` nor
+    /// `Sync` in any mode.
+    p: PhantomData<*const T>,
+}
+
+impl<T: Message> Unretained<T> {
+    /// Create a new pointer to the object, without retaining it.
+    #[inline]
+    pub fn new(obj: &T) -> Self {
+        Self {
+            #[cfg(not(all(debug_assertions, feature = "verify")))]
+            ptr: NonNull::from(obj),
+            // SAFETY: The object is valid
+            #[cfg(all(debug_assertions, feature = "verify"))]
+            state: Box::new(unsafe { verify::track(NonNull::from(obj).cast()) }),
+            p: PhantomData,
+        }
+    }
+
+    /// Returns a raw pointer to the object.
+    ///
+    /// The object may have been deallocated, in which case the pointer is
+    /// dangling.
+    ///
+    /// This is an associated method, and must be called as
+    /// `Unretained::as_ptr(&unretained)`.
+    #[inline]
+    pub fn as_ptr(this: &Self) -> *const T {
+        #[cfg(not(all(debug_assertions, feature = "verify")))]
+        {
+            this.ptr.as_ptr()
+        }
+        #[cfg(all(debug_assertions, feature = "verify"))]
+        {
+            this.state.obj.as_ptr().cast()
+        }
+    }
+
+    /// Get a reference to the object.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object has been deallocated, when the `"verify"`
+    /// feature and `debug_assertions` are enabled.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must not have been deallocated, and must not be
+    /// deallocated while the returned reference is alive.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn get(&self) -> &T {
+        #[cfg(all(debug_assertions, feature = "verify"))]
+        if self.state.is_deallocated() {
+            panic!(
+                "tried to use deallocated object {:p} through `Unretained`",
+                Self::as_ptr(self),
+            );
+        }
+        // SAFETY: Upheld by the caller
+        unsafe { &*Self::as_ptr(self) }
+    }
+
+    /// Retain the object, and return it as an [`Id`].
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object has been deallocated, when the `"verify"`
+    /// feature and `debug_assertions` are enabled.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must not have been deallocated.
+    #[doc(alias = "objc_retain")]
+    #[inline]
+    #[track_caller]
+    pub unsafe fn retain(&self) -> Id<T>
+    where
+        T: IsRetainable,
+    {
+        // SAFETY: Upheld by the caller
+        unsafe { self.get() }.retain()
+    }
+}
+
+impl<T: ?Sized> Clone for Unretained<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            #[cfg(not(all(debug_assertions, feature = "verify")))]
+            ptr: self.ptr,
+            #[cfg(all(debug_assertions, feature = "verify"))]
+            state: Box::new(Arc::clone(&self.state)),
+            p: PhantomData,
+        }
+    }
+}
+
+impl<T: Message> PartialEq for Unretained<T> {
+    /// Whether the two pointers point to the same object.
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Self::as_ptr(self) == Self::as_ptr(other)
+    }
+}
+
+impl<T: Message> Eq for Unretained<T> {}
+
+impl<T: Message> fmt::Debug for Unretained<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The object may have been deallocated, so we only print the address
+        f.debug_tuple("Unretained")
+            .field(&Self::as_ptr(self))
+            .finish()
+    }
+}
+
+impl<T: Message> fmt::Pointer for Unretained<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Pointer::fmt(&Self::as_ptr(self), f)
+    }
+}
+
+impl<T: Message> From<&T> for Unretained<T> {
+    #[inline]
+    fn from(obj: &T) -> Self {
+        Self::new(obj)
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "verify"))]
+mod verify {
+    use alloc::boxed::Box;
+    use alloc::sync::Arc;
+    use core::ptr::NonNull;
+    use core::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::declare::{Ivar, IvarDrop};
+    use crate::mutability::Mutable;
+    use crate::rc::Id;
+    use crate::runtime::{AnyObject, NSObject};
+    use crate::{declare_class, ffi, msg_send_id, ClassType};
+
+    /// State shared between an `Unretained` (and its clones) and the
+    /// sentinel object associated with the target.
+    ///
+    /// `pub` since it's used in the ivars of `DeallocSentinel`.
+    #[allow(unreachable_pub)]
+    pub struct State {
+        pub(super) obj: NonNull<AnyObject>,
+        deallocated: AtomicBool,
+    }
+
+    // SAFETY: `obj` is only used for its address through `State`. This is
+    // needed since the sentinel may be released on any thread.
+    unsafe impl Send for State {}
+    unsafe impl Sync for State {}
+
+    impl State {
+        pub(super) fn is_deallocated(&self) -> bool {
+            self.deallocated.load(Ordering::Acquire)
+        }
+    }
+
+    declare_class!(
+        /// Associated with the tracked object, so that the runtime releases
+        /// it when destroying that object, after all `dealloc` methods have
+        /// run.
+        struct DeallocSentinel {
+            state: IvarDrop<Box<Arc<State>>, "_state">,
+        }
+
+        mod ivars;
+
+        unsafe impl ClassType for DeallocSentinel {
+            type Super = NSObject;
+            type Mutability = Mutable;
+            const NAME: &'static str = "__Objc2UnretainedDeallocSentinel";
+        }
+    );
+
+    impl Drop for DeallocSentinel {
+        fn drop(&mut self) {
+            self.state.deallocated.store(true, Ordering::Release);
+        }
+    }
+
+    /// Start tracking when the object is deallocated, without retaining it.
+    ///
+    /// The sentinel stays associated with the object until it is
+    /// deallocated, even if the returned state is dropped before that.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The object must be valid.
+    pub(super) unsafe fn track(obj: NonNull<AnyObject>) -> Arc<State> {
+        let state = Arc::new(State {
+            obj,
+            deallocated: AtomicBool::new(false),
+        });
+        let mut sentinel: Id<DeallocSentinel> =
+            unsafe { msg_send_id![DeallocSentinel::class(), new] };
+        Ivar::write(&mut sentinel.state, Box::new(Arc::clone(&state)));
+
+        // The address of the state is unique for as long as the sentinel,
+        // and hence the association, is alive, so it works as the key.
+        let key = Arc::as_ptr(&state).cast();
+        let sentinel_ptr: *mut DeallocSentinel = Id::as_mut_ptr(&mut sentinel);
+        // SAFETY: The object is valid, and the association retains the
+        // sentinel.
+        unsafe {
+            ffi::objc_setAssociatedObject(
+                obj.as_ptr().cast(),
+                key,
+                sentinel_ptr.cast(),
+                ffi::OBJC_ASSOCIATION_RETAIN,
+            )
+        };
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem;
+    use core::ptr;
+
+    use super::*;
+    use crate::declare::IvarDrop;
+    use crate::mutability::Mutable;
+    use crate::rc::{__RcTestObject, __ThreadTestData};
+    use crate::runtime::NSObject;
+    use crate::{declare_class, msg_send_id, ClassType};
+
+    #[test]
+    fn test_unretained() {
+        let obj = __RcTestObject::new();
+        let mut expected = __ThreadTestData::current();
+
+        let unretained = Unretained::new(&*obj);
+        let unretained2 = unretained.clone();
+        expected.assert_current();
+        assert_eq!(unretained, unretained2);
+        assert_eq!(Unretained::as_ptr(&unretained), Id::as_ptr(&obj));
+
+        assert!(ptr::eq(unsafe { unretained.get() }, &*obj));
+        let retained = unsafe { unretained2.retain() };
+        expected.retain += 1;
+        expected.assert_current();
+        assert!(ptr::eq(&*retained, &*obj));
+    }
+
+    #[test]
+    #[cfg_attr(
+        not(all(debug_assertions, feature = "verify")),
+        ignore = "only checked with the `verify` feature and debug assertions"
+    )]
+    #[should_panic = "through `Unretained`"]
+    fn test_use_after_dealloc() {
+        let obj = __RcTestObject::new();
+        let unretained = Unretained::new(&*obj);
+        drop(obj);
+        let _ = unsafe { unretained.get() };
+    }
+
+    #[test]
+    fn test_get_in_dealloc() {
+        declare_class!(
+            struct SelfReferencing {
+                this: IvarDrop<Option<Unretained<NSObject>>, "_this">,
+            }
+
+            mod selfreferencing;
+
+            unsafe impl ClassType for SelfReferencing {
+                type Super = NSObject;
+                type Mutability = Mutable;
+                const NAME: &'static str = "__UnretainedSelfReferencing";
+            }
+        );
+
+        impl Drop for SelfReferencing {
+            fn drop(&mut self) {
+                // The object is still usable while it is being deallocated
+                let this = (*self.this).as_ref().unwrap();
+                assert!(ptr::eq(unsafe { this.get() }, &**self));
+            }
+        }
+
+        let mut obj: Id<SelfReferencing> = unsafe { msg_send_id![SelfReferencing::class(), new] };
+        let this = Unretained::new(&**obj);
+        *obj.this = Some(this);
+        drop(obj);
+    }
+
+    #[test]
+    fn test_size_of() {
+        assert_eq!(
+            mem::size_of::<Option<Unretained<NSObject>>>(),
+            mem::size_of::<*const ()>()
+        );
+    }
+}
@@ -1,12 +1,14 @@
 use alloc::boxed::Box;
 use core::cell::UnsafeCell;
 use core::fmt;
+use core::hash;
 use core::marker::PhantomData;
 use core::ptr;
 use std::panic::{RefUnwindSafe, UnwindSafe};
 
 use super::Id;
 use crate::mutability::{IsIdCloneable, IsRetainable};
+use crate::runtime::AnyObject;
 use crate::{ffi, Message};
 
 /// A weak pointer to an Objective-C reference counted object.
@@ -18,6 +20,11 @@ use crate::{ffi, Message};
 /// Useful for breaking reference cycles and safely checking whether an
 /// object has been deallocated.
 ///
+/// Weak pointers compare equal and hash based on the address of the object
+/// that they were created with, so they can be used as keys in maps, for
+/// example in observer registries. Note that once the object has been
+/// deallocated, a new object may be allocated at the same address.
+///
 ///
 /// # Comparison to `std` types
 ///
@@ -39,14 +46,32 @@ pub struct WeakId<T: ?Sized> {
     /// concurrently, but as long as we only use it through the `objc_XXXWeak`
     /// methods, all access is behind a lock.
     ///
-    /// TODO: Verify the need for UnsafeCell?
     /// TODO: Investigate if we can avoid some allocations using `Pin`.
-    inner: Box<UnsafeCell<*mut ffi::objc_object>>,
+    inner: Box<Inner>,
     /// WeakId inherits variance, dropck and various marker traits from
     /// `Id<T>`.
     item: PhantomData<Id<T>>,
 }
 
+struct Inner {
+    /// The weak pointer itself, which is managed by the runtime.
+    ///
+    /// TODO: Verify the need for UnsafeCell?
+    slot: UnsafeCell<*mut ffi::objc_object>,
+    /// The address of the object that the weak pointer was created with,
+    /// used for comparisons and hashing.
+    addr: *const ffi::objc_object,
+}
+
+impl Inner {
+    fn new_boxed(addr: *const ffi::objc_object) -> Box<Self> {
+        Box::new(Self {
+            slot: UnsafeCell::new(ptr::null_mut()),
+            addr,
+        })
+    }
+}
+
 impl<T: Message> WeakId<T> {
     /// Construct a new weak pointer that references the given object.
     #[doc(alias = "objc_initWeak")]
@@ -78,10 +103,10 @@ impl<T: Message> WeakId<T> {
     /// # Safety
     ///
     /// The object must be valid or null.
-    unsafe fn new_inner(obj: *const T) -> Self {
-        let inner = Box::new(UnsafeCell::new(ptr::null_mut()));
+    pub(crate) unsafe fn new_inner(obj: *const T) -> Self {
+        let inner = Inner::new_boxed(obj.cast());
         // SAFETY: `ptr` will never move, and the caller verifies `obj`
-        let _ = unsafe { ffi::objc_initWeak(inner.get(), (obj as *mut T).cast()) };
+        let _ = unsafe { ffi::objc_initWeak(inner.slot.get(), (obj as *mut T).cast()) };
         Self {
             inner,
             item: PhantomData,
@@ -97,40 +122,98 @@ impl<T: Message> WeakId<T> {
     #[doc(alias = "objc_loadWeakRetained")]
     #[inline]
     pub fn load(&self) -> Option<Id<T>> {
-        let ptr = self.inner.get();
+        let ptr = self.inner.slot.get();
         let obj = unsafe { ffi::objc_loadWeakRetained(ptr) }.cast();
         // SAFETY: The object has +1 retain count
         unsafe { Id::new(obj) }
     }
 
+    /// Whether [`WeakId::load`] would currently return the object.
+    ///
+    /// This is exactly `weak.load().is_some()`: the runtime offers no way of
+    /// inspecting a weak reference without loading it, so the object is
+    /// briefly retained and then released again. If other threads release
+    /// their strong references in the meantime, that release may be the last
+    /// one, and then `dealloc` runs on the current thread.
+    ///
+    /// Note that the object may also be deallocated on another thread right
+    /// after this returns `true`, so this is only useful as a hint; use
+    /// [`WeakId::load`] if you need to access the object.
+    #[doc(alias = "objc_loadWeakRetained")]
+    #[inline]
+    pub fn can_load(&self) -> bool {
+        self.load().is_some()
+    }
+
     // TODO: Add `autorelease(&self, pool) -> Option<&T>` using `objc_loadWeak`?
 }
 
+impl<T: ?Sized> WeakId<T> {
+    /// Returns the address of the object that the weak pointer was created
+    /// with, or NULL if it was created with [`Default::default`].
+    ///
+    /// The object may have been deallocated, so the pointer should only be
+    /// used for identification purposes.
+    ///
+    /// This is an associated method, and must be called as
+    /// `WeakId::as_ptr(&weak)`.
+    #[inline]
+    pub fn as_ptr(this: &Self) -> *const AnyObject {
+        this.inner.addr.cast()
+    }
+
+    /// Whether the two weak pointers were created with the same object.
+    ///
+    /// This is an associated method, and must be called as
+    /// `WeakId::ptr_eq(&a, &b)`.
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.inner.addr == other.inner.addr
+    }
+
+    /// Move the weak pointer out, leaving an empty weak pointer in its
+    /// place.
+    #[doc(alias = "objc_moveWeak")]
+    #[inline]
+    pub fn take(&mut self) -> Self {
+        let inner = Inner::new_boxed(self.inner.addr);
+        // SAFETY: Both pointers are valid, and the destination is
+        // uninitialized.
+        unsafe { ffi::objc_moveWeak(inner.slot.get(), self.inner.slot.get()) };
+        // `objc_moveWeak` leaves the source weak pointer as NULL, which is
+        // still a valid weak pointer.
+        self.inner.addr = ptr::null();
+        Self {
+            inner,
+            item: PhantomData,
+        }
+    }
+}
+
 impl<T: ?Sized> Drop for WeakId<T> {
     /// Destroys the weak pointer.
     #[doc(alias = "objc_destroyWeak")]
     #[inline]
     fn drop(&mut self) {
-        unsafe { ffi::objc_destroyWeak(self.inner.get()) }
+        unsafe { ffi::objc_destroyWeak(self.inner.slot.get()) }
     }
 }
 
-// TODO: Add ?Sized
-impl<T: IsRetainable> Clone for WeakId<T> {
+impl<T: ?Sized> Clone for WeakId<T> {
     /// Make a clone of the weak pointer that points to the same object.
     #[doc(alias = "objc_copyWeak")]
     fn clone(&self) -> Self {
-        let ptr = Box::new(UnsafeCell::new(ptr::null_mut()));
-        unsafe { ffi::objc_copyWeak(ptr.get(), self.inner.get()) };
+        let inner = Inner::new_boxed(self.inner.addr);
+        unsafe { ffi::objc_copyWeak(inner.slot.get(), self.inner.slot.get()) };
         Self {
-            inner: ptr,
+            inner,
             item: PhantomData,
         }
     }
 }
 
 // TODO: Add ?Sized
-impl<T: IsRetainable> Default for WeakId<T> {
+impl<T: Message> Default for WeakId<T> {
     /// Constructs a new weak pointer that doesn't reference any object.
     ///
     /// Calling [`Self::load`] on the return value always gives [`None`].
@@ -150,11 +233,12 @@ impl<T: ?Sized> fmt::Debug for WeakId<T> {
     }
 }
 
-// Same as `std::sync::Weak<T>`.
-unsafe impl<T: Sync + Send + ?Sized + IsIdCloneable> Sync for WeakId<T> {}
+// Same as `std::sync::Weak<T>`, where `Id<T>` acts like `Arc<T>`; so the
+// requirements on `T` depend on its mutability in the same way as for `Id`.
+unsafe impl<T: ?Sized + IsIdCloneable> Sync for WeakId<T> where Id<T>: Send + Sync {}
 
-// Same as `std::sync::Weak<T>`.
-unsafe impl<T: Sync + Send + ?Sized + IsIdCloneable> Send for WeakId<T> {}
+// Same as `std::sync::Weak<T>`, see above.
+unsafe impl<T: ?Sized + IsIdCloneable> Send for WeakId<T> where Id<T>: Send + Sync {}
 
 // Same as `std::sync::Weak<T>`.
 impl<T: ?Sized + Message> Unpin for WeakId<T> {}
@@ -165,6 +249,27 @@ impl<T: RefUnwindSafe + ?Sized + IsIdCloneable> RefUnwindSafe for WeakId<T> {}
 // Same as `std::sync::Weak<T>`.
 impl<T: RefUnwindSafe + ?Sized + IsIdCloneable> UnwindSafe for WeakId<T> {}
 
+impl<T: ?Sized> PartialEq for WeakId<T> {
+    /// Whether the two weak pointers were created with the same object.
+    ///
+    /// See [`WeakId::ptr_eq`].
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        WeakId::ptr_eq(self, other)
+    }
+}
+
+impl<T: ?Sized> Eq for WeakId<T> {}
+
+impl<T: ?Sized> hash::Hash for WeakId<T> {
+    /// Hashes the address of the object that the weak pointer was created
+    /// with.
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        WeakId::as_ptr(self).hash(state)
+    }
+}
+
 impl<T: IsRetainable> From<&T> for WeakId<T> {
     #[inline]
     fn from(obj: &T) -> Self {
@@ -260,6 +365,76 @@ mod tests {
         drop(weak);
     }
 
+    #[test]
+    fn test_weak_can_load() {
+        let obj = __RcTestObject::new();
+        let weak = WeakId::from(&obj);
+        let mut expected = __ThreadTestData::current();
+
+        assert!(weak.can_load());
+        expected.try_retain += 1;
+        expected.release += 1;
+        expected.assert_current();
+
+        drop(obj);
+        expected.release += 1;
+        expected.dealloc += 1;
+        expected.assert_current();
+
+        if cfg!(not(feature = "gnustep-1-7")) {
+            // Same as in `test_weak`
+            assert!(!weak.can_load());
+            expected.assert_current();
+        }
+        assert!(!WeakId::<__RcTestObject>::default().can_load());
+    }
+
+    #[test]
+    // The hash only depends on the address, which is never mutated.
+    #[allow(clippy::mutable_key_type)]
+    fn test_weak_eq_hash() {
+        use std::collections::HashSet;
+
+        let obj1 = __RcTestObject::new();
+        let obj2 = __RcTestObject::new();
+        let weak1 = WeakId::from(&obj1);
+        let weak2 = WeakId::from(&obj2);
+
+        assert_eq!(weak1, weak1.clone());
+        assert_eq!(weak1, WeakId::from(&obj1));
+        assert_ne!(weak1, weak2);
+        assert_eq!(WeakId::as_ptr(&weak1), Id::as_ptr(&obj1).cast());
+
+        let set: HashSet<_> = [weak1.clone(), weak2, weak1.clone()].into_iter().collect();
+        assert_eq!(set.len(), 2);
+
+        // The address is kept after the object has been deallocated
+        drop(obj1);
+        assert!(set.contains(&weak1));
+
+        let empty: WeakId<__RcTestObject> = WeakId::default();
+        assert!(WeakId::as_ptr(&empty).is_null());
+        assert_eq!(empty, WeakId::default());
+    }
+
+    #[test]
+    fn test_weak_take() {
+        let obj = __RcTestObject::new();
+        let mut weak = WeakId::from(&obj);
+        let taken = weak.take();
+
+        assert!(weak.load().is_none());
+        assert!(WeakId::as_ptr(&weak).is_null());
+        assert!(ptr::eq(&*taken.load().unwrap(), &*obj));
+        assert_eq!(taken, WeakId::from(&obj));
+    }
+
+    #[test]
+    fn auto_traits() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<WeakId<__RcTestObject>>();
+    }
+
     #[repr(C)]
     struct MyObject<'a> {
         inner: NSObject,
@@ -1,4 +1,5 @@
-//! Support for passing "out"-parameters to `msg_send!` and family.
+//! Support for passing "out"-parameters to `msg_send!` and family, and for
+//! receiving them in methods declared with `declare_class!`.
 //!
 //! See clang's documentation:
 //! <https://clang.llvm.org/docs/AutomaticReferenceCounting.html#passing-to-an-out-parameter-by-writeback>
@@ -14,6 +15,60 @@ use crate::encode::__unstable::EncodeConvertArgument;
 use crate::rc::Id;
 use crate::Message;
 
+/// Hands the value of an out parameter back to the caller when a method
+/// declared with `declare_class!` returns.
+///
+/// Out parameters are `__autoreleasing` in Objective-C, meaning that the
+/// callee stores an autoreleased value in them. While the method body runs,
+/// the Rust `Id` in the out parameter holds a +1 retain count on its value
+/// (which we took when the method was called), so we give that back by
+/// autoreleasing whatever value the out parameter contains afterwards.
+//
+// Must be `pub` since it is used in the (public) `EncodeConvertArgument`
+// implementations below.
+#[allow(unreachable_pub)]
+#[derive(Debug)]
+pub struct DeclaredOutParam<T: Message> {
+    ptr: Option<NonNull<*mut T>>,
+}
+
+impl<T: Message> DeclaredOutParam<T> {
+    /// Take a +1 retain count on the value in the out parameter.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid for reads and writes for the duration of
+    /// the method, and must contain either NULL or a valid object.
+    ///
+    /// In particular, the slot is read even for `&mut Option<Id<T>>`, so
+    /// callers must initialize it; uninitialized `NSError **` slots should
+    /// instead be handled by returning `Result` from the method.
+    #[inline]
+    unsafe fn new(ptr: Option<NonNull<*mut T>>) -> Self {
+        if let Some(ptr) = ptr {
+            // SAFETY: Upheld by caller.
+            let old = unsafe { Id::retain(*ptr.as_ptr()) };
+            let _ = ManuallyDrop::new(old);
+        }
+        Self { ptr }
+    }
+}
+
+impl<T: Message> Drop for DeclaredOutParam<T> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(ptr) = self.ptr {
+            // SAFETY: The out parameter is valid, and contains either NULL
+            // or an object with a +1 retain count (since it is a `&mut Id`
+            // or `&mut Option<Id>` in the method body).
+            let new: Option<Id<T>> = unsafe { Id::new(*ptr.as_ptr()) };
+            if let Some(new) = new {
+                let _: *mut T = Id::autorelease_inner(new);
+            }
+        }
+    }
+}
+
 // Note the `'static` bound here - this may not be necessary, but I'm unsure
 // of the exact requirements, so we better keep it for now.
 impl<T: Message + 'static> EncodeConvertArgument for &mut Id<T> {
@@ -33,9 +88,22 @@ impl<T: Message + 'static> EncodeConvertArgument for &mut Id<T> {
         NonNull<T>,
     );
 
+    type __DeclaredParamGuard = DeclaredOutParam<T>;
+
     #[inline]
-    fn __from_declared_param(_inner: Self::__Inner) -> Self {
-        todo!("`&mut Id<_>` is not supported in `declare_class!` yet")
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard) {
+        // SAFETY: The caller ensures that the pointer is valid.
+        if unsafe { *inner.as_ptr() }.is_null() {
+            panic!("found that NULL was passed in a `&mut Id<_>` out parameter to a declared method, which is UB! You should handle this with `&mut Option<Id<_>>` instead");
+        }
+        // SAFETY: The out parameter contains a valid object.
+        let guard = unsafe { DeclaredOutParam::new(Some(inner)) };
+        // SAFETY: `Id` is `#[repr(transparent)]` over `NonNull`, which has
+        // the same layout as `*mut T`, and we checked that the pointer is
+        // not NULL. We own a +1 retain count on the object until `guard` is
+        // dropped, which happens after the method body has run.
+        let this = unsafe { inner.cast::<Id<T>>().as_mut() };
+        (this, guard)
     }
 
     #[inline]
@@ -114,9 +182,17 @@ impl<T: Message + 'static> EncodeConvertArgument for &mut Option<Id<T>> {
 
     type __StoredBeforeMessage = (Self::__Inner, *mut T);
 
+    type __DeclaredParamGuard = DeclaredOutParam<T>;
+
     #[inline]
-    fn __from_declared_param(_inner: Self::__Inner) -> Self {
-        todo!("`&mut Option<Id<_>>` is not supported in `declare_class!` yet")
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard) {
+        // SAFETY: The caller ensures that the out parameter is valid.
+        let guard = unsafe { DeclaredOutParam::new(Some(inner)) };
+        // SAFETY: `Option<Id<T>>` has the same memory layout as `*mut T`,
+        // and we own a +1 retain count on the object (if any) until `guard`
+        // is dropped.
+        let this = unsafe { inner.cast::<Option<Id<T>>>().as_mut() };
+        (this, guard)
     }
 
     #[inline]
@@ -161,9 +237,18 @@ impl<T: Message + 'static> EncodeConvertArgument for Option<&mut Id<T>> {
 
     type __StoredBeforeMessage = Option<(NonNull<*mut T>, NonNull<T>)>;
 
+    type __DeclaredParamGuard = Option<DeclaredOutParam<T>>;
+
     #[inline]
-    fn __from_declared_param(_inner: Self::__Inner) -> Self {
-        todo!("`Option<&mut Id<_>>` is not supported in `declare_class!` yet")
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard) {
+        match inner {
+            Some(inner) => {
+                // SAFETY: Checked by caller
+                let (this, guard) = unsafe { <&mut Id<T>>::__from_declared_param(inner) };
+                (Some(this), Some(guard))
+            }
+            None => (None, None),
+        }
     }
 
     #[inline]
@@ -190,9 +275,18 @@ impl<T: Message + 'static> EncodeConvertArgument for Option<&mut Option<Id<T>>>
 
     type __StoredBeforeMessage = Option<(NonNull<*mut T>, *mut T)>;
 
+    type __DeclaredParamGuard = Option<DeclaredOutParam<T>>;
+
     #[inline]
-    fn __from_declared_param(_inner: Self::__Inner) -> Self {
-        todo!("`Option<&mut Option<Id<_>>>` is not supported in `declare_class!` yet")
+    unsafe fn __from_declared_param(inner: Self::__Inner) -> (Self, Self::__DeclaredParamGuard) {
+        match inner {
+            Some(inner) => {
+                // SAFETY: Checked by caller
+                let (this, guard) = unsafe { <&mut Option<Id<T>>>::__from_declared_param(inner) };
+                (Some(this), Some(guard))
+            }
+            None => (None, None),
+        }
     }
 
     #[inline]
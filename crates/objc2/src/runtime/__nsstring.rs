@@ -1,11 +1,12 @@
+use core::ffi::c_void;
 use core::slice;
 use core::str;
 use std::os::raw::c_char;
 
 use crate::ffi::NSUInteger;
-use crate::msg_send;
-use crate::rc::AutoreleasePool;
-use crate::runtime::NSObject;
+use crate::rc::{AutoreleasePool, Id};
+use crate::runtime::{AnyClass, NSObject};
+use crate::{msg_send, msg_send_id};
 
 // Note: While this is not public, it is still a breaking change to modify,
 // since `icrate` relies on it.
@@ -75,3 +76,21 @@ pub unsafe fn nsstring_to_str<'r, 's: 'r, 'p: 'r>(
     // TODO: Always UTF-8, so should we use `from_utf8_unchecked`?
     str::from_utf8(bytes).unwrap()
 }
+
+/// Create an `NSString` from a Rust string.
+///
+/// Returns `None` if `NSString` is not available, or if creating it failed.
+pub(crate) fn nsstring_from_str(s: &str) -> Option<Id<NSObject>> {
+    let cls = AnyClass::get("NSString")?;
+    let bytes: *const c_void = s.as_ptr().cast();
+    // SAFETY: The bytes are valid UTF-8, and `initWithBytes:length:encoding:`
+    // copies them.
+    unsafe {
+        msg_send_id![
+            msg_send_id![cls, alloc],
+            initWithBytes: bytes,
+            length: s.len(),
+            encoding: UTF8_ENCODING,
+        ]
+    }
+}
@@ -1,6 +1,6 @@
 use crate::mutability::{
     Immutable, ImmutableWithMutableSubclass, InteriorMutable, MainThreadOnly, Mutable,
-    MutableWithImmutableSuperclass, Root,
+    MutableWithImmutableSuperclass, Root, ThreadConfined,
 };
 use crate::rc::Id;
 use crate::{msg_send_id, ClassType, ProtocolType};
@@ -93,6 +93,15 @@ impl<T: NSCopying + ClassType<Mutability = MainThreadOnly>> Copyhelper<T> for Ma
         t.retain()
     }
 }
+impl<T: NSCopying + ClassType<Mutability = ThreadConfined>> Copyhelper<T> for ThreadConfined {
+    type CopyOutput = T;
+    type MutableCopyOutput = T;
+
+    #[inline]
+    fn __do_copy(t: &T) -> Id<T> {
+        t.retain()
+    }
+}
 
 /// A protocol to provide functional copies of objects.
 ///
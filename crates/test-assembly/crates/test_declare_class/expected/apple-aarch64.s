@@ -421,76 +421,120 @@ Lloh95:
 	.loh AdrpAdd	Lloh90, Lloh91
 	.loh AdrpAdd	Lloh88, Lloh89
 
+	.globl	SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0)
+	.p2align	2
+SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0):
+	stp	x22, x21, [sp, #-48]!
+	stp	x20, x19, [sp, #16]
+	stp	x29, x30, [sp, #32]
+	add	x29, sp, #32
+	mov	x19, x2
+	mov	x20, x1
+	mov	x21, x0
+	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+Lloh96:
+	adrp	x1, l_anon.[ID].5@PAGE
+Lloh97:
+	add	x1, x1, l_anon.[ID].5@PAGEOFF
+Lloh98:
+	adrp	x3, l_anon.[ID].6@PAGE
+Lloh99:
+	add	x3, x3, l_anon.[ID].6@PAGEOFF
+	mov	w2, #4
+	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	strb	w19, [x21, x0]
+	mov	x0, x21
+	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+Lloh100:
+	adrp	x1, l_anon.[ID].7@PAGE
+Lloh101:
+	add	x1, x1, l_anon.[ID].7@PAGEOFF
+Lloh102:
+	adrp	x3, l_anon.[ID].8@PAGE
+Lloh103:
+	add	x3, x3, l_anon.[ID].8@PAGEOFF
+	mov	w2, #4
+	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	str	x20, [x21, x0]
+	ldp	x29, x30, [sp, #32]
+	ldp	x20, x19, [sp, #16]
+	ldp	x22, x21, [sp], #48
+	ret
+	.loh AdrpAdd	Lloh102, Lloh103
+	.loh AdrpAdd	Lloh100, Lloh101
+	.loh AdrpAdd	Lloh98, Lloh99
+	.loh AdrpAdd	Lloh96, Lloh97
+
 	.globl	SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0)
 	.p2align	2
 SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0):
 	sub	sp, sp, #32
 	stp	x29, x30, [sp, #16]
 	add	x29, sp, #16
-Lloh96:
+Lloh104:
 	adrp	x8, SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)@PAGE
-Lloh97:
+Lloh105:
 	add	x8, x8, SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)@PAGEOFF
 	ldapr	x8, [x8]
 	cmp	x8, #3
-	b.ne	LBB7_3
-Lloh98:
+	b.ne	LBB8_3
+Lloh106:
 	adrp	x0, l_anon.[ID].11@PAGE
-Lloh99:
+Lloh107:
 	add	x0, x0, l_anon.[ID].11@PAGEOFF
 	mov	w1, #15
 	bl	SYM(objc2::runtime::AnyClass::get::GENERATED_ID, 0)
-	cbz	x0, LBB7_4
-LBB7_2:
+	cbz	x0, LBB8_4
+LBB8_2:
 	ldp	x29, x30, [sp, #16]
 	add	sp, sp, #32
 	ret
-LBB7_3:
+LBB8_3:
 	mov	w8, #1
 	strb	w8, [sp, #7]
 	add	x8, sp, #7
 	str	x8, [sp, #8]
-Lloh100:
+Lloh108:
 	adrp	x0, SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)@PAGE
-Lloh101:
+Lloh109:
 	add	x0, x0, SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)@PAGEOFF
-Lloh102:
+Lloh110:
 	adrp	x3, l_anon.[ID].0@PAGE
-Lloh103:
+Lloh111:
 	add	x3, x3, l_anon.[ID].0@PAGEOFF
-Lloh104:
+Lloh112:
 	adrp	x4, l_anon.[ID].10@PAGE
-Lloh105:
+Lloh113:
 	add	x4, x4, l_anon.[ID].10@PAGEOFF
 	add	x2, sp, #8
 	mov	w1, #0
 	bl	SYM(std::sys_common::once::queue::Once::call::GENERATED_ID, 0)
-Lloh106:
+Lloh114:
 	adrp	x0, l_anon.[ID].11@PAGE
-Lloh107:
+Lloh115:
 	add	x0, x0, l_anon.[ID].11@PAGEOFF
 	mov	w1, #15
 	bl	SYM(objc2::runtime::AnyClass::get::GENERATED_ID, 0)
-	cbnz	x0, LBB7_2
-LBB7_4:
-Lloh108:
+	cbnz	x0, LBB8_2
+LBB8_4:
+Lloh116:
 	adrp	x0, l_anon.[ID].2@PAGE
-Lloh109:
+Lloh117:
 	add	x0, x0, l_anon.[ID].2@PAGEOFF
-Lloh110:
+Lloh118:
 	adrp	x2, l_anon.[ID].10@PAGE
-Lloh111:
+Lloh119:
 	add	x2, x2, l_anon.[ID].10@PAGEOFF
 	mov	w1, #43
 	bl	SYM(core::panicking::panic::GENERATED_ID, 0)
-	.loh AdrpAdd	Lloh96, Lloh97
-	.loh AdrpAdd	Lloh98, Lloh99
-	.loh AdrpAdd	Lloh106, Lloh107
 	.loh AdrpAdd	Lloh104, Lloh105
-	.loh AdrpAdd	Lloh102, Lloh103
-	.loh AdrpAdd	Lloh100, Lloh101
+	.loh AdrpAdd	Lloh106, Lloh107
+	.loh AdrpAdd	Lloh114, Lloh115
+	.loh AdrpAdd	Lloh112, Lloh113
 	.loh AdrpAdd	Lloh110, Lloh111
 	.loh AdrpAdd	Lloh108, Lloh109
+	.loh AdrpAdd	Lloh118, Lloh119
+	.loh AdrpAdd	Lloh116, Lloh117
 
 	.p2align	2
 SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::{closure#0}::__objc2_dealloc, 0):
@@ -501,25 +545,25 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	mov	x19, x1
 	mov	x20, x0
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh112:
+Lloh120:
 	adrp	x1, l_anon.[ID].7@PAGE
-Lloh113:
+Lloh121:
 	add	x1, x1, l_anon.[ID].7@PAGEOFF
-Lloh114:
+Lloh122:
 	adrp	x3, l_anon.[ID].8@PAGE
-Lloh115:
+Lloh123:
 	add	x3, x3, l_anon.[ID].8@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	x0, [x20, x0]
-	cbz	x0, LBB8_2
+	cbz	x0, LBB9_2
 	bl	_objc_release
-LBB8_2:
-Lloh116:
+LBB9_2:
+Lloh124:
 	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_NSObject@GOTPAGE
-Lloh117:
+Lloh125:
 	ldr	x8, [x8, L_OBJC_CLASSLIST_REFERENCES_$_NSObject@GOTPAGEOFF]
-Lloh118:
+Lloh126:
 	ldr	x8, [x8]
 	stp	x20, x8, [sp]
 	mov	x0, sp
@@ -529,9 +573,9 @@ Lloh118:
 	ldp	x20, x19, [sp, #16]
 	add	sp, sp, #48
 	ret
-	.loh AdrpAdd	Lloh114, Lloh115
-	.loh AdrpAdd	Lloh112, Lloh113
-	.loh AdrpLdrGotLdr	Lloh116, Lloh117, Lloh118
+	.loh AdrpAdd	Lloh122, Lloh123
+	.loh AdrpAdd	Lloh120, Lloh121
+	.loh AdrpLdrGotLdr	Lloh124, Lloh125, Lloh126
 
 	.globl	_init
 	.p2align	2
@@ -540,32 +584,32 @@ _init:
 	stp	x20, x19, [sp, #16]
 	stp	x29, x30, [sp, #32]
 	add	x29, sp, #32
-Lloh119:
+Lloh127:
 	adrp	x8, L_OBJC_SELECTOR_REFERENCES_init@GOTPAGE
-Lloh120:
+Lloh128:
 	ldr	x8, [x8, L_OBJC_SELECTOR_REFERENCES_init@GOTPAGEOFF]
-Lloh121:
+Lloh129:
 	ldr	x1, [x8]
-Lloh122:
+Lloh130:
 	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_NSObject@GOTPAGE
-Lloh123:
+Lloh131:
 	ldr	x8, [x8, L_OBJC_CLASSLIST_REFERENCES_$_NSObject@GOTPAGEOFF]
-Lloh124:
+Lloh132:
 	ldr	x8, [x8]
 	stp	x0, x8, [sp]
 	mov	x0, sp
 	bl	_objc_msgSendSuper
 	mov	x19, x0
-	cbz	x0, LBB9_2
+	cbz	x0, LBB10_2
 	mov	x0, x19
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh125:
+Lloh133:
 	adrp	x1, l_anon.[ID].5@PAGE
-Lloh126:
+Lloh134:
 	add	x1, x1, l_anon.[ID].5@PAGEOFF
-Lloh127:
+Lloh135:
 	adrp	x3, l_anon.[ID].6@PAGE
-Lloh128:
+Lloh136:
 	add	x3, x3, l_anon.[ID].6@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
@@ -573,29 +617,29 @@ Lloh128:
 	strb	w8, [x19, x0]
 	mov	x0, x19
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh129:
+Lloh137:
 	adrp	x1, l_anon.[ID].7@PAGE
-Lloh130:
+Lloh138:
 	add	x1, x1, l_anon.[ID].7@PAGEOFF
-Lloh131:
+Lloh139:
 	adrp	x3, l_anon.[ID].8@PAGE
-Lloh132:
+Lloh140:
 	add	x3, x3, l_anon.[ID].8@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	str	xzr, [x19, x0]
-LBB9_2:
+LBB10_2:
 	mov	x0, x19
 	ldp	x29, x30, [sp, #32]
 	ldp	x20, x19, [sp, #16]
 	add	sp, sp, #48
 	ret
-	.loh AdrpLdrGotLdr	Lloh122, Lloh123, Lloh124
-	.loh AdrpLdrGotLdr	Lloh119, Lloh120, Lloh121
-	.loh AdrpAdd	Lloh131, Lloh132
-	.loh AdrpAdd	Lloh129, Lloh130
-	.loh AdrpAdd	Lloh127, Lloh128
-	.loh AdrpAdd	Lloh125, Lloh126
+	.loh AdrpLdrGotLdr	Lloh130, Lloh131, Lloh132
+	.loh AdrpLdrGotLdr	Lloh127, Lloh128, Lloh129
+	.loh AdrpAdd	Lloh139, Lloh140
+	.loh AdrpAdd	Lloh137, Lloh138
+	.loh AdrpAdd	Lloh135, Lloh136
+	.loh AdrpAdd	Lloh133, Lloh134
 
 	.globl	_class_method
 	.p2align	2
@@ -621,25 +665,25 @@ _method_id:
 	add	x29, sp, #16
 	mov	x19, x0
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh133:
+Lloh141:
 	adrp	x1, l_anon.[ID].7@PAGE
-Lloh134:
+Lloh142:
 	add	x1, x1, l_anon.[ID].7@PAGEOFF
-Lloh135:
+Lloh143:
 	adrp	x3, l_anon.[ID].8@PAGE
-Lloh136:
+Lloh144:
 	add	x3, x3, l_anon.[ID].8@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	x0, [x19, x0]
-	cbz	x0, LBB13_2
+	cbz	x0, LBB14_2
 	bl	_objc_retain
-LBB13_2:
+LBB14_2:
 	ldp	x29, x30, [sp, #16]
 	ldp	x20, x19, [sp], #32
 	b	_objc_autoreleaseReturnValue
-	.loh AdrpAdd	Lloh135, Lloh136
-	.loh AdrpAdd	Lloh133, Lloh134
+	.loh AdrpAdd	Lloh143, Lloh144
+	.loh AdrpAdd	Lloh141, Lloh142
 
 	.globl	_method_id_with_param
 	.p2align	2
@@ -652,38 +696,38 @@ _method_id_with_param:
 	mov	x20, x0
 	bl	SYM(objc2::runtime::nsobject::NSObject::new::GENERATED_ID, 0)
 	mov	x19, x0
-	cbz	w21, LBB14_5
+	cbz	w21, LBB15_5
 	mov	x0, x20
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh137:
+Lloh145:
 	adrp	x1, l_anon.[ID].7@PAGE
-Lloh138:
+Lloh146:
 	add	x1, x1, l_anon.[ID].7@PAGEOFF
-Lloh139:
+Lloh147:
 	adrp	x3, l_anon.[ID].8@PAGE
-Lloh140:
+Lloh148:
 	add	x3, x3, l_anon.[ID].8@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	x0, [x20, x0]
-	cbz	x0, LBB14_3
+	cbz	x0, LBB15_3
 	bl	_objc_retain
 	mov	x20, x0
-	b	LBB14_4
-LBB14_3:
+	b	LBB15_4
+LBB15_3:
 	mov	x20, #0
-LBB14_4:
+LBB15_4:
 	mov	x0, x19
 	bl	_objc_release
 	mov	x19, x20
-LBB14_5:
+LBB15_5:
 	mov	x0, x19
 	ldp	x29, x30, [sp, #32]
 	ldp	x20, x19, [sp, #16]
 	ldp	x22, x21, [sp], #48
 	b	_objc_autoreleaseReturnValue
-	.loh AdrpAdd	Lloh139, Lloh140
-	.loh AdrpAdd	Lloh137, Lloh138
+	.loh AdrpAdd	Lloh147, Lloh148
+	.loh AdrpAdd	Lloh145, Lloh146
 
 	.globl	_copy_with_zone
 	.p2align	2
@@ -696,16 +740,16 @@ _copy_with_zone:
 	mov	x20, x0
 	bl	_get_obj
 	mov	x19, x0
-	cbz	x0, LBB15_5
+	cbz	x0, LBB16_5
 	mov	x0, x20
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh141:
+Lloh149:
 	adrp	x21, l_anon.[ID].5@PAGE
-Lloh142:
+Lloh150:
 	add	x21, x21, l_anon.[ID].5@PAGEOFF
-Lloh143:
+Lloh151:
 	adrp	x22, l_anon.[ID].6@PAGE
-Lloh144:
+Lloh152:
 	add	x22, x22, l_anon.[ID].6@PAGEOFF
 	mov	x1, x21
 	mov	w2, #4
@@ -721,50 +765,50 @@ Lloh144:
 	strb	w23, [x19, x0]
 	mov	x0, x20
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh145:
+Lloh153:
 	adrp	x1, l_anon.[ID].7@PAGE
-Lloh146:
+Lloh154:
 	add	x1, x1, l_anon.[ID].7@PAGEOFF
-Lloh147:
+Lloh155:
 	adrp	x3, l_anon.[ID].8@PAGE
-Lloh148:
+Lloh156:
 	add	x3, x3, l_anon.[ID].8@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	x0, [x20, x0]
-	cbz	x0, LBB15_3
+	cbz	x0, LBB16_3
 	bl	_objc_retain
 	mov	x20, x0
-	b	LBB15_4
-LBB15_3:
+	b	LBB16_4
+LBB16_3:
 	mov	x20, #0
-LBB15_4:
+LBB16_4:
 	mov	x0, x19
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-Lloh149:
+Lloh157:
 	adrp	x1, l_anon.[ID].7@PAGE
-Lloh150:
+Lloh158:
 	add	x1, x1, l_anon.[ID].7@PAGEOFF
-Lloh151:
+Lloh159:
 	adrp	x3, l_anon.[ID].8@PAGE
-Lloh152:
+Lloh160:
 	add	x3, x3, l_anon.[ID].8@PAGEOFF
 	mov	w2, #4
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	str	x20, [x19, x0]
-LBB15_5:
+LBB16_5:
 	mov	x0, x19
 	ldp	x29, x30, [sp, #48]
 	ldp	x20, x19, [sp, #32]
 	ldp	x22, x21, [sp, #16]
 	ldp	x24, x23, [sp], #64
 	ret
-	.loh AdrpAdd	Lloh147, Lloh148
-	.loh AdrpAdd	Lloh145, Lloh146
-	.loh AdrpAdd	Lloh143, Lloh144
-	.loh AdrpAdd	Lloh141, Lloh142
+	.loh AdrpAdd	Lloh155, Lloh156
+	.loh AdrpAdd	Lloh153, Lloh154
 	.loh AdrpAdd	Lloh151, Lloh152
 	.loh AdrpAdd	Lloh149, Lloh150
+	.loh AdrpAdd	Lloh159, Lloh160
+	.loh AdrpAdd	Lloh157, Lloh158
 
 	.section	__DATA,__const
 	.p2align	3, 0x0
@@ -378,6 +378,42 @@ LPC6_3:
 	mov	r1, r6
 	pop	{r4, r5, r6, r7, pc}
 
+	.globl	SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0)
+	.p2align	2
+	.code	32
+SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0):
+	push	{r4, r5, r6, r7, lr}
+	add	r7, sp, #12
+	mov	r6, r2
+	mov	r4, r1
+	mov	r5, r0
+	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+	movw	r1, :lower16:(L_anon.[ID].5-(LPC7_0+8))
+	mov	r2, #4
+	movt	r1, :upper16:(L_anon.[ID].5-(LPC7_0+8))
+	movw	r3, :lower16:(l_anon.[ID].6-(LPC7_1+8))
+	movt	r3, :upper16:(l_anon.[ID].6-(LPC7_1+8))
+LPC7_0:
+	add	r1, pc, r1
+LPC7_1:
+	add	r3, pc, r3
+	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	strb	r6, [r5, r0]
+	mov	r0, r5
+	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC7_2+8))
+	mov	r2, #4
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC7_2+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC7_3+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC7_3+8))
+LPC7_2:
+	add	r1, pc, r1
+LPC7_3:
+	add	r3, pc, r3
+	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	str	r4, [r5, r0]
+	pop	{r4, r5, r6, r7, pc}
+
 	.globl	SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0)
 	.p2align	2
 	.code	32
@@ -385,58 +421,58 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	push	{r7, lr}
 	mov	r7, sp
 	sub	sp, sp, #12
-	movw	r0, :lower16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC7_0+8))
-	movt	r0, :upper16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC7_0+8))
-LPC7_0:
+	movw	r0, :lower16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC8_0+8))
+	movt	r0, :upper16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC8_0+8))
+LPC8_0:
 	add	r0, pc, r0
 	ldr	r0, [r0]
 	dmb	ish
 	cmp	r0, #3
-	bne	LBB7_3
-LBB7_1:
-	movw	r0, :lower16:(l_anon.[ID].11-(LPC7_4+8))
+	bne	LBB8_3
+LBB8_1:
+	movw	r0, :lower16:(l_anon.[ID].11-(LPC8_4+8))
 	mov	r1, #15
-	movt	r0, :upper16:(l_anon.[ID].11-(LPC7_4+8))
-LPC7_4:
+	movt	r0, :upper16:(l_anon.[ID].11-(LPC8_4+8))
+LPC8_4:
 	add	r0, pc, r0
 	bl	SYM(objc2::runtime::AnyClass::get::GENERATED_ID, 0)
 	cmp	r0, #0
 	movne	sp, r7
 	popne	{r7, pc}
-LBB7_2:
-	movw	r0, :lower16:(l_anon.[ID].2-(LPC7_5+8))
+LBB8_2:
+	movw	r0, :lower16:(l_anon.[ID].2-(LPC8_5+8))
 	mov	r1, #43
-	movt	r0, :upper16:(l_anon.[ID].2-(LPC7_5+8))
-	movw	r2, :lower16:(l_anon.[ID].10-(LPC7_6+8))
-	movt	r2, :upper16:(l_anon.[ID].10-(LPC7_6+8))
-LPC7_5:
+	movt	r0, :upper16:(l_anon.[ID].2-(LPC8_5+8))
+	movw	r2, :lower16:(l_anon.[ID].10-(LPC8_6+8))
+	movt	r2, :upper16:(l_anon.[ID].10-(LPC8_6+8))
+LPC8_5:
 	add	r0, pc, r0
-LPC7_6:
+LPC8_6:
 	add	r2, pc, r2
 	mov	lr, pc
 	b	SYM(core::panicking::panic::GENERATED_ID, 0)
-LBB7_3:
-	movw	r0, :lower16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC7_1+8))
+LBB8_3:
+	movw	r0, :lower16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC8_1+8))
 	mov	r2, #1
-	movt	r0, :upper16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC7_1+8))
-	movw	r3, :lower16:(l_anon.[ID].0-(LPC7_2+8))
-	movt	r3, :upper16:(l_anon.[ID].0-(LPC7_2+8))
-	movw	r1, :lower16:(l_anon.[ID].10-(LPC7_3+8))
-	movt	r1, :upper16:(l_anon.[ID].10-(LPC7_3+8))
+	movt	r0, :upper16:(SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-(LPC8_1+8))
+	movw	r3, :lower16:(l_anon.[ID].0-(LPC8_2+8))
+	movt	r3, :upper16:(l_anon.[ID].0-(LPC8_2+8))
+	movw	r1, :lower16:(l_anon.[ID].10-(LPC8_3+8))
+	movt	r1, :upper16:(l_anon.[ID].10-(LPC8_3+8))
 	strb	r2, [r7, #-5]
-LPC7_3:
+LPC8_3:
 	add	r1, pc, r1
 	sub	r2, r7, #5
 	str	r2, [r7, #-4]
-LPC7_1:
+LPC8_1:
 	add	r0, pc, r0
 	str	r1, [sp]
-LPC7_2:
+LPC8_2:
 	add	r3, pc, r3
 	sub	r2, r7, #4
 	mov	r1, #0
 	bl	SYM(std::sys_common::once::queue::Once::call::GENERATED_ID, 0)
-	b	LBB7_1
+	b	LBB8_1
 
 	.p2align	2
 	.code	32
@@ -447,26 +483,26 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	mov	r4, r1
 	mov	r5, r0
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].7-(LPC8_0+8))
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC9_0+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].7-(LPC8_0+8))
-	movw	r3, :lower16:(l_anon.[ID].8-(LPC8_1+8))
-	movt	r3, :upper16:(l_anon.[ID].8-(LPC8_1+8))
-LPC8_0:
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC9_0+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC9_1+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC9_1+8))
+LPC9_0:
 	add	r1, pc, r1
-LPC8_1:
+LPC9_1:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	r0, [r5, r0]
 	cmp	r0, #0
-	beq	LBB8_2
+	beq	LBB9_2
 	bl	_objc_release
-LBB8_2:
-	movw	r0, :lower16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC8_2+8))
+LBB9_2:
+	movw	r0, :lower16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC9_2+8))
 	mov	r1, r4
-	movt	r0, :upper16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC8_2+8))
+	movt	r0, :upper16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC9_2+8))
 	str	r5, [sp]
-LPC8_2:
+LPC9_2:
 	ldr	r0, [pc, r0]
 	ldr	r0, [r0]
 	str	r0, [sp, #4]
@@ -482,13 +518,13 @@ _init:
 	push	{r4, r7, lr}
 	add	r7, sp, #4
 	sub	sp, sp, #8
-	movw	r1, :lower16:(LL_OBJC_SELECTOR_REFERENCES_init$non_lazy_ptr-(LPC9_0+8))
-	movt	r1, :upper16:(LL_OBJC_SELECTOR_REFERENCES_init$non_lazy_ptr-(LPC9_0+8))
-	movw	r2, :lower16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC9_1+8))
-LPC9_0:
+	movw	r1, :lower16:(LL_OBJC_SELECTOR_REFERENCES_init$non_lazy_ptr-(LPC10_0+8))
+	movt	r1, :upper16:(LL_OBJC_SELECTOR_REFERENCES_init$non_lazy_ptr-(LPC10_0+8))
+	movw	r2, :lower16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC10_1+8))
+LPC10_0:
 	ldr	r1, [pc, r1]
-	movt	r2, :upper16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC9_1+8))
-LPC9_1:
+	movt	r2, :upper16:(LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-(LPC10_1+8))
+LPC10_1:
 	ldr	r2, [pc, r2]
 	ldr	r1, [r1]
 	ldr	r2, [r2]
@@ -497,36 +533,36 @@ LPC9_1:
 	bl	_objc_msgSendSuper
 	mov	r4, r0
 	cmp	r0, #0
-	beq	LBB9_2
+	beq	LBB10_2
 	mov	r0, r4
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].5-(LPC9_2+8))
+	movw	r1, :lower16:(L_anon.[ID].5-(LPC10_2+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].5-(LPC9_2+8))
-	movw	r3, :lower16:(l_anon.[ID].6-(LPC9_3+8))
-	movt	r3, :upper16:(l_anon.[ID].6-(LPC9_3+8))
-LPC9_2:
+	movt	r1, :upper16:(L_anon.[ID].5-(LPC10_2+8))
+	movw	r3, :lower16:(l_anon.[ID].6-(LPC10_3+8))
+	movt	r3, :upper16:(l_anon.[ID].6-(LPC10_3+8))
+LPC10_2:
 	add	r1, pc, r1
-LPC9_3:
+LPC10_3:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	r1, #42
 	strb	r1, [r4, r0]
 	mov	r0, r4
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].7-(LPC9_4+8))
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC10_4+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].7-(LPC9_4+8))
-	movw	r3, :lower16:(l_anon.[ID].8-(LPC9_5+8))
-	movt	r3, :upper16:(l_anon.[ID].8-(LPC9_5+8))
-LPC9_4:
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC10_4+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC10_5+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC10_5+8))
+LPC10_4:
 	add	r1, pc, r1
-LPC9_5:
+LPC10_5:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	r1, #0
 	str	r1, [r4, r0]
-LBB9_2:
+LBB10_2:
 	mov	r0, r4
 	sub	sp, r7, #4
 	pop	{r4, r7, pc}
@@ -559,23 +595,23 @@ _method_id:
 	add	r7, sp, #4
 	mov	r4, r0
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].7-(LPC13_0+8))
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC14_0+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].7-(LPC13_0+8))
-	movw	r3, :lower16:(l_anon.[ID].8-(LPC13_1+8))
-	movt	r3, :upper16:(l_anon.[ID].8-(LPC13_1+8))
-LPC13_0:
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC14_0+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC14_1+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC14_1+8))
+LPC14_0:
 	add	r1, pc, r1
-LPC13_1:
+LPC14_1:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	r0, [r4, r0]
 	cmp	r0, #0
-	beq	LBB13_2
+	beq	LBB14_2
 	bl	_objc_retain
 	bl	_objc_autoreleaseReturnValue
 	pop	{r4, r7, pc}
-LBB13_2:
+LBB14_2:
 	mov	r0, #0
 	bl	_objc_autoreleaseReturnValue
 	pop	{r4, r7, pc}
@@ -591,33 +627,33 @@ _method_id_with_param:
 	bl	SYM(objc2::runtime::nsobject::NSObject::new::GENERATED_ID, 0)
 	mov	r4, r0
 	cmp	r6, #0
-	beq	LBB14_3
+	beq	LBB15_3
 	mov	r0, r5
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].7-(LPC14_0+8))
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC15_0+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].7-(LPC14_0+8))
-	movw	r3, :lower16:(l_anon.[ID].8-(LPC14_1+8))
-	movt	r3, :upper16:(l_anon.[ID].8-(LPC14_1+8))
-LPC14_0:
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC15_0+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC15_1+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC15_1+8))
+LPC15_0:
 	add	r1, pc, r1
-LPC14_1:
+LPC15_1:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	r0, [r5, r0]
 	cmp	r0, #0
-	beq	LBB14_4
+	beq	LBB15_4
 	bl	_objc_retain
 	mov	r5, r0
-	b	LBB14_5
-LBB14_3:
+	b	LBB15_5
+LBB15_3:
 	mov	r5, r4
 	mov	r0, r5
 	bl	_objc_autoreleaseReturnValue
 	pop	{r4, r5, r6, r7, pc}
-LBB14_4:
+LBB15_4:
 	mov	r5, #0
-LBB14_5:
+LBB15_5:
 	mov	r0, r4
 	bl	_objc_release
 	mov	r0, r5
@@ -635,17 +671,17 @@ _copy_with_zone:
 	bl	_get_obj
 	mov	r4, r0
 	cmp	r0, #0
-	beq	LBB15_5
+	beq	LBB16_5
 	mov	r0, r5
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r10, :lower16:(L_anon.[ID].5-(LPC15_0+8))
+	movw	r10, :lower16:(L_anon.[ID].5-(LPC16_0+8))
 	mov	r2, #4
-	movt	r10, :upper16:(L_anon.[ID].5-(LPC15_0+8))
-	movw	r8, :lower16:(l_anon.[ID].6-(LPC15_1+8))
-	movt	r8, :upper16:(l_anon.[ID].6-(LPC15_1+8))
-LPC15_0:
+	movt	r10, :upper16:(L_anon.[ID].5-(LPC16_0+8))
+	movw	r8, :lower16:(l_anon.[ID].6-(LPC16_1+8))
+	movt	r8, :upper16:(l_anon.[ID].6-(LPC16_1+8))
+LPC16_0:
 	add	r10, pc, r10
-LPC15_1:
+LPC16_1:
 	add	r8, pc, r8
 	mov	r1, r10
 	mov	r3, r8
@@ -660,39 +696,39 @@ LPC15_1:
 	strb	r6, [r4, r0]
 	mov	r0, r5
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].7-(LPC15_2+8))
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC16_2+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].7-(LPC15_2+8))
-	movw	r3, :lower16:(l_anon.[ID].8-(LPC15_3+8))
-	movt	r3, :upper16:(l_anon.[ID].8-(LPC15_3+8))
-LPC15_2:
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC16_2+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC16_3+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC16_3+8))
+LPC16_2:
 	add	r1, pc, r1
-LPC15_3:
+LPC16_3:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	ldr	r0, [r5, r0]
 	cmp	r0, #0
-	beq	LBB15_3
+	beq	LBB16_3
 	bl	_objc_retain
 	mov	r5, r0
-	b	LBB15_4
-LBB15_3:
+	b	LBB16_4
+LBB16_3:
 	mov	r5, #0
-LBB15_4:
+LBB16_4:
 	mov	r0, r4
 	bl	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	movw	r1, :lower16:(L_anon.[ID].7-(LPC15_4+8))
+	movw	r1, :lower16:(L_anon.[ID].7-(LPC16_4+8))
 	mov	r2, #4
-	movt	r1, :upper16:(L_anon.[ID].7-(LPC15_4+8))
-	movw	r3, :lower16:(l_anon.[ID].8-(LPC15_5+8))
-	movt	r3, :upper16:(l_anon.[ID].8-(LPC15_5+8))
-LPC15_4:
+	movt	r1, :upper16:(L_anon.[ID].7-(LPC16_4+8))
+	movw	r3, :lower16:(l_anon.[ID].8-(LPC16_5+8))
+	movt	r3, :upper16:(l_anon.[ID].8-(LPC16_5+8))
+LPC16_4:
 	add	r1, pc, r1
-LPC15_5:
+LPC16_5:
 	add	r3, pc, r3
 	bl	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	str	r5, [r4, r0]
-LBB15_5:
+LBB16_5:
 	mov	r0, r4
 	pop	{r8, r10}
 	pop	{r4, r5, r6, r7, pc}
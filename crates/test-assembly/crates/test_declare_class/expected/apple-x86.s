@@ -346,6 +346,54 @@ L6$pb:
 	pop	ebp
 	ret
 
+	.globl	SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0)
+	.p2align	4, 0x90
+SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0):
+	push	ebp
+	mov	ebp, esp
+	push	ebx
+	push	edi
+	push	esi
+	sub	esp, 12
+	call	L7$pb
+L7$pb:
+	pop	ebx
+	mov	esi, dword ptr [ebp + 12]
+	mov	edi, dword ptr [ebp + 8]
+	sub	esp, 12
+	push	edi
+	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+	add	esp, 16
+	lea	ecx, [ebx + l_anon.[ID].6-L7$pb]
+	lea	edx, [ebx + L_anon.[ID].5-L7$pb]
+	push	ecx
+	push	4
+	push	edx
+	push	eax
+	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	add	esp, 16
+	movzx	ecx, byte ptr [ebp + 16]
+	mov	byte ptr [edi + eax], cl
+	sub	esp, 12
+	push	edi
+	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+	add	esp, 16
+	lea	ecx, [ebx + l_anon.[ID].8-L7$pb]
+	lea	edx, [ebx + L_anon.[ID].7-L7$pb]
+	push	ecx
+	push	4
+	push	edx
+	push	eax
+	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	add	esp, 16
+	mov	dword ptr [edi + eax], esi
+	add	esp, 12
+	pop	esi
+	pop	edi
+	pop	ebx
+	pop	ebp
+	ret
+
 	.globl	SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0)
 	.p2align	4, 0x90
 SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0):
@@ -354,35 +402,35 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	push	edi
 	push	esi
 	sub	esp, 16
-	call	L7$pb
-L7$pb:
+	call	L8$pb
+L8$pb:
 	pop	esi
-	mov	eax, dword ptr [esi + SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-L7$pb]
+	mov	eax, dword ptr [esi + SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-L8$pb]
 	cmp	eax, 3
-	jne	LBB7_1
-LBB7_2:
+	jne	LBB8_1
+LBB8_2:
 	sub	esp, 8
-	lea	eax, [esi + l_anon.[ID].11-L7$pb]
+	lea	eax, [esi + l_anon.[ID].11-L8$pb]
 	push	15
 	push	eax
 	call	SYM(objc2::runtime::AnyClass::get::GENERATED_ID, 0)
 	add	esp, 16
 	test	eax, eax
-	je	LBB7_4
+	je	LBB8_4
 	add	esp, 16
 	pop	esi
 	pop	edi
 	pop	ebp
 	ret
-LBB7_1:
+LBB8_1:
 	mov	byte ptr [ebp - 9], 1
 	lea	eax, [ebp - 9]
 	mov	dword ptr [ebp - 16], eax
 	sub	esp, 12
-	lea	eax, [esi + l_anon.[ID].10-L7$pb]
-	lea	ecx, [esi + l_anon.[ID].0-L7$pb]
+	lea	eax, [esi + l_anon.[ID].10-L8$pb]
+	lea	ecx, [esi + l_anon.[ID].0-L8$pb]
 	lea	edx, [ebp - 16]
-	lea	edi, [esi + SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-L7$pb]
+	lea	edi, [esi + SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)-L8$pb]
 	push	eax
 	push	ecx
 	push	edx
@@ -390,11 +438,11 @@ LBB7_1:
 	push	edi
 	call	SYM(std::sys_common::once::queue::Once::call::GENERATED_ID, 0)
 	add	esp, 32
-	jmp	LBB7_2
-LBB7_4:
+	jmp	LBB8_2
+LBB8_4:
 	sub	esp, 4
-	lea	eax, [esi + l_anon.[ID].10-L7$pb]
-	lea	ecx, [esi + l_anon.[ID].2-L7$pb]
+	lea	eax, [esi + l_anon.[ID].10-L8$pb]
+	lea	ecx, [esi + l_anon.[ID].2-L8$pb]
 	push	eax
 	push	43
 	push	ecx
@@ -408,8 +456,8 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	push	edi
 	push	esi
 	sub	esp, 12
-	call	L8$pb
-L8$pb:
+	call	L9$pb
+L9$pb:
 	pop	ebx
 	mov	esi, dword ptr [ebp + 12]
 	mov	edi, dword ptr [ebp + 8]
@@ -417,8 +465,8 @@ L8$pb:
 	push	edi
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	ecx, [ebx + l_anon.[ID].8-L8$pb]
-	lea	edx, [ebx + L_anon.[ID].7-L8$pb]
+	lea	ecx, [ebx + l_anon.[ID].8-L9$pb]
+	lea	edx, [ebx + L_anon.[ID].7-L9$pb]
 	push	ecx
 	push	4
 	push	edx
@@ -427,13 +475,13 @@ L8$pb:
 	add	esp, 16
 	mov	eax, dword ptr [edi + eax]
 	test	eax, eax
-	je	LBB8_2
+	je	LBB9_2
 	sub	esp, 12
 	push	eax
 	call	_objc_release
 	add	esp, 16
-LBB8_2:
-	mov	eax, dword ptr [ebx + LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-L8$pb]
+LBB9_2:
+	mov	eax, dword ptr [ebx + LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-L9$pb]
 	mov	eax, dword ptr [eax]
 	mov	dword ptr [ebp - 24], edi
 	mov	dword ptr [ebp - 20], eax
@@ -457,13 +505,13 @@ _init:
 	push	edi
 	push	esi
 	sub	esp, 16
-	call	L9$pb
-L9$pb:
+	call	L10$pb
+L10$pb:
 	pop	edi
 	mov	eax, dword ptr [ebp + 8]
-	mov	ecx, dword ptr [edi + LL_OBJC_SELECTOR_REFERENCES_init$non_lazy_ptr-L9$pb]
+	mov	ecx, dword ptr [edi + LL_OBJC_SELECTOR_REFERENCES_init$non_lazy_ptr-L10$pb]
 	mov	ecx, dword ptr [ecx]
-	mov	edx, dword ptr [edi + LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-L9$pb]
+	mov	edx, dword ptr [edi + LL_OBJC_CLASSLIST_REFERENCES_$_NSObject$non_lazy_ptr-L10$pb]
 	mov	edx, dword ptr [edx]
 	mov	dword ptr [ebp - 16], eax
 	mov	dword ptr [ebp - 12], edx
@@ -475,13 +523,13 @@ L9$pb:
 	add	esp, 16
 	mov	esi, eax
 	test	eax, eax
-	je	LBB9_2
+	je	LBB10_2
 	sub	esp, 12
 	push	esi
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	ecx, [edi + l_anon.[ID].6-L9$pb]
-	lea	edx, [edi + L_anon.[ID].5-L9$pb]
+	lea	ecx, [edi + l_anon.[ID].6-L10$pb]
+	lea	edx, [edi + L_anon.[ID].5-L10$pb]
 	push	ecx
 	push	4
 	push	edx
@@ -493,8 +541,8 @@ L9$pb:
 	push	esi
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	ecx, [edi + l_anon.[ID].8-L9$pb]
-	lea	edx, [edi + L_anon.[ID].7-L9$pb]
+	lea	ecx, [edi + l_anon.[ID].8-L10$pb]
+	lea	edx, [edi + L_anon.[ID].7-L10$pb]
 	push	ecx
 	push	4
 	push	edx
@@ -502,7 +550,7 @@ L9$pb:
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	add	esp, 16
 	mov	dword ptr [esi + eax], 0
-LBB9_2:
+LBB10_2:
 	mov	eax, esi
 	add	esp, 16
 	pop	esi
@@ -544,16 +592,16 @@ _method_id:
 	mov	ebp, esp
 	push	edi
 	push	esi
-	call	L13$pb
-L13$pb:
+	call	L14$pb
+L14$pb:
 	pop	edi
 	mov	esi, dword ptr [ebp + 8]
 	sub	esp, 12
 	push	esi
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	ecx, [edi + l_anon.[ID].8-L13$pb]
-	lea	edx, [edi + L_anon.[ID].7-L13$pb]
+	lea	ecx, [edi + l_anon.[ID].8-L14$pb]
+	lea	edx, [edi + L_anon.[ID].7-L14$pb]
 	push	ecx
 	push	4
 	push	edx
@@ -562,15 +610,15 @@ L13$pb:
 	add	esp, 16
 	mov	eax, dword ptr [esi + eax]
 	test	eax, eax
-	je	LBB13_1
+	je	LBB14_1
 	sub	esp, 12
 	push	eax
 	call	_objc_retain
 	add	esp, 16
-	jmp	LBB13_3
-LBB13_1:
+	jmp	LBB14_3
+LBB14_1:
 	xor	eax, eax
-LBB13_3:
+LBB14_3:
 	sub	esp, 12
 	push	eax
 	call	_objc_autoreleaseReturnValue
@@ -589,37 +637,37 @@ _method_id_with_param:
 	push	edi
 	push	esi
 	sub	esp, 28
-	call	L14$pb
-L14$pb:
+	call	L15$pb
+L15$pb:
 	pop	edi
 	call	SYM(objc2::runtime::nsobject::NSObject::new::GENERATED_ID, 0)
 	mov	esi, eax
 	cmp	byte ptr [ebp + 16], 0
-	je	LBB14_5
+	je	LBB15_5
 	mov	ebx, dword ptr [ebp + 8]
 	mov	dword ptr [esp], ebx
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
-	lea	ecx, [edi + l_anon.[ID].8-L14$pb]
+	lea	ecx, [edi + l_anon.[ID].8-L15$pb]
 	mov	dword ptr [esp + 12], ecx
-	lea	ecx, [edi + L_anon.[ID].7-L14$pb]
+	lea	ecx, [edi + L_anon.[ID].7-L15$pb]
 	mov	dword ptr [esp + 4], ecx
 	mov	dword ptr [esp], eax
 	mov	dword ptr [esp + 8], 4
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	eax, dword ptr [ebx + eax]
 	test	eax, eax
-	je	LBB14_2
+	je	LBB15_2
 	mov	dword ptr [esp], eax
 	call	_objc_retain
 	mov	edi, eax
-	jmp	LBB14_4
-LBB14_2:
+	jmp	LBB15_4
+LBB15_2:
 	xor	edi, edi
-LBB14_4:
+LBB15_4:
 	mov	dword ptr [esp], esi
 	call	_objc_release
 	mov	esi, edi
-LBB14_5:
+LBB15_5:
 	mov	dword ptr [esp], esi
 	call	_objc_autoreleaseReturnValue
 	add	esp, 28
@@ -638,21 +686,21 @@ _copy_with_zone:
 	push	edi
 	push	esi
 	sub	esp, 12
-	call	L15$pb
-L15$pb:
+	call	L16$pb
+L16$pb:
 	pop	edi
 	call	_get_obj
 	mov	esi, eax
 	test	eax, eax
-	je	LBB15_5
+	je	LBB16_5
 	mov	eax, dword ptr [ebp + 8]
 	sub	esp, 12
 	push	eax
 	mov	ebx, eax
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	edx, [edi + l_anon.[ID].6-L15$pb]
-	lea	ecx, [edi + L_anon.[ID].5-L15$pb]
+	lea	edx, [edi + l_anon.[ID].6-L16$pb]
+	lea	ecx, [edi + L_anon.[ID].5-L16$pb]
 	push	edx
 	push	4
 	push	ecx
@@ -664,10 +712,10 @@ L15$pb:
 	push	esi
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	ecx, [edi + l_anon.[ID].6-L15$pb]
+	lea	ecx, [edi + l_anon.[ID].6-L16$pb]
 	push	ecx
 	push	4
-	lea	ecx, [edi + L_anon.[ID].5-L15$pb]
+	lea	ecx, [edi + L_anon.[ID].5-L16$pb]
 	push	ecx
 	push	eax
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
@@ -677,8 +725,8 @@ L15$pb:
 	push	dword ptr [ebp + 8]
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	add	esp, 16
-	lea	ecx, [edi + l_anon.[ID].8-L15$pb]
-	lea	ebx, [edi + L_anon.[ID].7-L15$pb]
+	lea	ecx, [edi + l_anon.[ID].8-L16$pb]
+	lea	ebx, [edi + L_anon.[ID].7-L16$pb]
 	mov	dword ptr [ebp - 16], ecx
 	push	ecx
 	push	4
@@ -689,16 +737,16 @@ L15$pb:
 	mov	ecx, dword ptr [ebp + 8]
 	mov	eax, dword ptr [ecx + eax]
 	test	eax, eax
-	je	LBB15_2
+	je	LBB16_2
 	sub	esp, 12
 	push	eax
 	call	_objc_retain
 	add	esp, 16
 	mov	edi, eax
-	jmp	LBB15_4
-LBB15_2:
+	jmp	LBB16_4
+LBB16_2:
 	xor	edi, edi
-LBB15_4:
+LBB16_4:
 	sub	esp, 12
 	push	esi
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
@@ -710,7 +758,7 @@ LBB15_4:
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	add	esp, 16
 	mov	dword ptr [esi + eax], edi
-LBB15_5:
+LBB16_5:
 	mov	eax, esi
 	add	esp, 12
 	pop	esi
@@ -255,6 +255,40 @@ _access_ivars:
 	pop	rbp
 	ret
 
+	.globl	SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0)
+	.p2align	4, 0x90
+SYM(test_declare_class[CRATE_ID]::ivars::__objc2_write_ivars, 0):
+	push	rbp
+	mov	rbp, rsp
+	push	r15
+	push	r14
+	push	rbx
+	push	rax
+	mov	r15d, edx
+	mov	rbx, rsi
+	mov	r14, rdi
+	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+	lea	rsi, [rip + L_anon.[ID].5]
+	lea	rcx, [rip + l_anon.[ID].6]
+	mov	edx, 4
+	mov	rdi, rax
+	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	mov	byte ptr [r14 + rax], r15b
+	mov	rdi, r14
+	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
+	lea	rsi, [rip + L_anon.[ID].7]
+	lea	rcx, [rip + l_anon.[ID].8]
+	mov	edx, 4
+	mov	rdi, rax
+	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
+	mov	qword ptr [r14 + rax], rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	pop	r15
+	pop	rbp
+	ret
+
 	.globl	SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0)
 	.p2align	4, 0x90
 SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class, 0):
@@ -263,17 +297,17 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	sub	rsp, 16
 	mov	rax, qword ptr [rip + SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassType>::class::REGISTER_CLASS, 0)]
 	cmp	rax, 3
-	jne	LBB7_1
-LBB7_2:
+	jne	LBB8_1
+LBB8_2:
 	lea	rdi, [rip + l_anon.[ID].11]
 	mov	esi, 15
 	call	SYM(objc2::runtime::AnyClass::get::GENERATED_ID, 0)
 	test	rax, rax
-	je	LBB7_4
+	je	LBB8_4
 	add	rsp, 16
 	pop	rbp
 	ret
-LBB7_1:
+LBB8_1:
 	mov	byte ptr [rbp - 1], 1
 	lea	rax, [rbp - 1]
 	mov	qword ptr [rbp - 16], rax
@@ -283,8 +317,8 @@ LBB7_1:
 	lea	rdx, [rbp - 16]
 	xor	esi, esi
 	call	SYM(std::sys_common::once::queue::Once::call::GENERATED_ID, 0)
-	jmp	LBB7_2
-LBB7_4:
+	jmp	LBB8_2
+LBB8_4:
 	lea	rdi, [rip + l_anon.[ID].2]
 	lea	rdx, [rip + l_anon.[ID].10]
 	mov	esi, 43
@@ -307,9 +341,9 @@ SYM(<test_declare_class[CRATE_ID]::Custom as objc2[CRATE_ID]::class_type::ClassT
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	rdi, qword ptr [r14 + rax]
 	test	rdi, rdi
-	je	LBB8_2
+	je	LBB9_2
 	call	_objc_release
-LBB8_2:
+LBB9_2:
 	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_NSObject@GOTPCREL]
 	mov	rax, qword ptr [rax]
 	mov	qword ptr [rbp - 32], r14
@@ -340,7 +374,7 @@ _init:
 	call	_objc_msgSendSuper
 	mov	rbx, rax
 	test	rax, rax
-	je	LBB9_2
+	je	LBB10_2
 	mov	rdi, rbx
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	lea	rsi, [rip + L_anon.[ID].5]
@@ -357,7 +391,7 @@ _init:
 	mov	rdi, rax
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	qword ptr [rbx + rax], 0
-LBB9_2:
+LBB10_2:
 	mov	rax, rbx
 	add	rsp, 24
 	pop	rbx
@@ -407,14 +441,14 @@ _method_id:
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	rdi, qword ptr [rbx + rax]
 	test	rdi, rdi
-	je	LBB13_1
+	je	LBB14_1
 	call	_objc_retain
 	mov	rdi, rax
 	add	rsp, 8
 	pop	rbx
 	pop	rbp
 	jmp	_objc_autoreleaseReturnValue
-LBB13_1:
+LBB14_1:
 	xor	edi, edi
 	add	rsp, 8
 	pop	rbx
@@ -435,7 +469,7 @@ _method_id_with_param:
 	call	SYM(objc2::runtime::nsobject::NSObject::new::GENERATED_ID, 0)
 	mov	rbx, rax
 	test	r15b, r15b
-	je	LBB14_5
+	je	LBB15_5
 	mov	rdi, r14
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	lea	rsi, [rip + L_anon.[ID].7]
@@ -445,17 +479,17 @@ _method_id_with_param:
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	rdi, qword ptr [r14 + rax]
 	test	rdi, rdi
-	je	LBB14_2
+	je	LBB15_2
 	call	_objc_retain
 	mov	r14, rax
-	jmp	LBB14_4
-LBB14_2:
+	jmp	LBB15_4
+LBB15_2:
 	xor	r14d, r14d
-LBB14_4:
+LBB15_4:
 	mov	rdi, rbx
 	call	_objc_release
 	mov	rbx, r14
-LBB14_5:
+LBB15_5:
 	mov	rdi, rbx
 	add	rsp, 8
 	pop	rbx
@@ -479,7 +513,7 @@ _copy_with_zone:
 	call	_get_obj
 	mov	rbx, rax
 	test	rax, rax
-	je	LBB15_5
+	je	LBB16_5
 	mov	rdi, r14
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	lea	r15, [rip + L_anon.[ID].5]
@@ -507,13 +541,13 @@ _copy_with_zone:
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	rdi, qword ptr [r14 + rax]
 	test	rdi, rdi
-	je	LBB15_2
+	je	LBB16_2
 	call	_objc_retain
 	mov	r14, rax
-	jmp	LBB15_4
-LBB15_2:
+	jmp	LBB16_4
+LBB16_2:
 	xor	r14d, r14d
-LBB15_4:
+LBB16_4:
 	mov	rdi, rbx
 	call	SYM(objc2::runtime::AnyObject::class::GENERATED_ID, 0)
 	lea	rsi, [rip + L_anon.[ID].7]
@@ -522,7 +556,7 @@ LBB15_4:
 	mov	rdi, rax
 	call	SYM(objc2::runtime::ivar_offset::GENERATED_ID, 0)
 	mov	qword ptr [rbx + rax], r14
-LBB15_5:
+LBB16_5:
 	mov	rax, rbx
 	add	rsp, 8
 	pop	rbx
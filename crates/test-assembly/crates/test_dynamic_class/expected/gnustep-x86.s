@@ -1,3 +1,470 @@
 	.text
 	.intel_syntax noprefix
+	.section	.text.get_class,"ax",@progbits
+	.globl	get_class
+	.p2align	4, 0x90
+	.type	get_class,@function
+get_class:
+	push	ebx
+	sub	esp, 8
+	call	.L0$pb
+.L0$pb:
+	pop	ebx
+.Ltmp0:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp0-.L0$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB0_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB0_1:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].2@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end0:
+	.size	get_class, .Lfunc_end0-get_class
+
+	.section	.text.get_same_class,"ax",@progbits
+	.globl	get_same_class
+	.p2align	4, 0x90
+	.type	get_same_class,@function
+get_same_class:
+	push	ebx
+	sub	esp, 8
+	call	.L1$pb
+.L1$pb:
+	pop	ebx
+.Ltmp1:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp1-.L1$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB1_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB1_1:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].3@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end1:
+	.size	get_same_class, .Lfunc_end1-get_same_class
+
+	.section	.text.get_different_class,"ax",@progbits
+	.globl	get_different_class
+	.p2align	4, 0x90
+	.type	get_different_class,@function
+get_different_class:
+	push	ebx
+	sub	esp, 8
+	call	.L2$pb
+.L2$pb:
+	pop	ebx
+.Ltmp2:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp2-.L2$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB2_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB2_1:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].5@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].4@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end2:
+	.size	get_different_class, .Lfunc_end2-get_different_class
+
+	.section	.text.unused_class,"ax",@progbits
+	.globl	unused_class
+	.p2align	4, 0x90
+	.type	unused_class,@function
+unused_class:
+	push	ebx
+	sub	esp, 8
+	call	.L3$pb
+.L3$pb:
+	pop	ebx
+.Ltmp3:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp3-.L3$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB3_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB3_1:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].7@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].6@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end3:
+	.size	unused_class, .Lfunc_end3-unused_class
+
+	.section	.text.use_fns,"ax",@progbits
+	.globl	use_fns
+	.p2align	4, 0x90
+	.type	use_fns,@function
+use_fns:
+	push	ebp
+	push	ebx
+	push	edi
+	push	esi
+	sub	esp, 12
+	call	.L4$pb
+.L4$pb:
+	pop	ebx
+.Ltmp4:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp4-.L4$pb)
+	mov	ecx, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	test	ecx, ecx
+	je	.LBB4_1
+	mov	edi, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)@GOTOFF]
+	test	edi, edi
+	je	.LBB4_3
+.LBB4_4:
+	mov	esi, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)@GOTOFF]
+	mov	ebp, dword ptr [esp + 32]
+	test	esi, esi
+	je	.LBB4_5
+.LBB4_6:
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB4_7
+.LBB4_8:
+	mov	dword ptr [ebp], ecx
+	mov	dword ptr [ebp + 4], edi
+	mov	dword ptr [ebp + 8], esi
+	mov	dword ptr [ebp + 12], eax
+	mov	eax, ebp
+	add	esp, 12
+	pop	esi
+	pop	edi
+	pop	ebx
+	pop	ebp
+	ret	4
+.LBB4_1:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].2@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	mov	ecx, eax
+	mov	edi, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)@GOTOFF]
+	test	edi, edi
+	jne	.LBB4_4
+.LBB4_3:
+	sub	esp, 4
+	mov	esi, ecx
+	lea	eax, [ebx + .Lanon.[ID].3@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	mov	ecx, esi
+	add	esp, 16
+	mov	edi, eax
+	mov	esi, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)@GOTOFF]
+	mov	ebp, dword ptr [esp + 32]
+	test	esi, esi
+	jne	.LBB4_6
+.LBB4_5:
+	sub	esp, 4
+	mov	esi, ecx
+	lea	eax, [ebx + .Lanon.[ID].5@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].4@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	mov	ecx, esi
+	add	esp, 16
+	mov	esi, eax
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	jne	.LBB4_8
+.LBB4_7:
+	sub	esp, 4
+	mov	dword ptr [esp + 12], ecx
+	lea	eax, [ebx + .Lanon.[ID].9@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].8@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	mov	ecx, dword ptr [esp + 24]
+	add	esp, 16
+	jmp	.LBB4_8
+.Lfunc_end4:
+	.size	use_fns, .Lfunc_end4-use_fns
+
+	.section	.text.use_same_twice,"ax",@progbits
+	.globl	use_same_twice
+	.p2align	4, 0x90
+	.type	use_same_twice,@function
+use_same_twice:
+	push	ebx
+	push	edi
+	push	esi
+	call	.L5$pb
+.L5$pb:
+	pop	ebx
+	mov	esi, dword ptr [esp + 16]
+.Ltmp5:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp5-.L5$pb)
+	mov	edi, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	test	edi, edi
+	je	.LBB5_1
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB5_3
+.LBB5_4:
+	mov	dword ptr [esi], edi
+	mov	dword ptr [esi + 4], eax
+	mov	eax, esi
+	pop	esi
+	pop	edi
+	pop	ebx
+	ret	4
+.LBB5_1:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].2@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	mov	edi, eax
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	jne	.LBB5_4
+.LBB5_3:
+	sub	esp, 4
+	lea	eax, [ebx + .Lanon.[ID].2@GOTOFF]
+	lea	ecx, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	edx, [ebx + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	push	edx
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	jmp	.LBB5_4
+.Lfunc_end5:
+	.size	use_same_twice, .Lfunc_end5-use_same_twice
+
+	.section	.text.use_in_loop,"ax",@progbits
+	.globl	use_in_loop
+	.p2align	4, 0x90
+	.type	use_in_loop,@function
+use_in_loop:
+	push	ebp
+	push	ebx
+	push	edi
+	push	esi
+	sub	esp, 12
+	mov	esi, dword ptr [esp + 32]
+	call	.L6$pb
+.L6$pb:
+	pop	ebx
+.Ltmp6:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp6-.L6$pb)
+	test	esi, esi
+	je	.LBB6_5
+	lea	eax, [ebx + .Lanon.[ID].11@GOTOFF]
+	lea	ebp, [ebx + .Lanon.[ID].10@GOTOFF]
+	lea	edi, [ebx + SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0)@GOTOFF]
+	mov	dword ptr [esp + 8], eax
+	jmp	.LBB6_2
+	.p2align	4, 0x90
+.LBB6_4:
+	dec	esi
+	je	.LBB6_5
+.LBB6_2:
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0)@GOTOFF]
+	test	eax, eax
+	jne	.LBB6_4
+	sub	esp, 4
+	push	dword ptr [esp + 12]
+	push	ebp
+	push	edi
+	call	SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	jmp	.LBB6_4
+.LBB6_5:
+	add	esp, 12
+	pop	esi
+	pop	edi
+	pop	ebx
+	pop	ebp
+	ret
+.Lfunc_end6:
+	.size	use_in_loop, .Lfunc_end6-use_in_loop
+
+	.type	.Lanon.[ID].0,@object
+	.section	.rodata..Lanon.[ID].0,"a",@progbits
+.Lanon.[ID].0:
+	.asciz	"NSObject"
+	.size	.Lanon.[ID].0, 9
+
+	.type	.Lanon.[ID].1,@object
+	.section	.rodata..Lanon.[ID].1,"a",@progbits
+.Lanon.[ID].1:
+	.ascii	"crates/$DIR/../test_static_class/lib.rs"
+	.size	.Lanon.[ID].1, 74
+
+	.type	.Lanon.[ID].2,@object
+	.section	.data.rel.ro..Lanon.[ID].2,"aw",@progbits
+	.p2align	2, 0x0
+.Lanon.[ID].2:
+	.long	.Lanon.[ID].1
+	.asciz	"J\000\000\000\b\000\000\000\005\000\000"
+	.size	.Lanon.[ID].2, 16
+
+	.type	.Lanon.[ID].3,@object
+	.section	.data.rel.ro..Lanon.[ID].3,"aw",@progbits
+	.p2align	2, 0x0
+.Lanon.[ID].3:
+	.long	.Lanon.[ID].1
+	.asciz	"J\000\000\000\r\000\000\000\005\000\000"
+	.size	.Lanon.[ID].3, 16
+
+	.type	.Lanon.[ID].4,@object
+	.section	.rodata..Lanon.[ID].4,"a",@progbits
+.Lanon.[ID].4:
+	.asciz	"NSString"
+	.size	.Lanon.[ID].4, 9
+
+	.type	.Lanon.[ID].5,@object
+	.section	.data.rel.ro..Lanon.[ID].5,"aw",@progbits
+	.p2align	2, 0x0
+.Lanon.[ID].5:
+	.long	.Lanon.[ID].1
+	.asciz	"J\000\000\000\022\000\000\000\005\000\000"
+	.size	.Lanon.[ID].5, 16
+
+	.type	.Lanon.[ID].6,@object
+	.section	.rodata..Lanon.[ID].6,"a",@progbits
+.Lanon.[ID].6:
+	.asciz	"NSData"
+	.size	.Lanon.[ID].6, 7
+
+	.type	.Lanon.[ID].7,@object
+	.section	.data.rel.ro..Lanon.[ID].7,"aw",@progbits
+	.p2align	2, 0x0
+.Lanon.[ID].7:
+	.long	.Lanon.[ID].1
+	.asciz	"J\000\000\000\027\000\000\000\r\000\000"
+	.size	.Lanon.[ID].7, 16
+
+	.type	.Lanon.[ID].8,@object
+	.section	.rodata..Lanon.[ID].8,"a",@progbits
+.Lanon.[ID].8:
+	.asciz	"NSException"
+	.size	.Lanon.[ID].8, 12
+
+	.type	.Lanon.[ID].9,@object
+	.section	.data.rel.ro..Lanon.[ID].9,"aw",@progbits
+	.p2align	2, 0x0
+.Lanon.[ID].9:
+	.long	.Lanon.[ID].1
+	.asciz	"J\000\000\000\037\000\000\000\016\000\000"
+	.size	.Lanon.[ID].9, 16
+
+	.type	.Lanon.[ID].10,@object
+	.section	.rodata..Lanon.[ID].10,"a",@progbits
+.Lanon.[ID].10:
+	.asciz	"NSLock"
+	.size	.Lanon.[ID].10, 7
+
+	.type	.Lanon.[ID].11,@object
+	.section	.data.rel.ro..Lanon.[ID].11,"aw",@progbits
+	.p2align	2, 0x0
+.Lanon.[ID].11:
+	.long	.Lanon.[ID].1
+	.asciz	"J\000\000\000-\000\000\000\021\000\000"
+	.size	.Lanon.[ID].11, 16
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0):
+	.zero	4
+	.size	SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0), 4
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0):
+	.zero	4
+	.size	SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0), 4
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0):
+	.zero	4
+	.size	SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0), 4
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0):
+	.zero	4
+	.size	SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0), 4
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0):
+	.zero	4
+	.size	SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0), 4
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0):
+	.zero	4
+	.size	SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0), 4
+
 	.section	".note.GNU-stack","",@progbits
@@ -1,3 +1,352 @@
 	.text
 	.intel_syntax noprefix
+	.section	.text.get_class,"ax",@progbits
+	.globl	get_class
+	.p2align	4, 0x90
+	.type	get_class,@function
+get_class:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	test	rax, rax
+	je	.LBB0_2
+	ret
+.LBB0_2:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	lea	rdx, [rip + .Lanon.[ID].2]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end0:
+	.size	get_class, .Lfunc_end0-get_class
+
+	.section	.text.get_same_class,"ax",@progbits
+	.globl	get_same_class
+	.p2align	4, 0x90
+	.type	get_same_class,@function
+get_same_class:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)]
+	test	rax, rax
+	je	.LBB1_2
+	ret
+.LBB1_2:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	lea	rdx, [rip + .Lanon.[ID].3]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end1:
+	.size	get_same_class, .Lfunc_end1-get_same_class
+
+	.section	.text.get_different_class,"ax",@progbits
+	.globl	get_different_class
+	.p2align	4, 0x90
+	.type	get_different_class,@function
+get_different_class:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)]
+	test	rax, rax
+	je	.LBB2_2
+	ret
+.LBB2_2:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].4]
+	lea	rdx, [rip + .Lanon.[ID].5]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end2:
+	.size	get_different_class, .Lfunc_end2-get_different_class
+
+	.section	.text.unused_class,"ax",@progbits
+	.globl	unused_class
+	.p2align	4, 0x90
+	.type	unused_class,@function
+unused_class:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0)]
+	test	rax, rax
+	je	.LBB3_2
+	ret
+.LBB3_2:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].6]
+	lea	rdx, [rip + .Lanon.[ID].7]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end3:
+	.size	unused_class, .Lfunc_end3-unused_class
+
+	.section	.text.use_fns,"ax",@progbits
+	.globl	use_fns
+	.p2align	4, 0x90
+	.type	use_fns,@function
+use_fns:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdi
+	mov	r14, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	test	r14, r14
+	je	.LBB4_1
+	mov	r15, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)]
+	test	r15, r15
+	je	.LBB4_3
+.LBB4_4:
+	mov	r12, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)]
+	test	r12, r12
+	je	.LBB4_5
+.LBB4_6:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0)]
+	test	rax, rax
+	je	.LBB4_7
+.LBB4_8:
+	mov	qword ptr [rbx], r14
+	mov	qword ptr [rbx + 8], r15
+	mov	qword ptr [rbx + 16], r12
+	mov	qword ptr [rbx + 24], rax
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.LBB4_1:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	lea	rdx, [rip + .Lanon.[ID].2]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r14, rax
+	mov	r15, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)]
+	test	r15, r15
+	jne	.LBB4_4
+.LBB4_3:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	lea	rdx, [rip + .Lanon.[ID].3]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r15, rax
+	mov	r12, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)]
+	test	r12, r12
+	jne	.LBB4_6
+.LBB4_5:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].4]
+	lea	rdx, [rip + .Lanon.[ID].5]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r12, rax
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0)]
+	test	rax, rax
+	jne	.LBB4_8
+.LBB4_7:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].8]
+	lea	rdx, [rip + .Lanon.[ID].9]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	jmp	.LBB4_8
+.Lfunc_end4:
+	.size	use_fns, .Lfunc_end4-use_fns
+
+	.section	.text.use_same_twice,"ax",@progbits
+	.globl	use_same_twice
+	.p2align	4, 0x90
+	.type	use_same_twice,@function
+use_same_twice:
+	push	r14
+	push	rbx
+	push	rax
+	mov	rbx, rdi
+	mov	r14, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	test	r14, r14
+	je	.LBB5_1
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	test	rax, rax
+	je	.LBB5_3
+.LBB5_4:
+	mov	qword ptr [rbx], r14
+	mov	qword ptr [rbx + 8], rax
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB5_1:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	lea	rdx, [rip + .Lanon.[ID].2]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r14, rax
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	test	rax, rax
+	jne	.LBB5_4
+.LBB5_3:
+	lea	rdi, [rip + SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	lea	rdx, [rip + .Lanon.[ID].2]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	jmp	.LBB5_4
+.Lfunc_end5:
+	.size	use_same_twice, .Lfunc_end5-use_same_twice
+
+	.section	.text.use_in_loop,"ax",@progbits
+	.globl	use_in_loop
+	.p2align	4, 0x90
+	.type	use_in_loop,@function
+use_in_loop:
+	push	r15
+	push	r14
+	push	r13
+	push	r12
+	push	rbx
+	test	rdi, rdi
+	je	.LBB6_5
+	mov	rbx, rdi
+	lea	r14, [rip + SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0)]
+	lea	r15, [rip + .Lanon.[ID].10]
+	lea	r12, [rip + .Lanon.[ID].11]
+	mov	r13, qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedClass::fetch::GENERATED_ID, 0)@GOTPCREL]
+	jmp	.LBB6_2
+	.p2align	4, 0x90
+.LBB6_4:
+	dec	rbx
+	je	.LBB6_5
+.LBB6_2:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0)]
+	test	rax, rax
+	jne	.LBB6_4
+	mov	rdi, r14
+	mov	rsi, r15
+	mov	rdx, r12
+	call	r13
+	jmp	.LBB6_4
+.LBB6_5:
+	pop	rbx
+	pop	r12
+	pop	r13
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end6:
+	.size	use_in_loop, .Lfunc_end6-use_in_loop
+
+	.type	.Lanon.[ID].0,@object
+	.section	.rodata..Lanon.[ID].0,"a",@progbits
+.Lanon.[ID].0:
+	.asciz	"NSObject"
+	.size	.Lanon.[ID].0, 9
+
+	.type	.Lanon.[ID].1,@object
+	.section	.rodata..Lanon.[ID].1,"a",@progbits
+.Lanon.[ID].1:
+	.ascii	"crates/$DIR/../test_static_class/lib.rs"
+	.size	.Lanon.[ID].1, 74
+
+	.type	.Lanon.[ID].2,@object
+	.section	.data.rel.ro..Lanon.[ID].2,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.[ID].2:
+	.quad	.Lanon.[ID].1
+	.asciz	"J\000\000\000\000\000\000\000\b\000\000\000\005\000\000"
+	.size	.Lanon.[ID].2, 24
+
+	.type	.Lanon.[ID].3,@object
+	.section	.data.rel.ro..Lanon.[ID].3,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.[ID].3:
+	.quad	.Lanon.[ID].1
+	.asciz	"J\000\000\000\000\000\000\000\r\000\000\000\005\000\000"
+	.size	.Lanon.[ID].3, 24
+
+	.type	.Lanon.[ID].4,@object
+	.section	.rodata..Lanon.[ID].4,"a",@progbits
+.Lanon.[ID].4:
+	.asciz	"NSString"
+	.size	.Lanon.[ID].4, 9
+
+	.type	.Lanon.[ID].5,@object
+	.section	.data.rel.ro..Lanon.[ID].5,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.[ID].5:
+	.quad	.Lanon.[ID].1
+	.asciz	"J\000\000\000\000\000\000\000\022\000\000\000\005\000\000"
+	.size	.Lanon.[ID].5, 24
+
+	.type	.Lanon.[ID].6,@object
+	.section	.rodata..Lanon.[ID].6,"a",@progbits
+.Lanon.[ID].6:
+	.asciz	"NSData"
+	.size	.Lanon.[ID].6, 7
+
+	.type	.Lanon.[ID].7,@object
+	.section	.data.rel.ro..Lanon.[ID].7,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.[ID].7:
+	.quad	.Lanon.[ID].1
+	.asciz	"J\000\000\000\000\000\000\000\027\000\000\000\r\000\000"
+	.size	.Lanon.[ID].7, 24
+
+	.type	.Lanon.[ID].8,@object
+	.section	.rodata..Lanon.[ID].8,"a",@progbits
+.Lanon.[ID].8:
+	.asciz	"NSException"
+	.size	.Lanon.[ID].8, 12
+
+	.type	.Lanon.[ID].9,@object
+	.section	.data.rel.ro..Lanon.[ID].9,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.[ID].9:
+	.quad	.Lanon.[ID].1
+	.asciz	"J\000\000\000\000\000\000\000\037\000\000\000\016\000\000"
+	.size	.Lanon.[ID].9, 24
+
+	.type	.Lanon.[ID].10,@object
+	.section	.rodata..Lanon.[ID].10,"a",@progbits
+.Lanon.[ID].10:
+	.asciz	"NSLock"
+	.size	.Lanon.[ID].10, 7
+
+	.type	.Lanon.[ID].11,@object
+	.section	.data.rel.ro..Lanon.[ID].11,"aw",@progbits
+	.p2align	3, 0x0
+.Lanon.[ID].11:
+	.quad	.Lanon.[ID].1
+	.asciz	"J\000\000\000\000\000\000\000-\000\000\000\021\000\000"
+	.size	.Lanon.[ID].11, 24
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0):
+	.zero	8
+	.size	SYM(test_dynamic_class[CRATE_ID]::get_class::CACHED_CLASS, 0), 8
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0):
+	.zero	8
+	.size	SYM(test_dynamic_class[CRATE_ID]::get_same_class::CACHED_CLASS, 0), 8
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0):
+	.zero	8
+	.size	SYM(test_dynamic_class[CRATE_ID]::get_different_class::CACHED_CLASS, 0), 8
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0):
+	.zero	8
+	.size	SYM(test_dynamic_class[CRATE_ID]::unused_class::CACHED_CLASS, 0), 8
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0):
+	.zero	8
+	.size	SYM(test_dynamic_class[CRATE_ID]::use_fns::CACHED_CLASS, 0), 8
+
+	.type	SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0),@object
+	.section	.bss.SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0):
+	.zero	8
+	.size	SYM(test_dynamic_class[CRATE_ID]::use_in_loop::CACHED_CLASS, 0), 8
+
 	.section	".note.GNU-stack","",@progbits
@@ -1,3 +1,448 @@
 	.text
 	.intel_syntax noprefix
+	.section	.text.get_sel,"ax",@progbits
+	.globl	get_sel
+	.p2align	4, 0x90
+	.type	get_sel,@function
+get_sel:
+	push	ebx
+	sub	esp, 8
+	call	.L0$pb
+.L0$pb:
+	pop	ebx
+.Ltmp0:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp0-.L0$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB0_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB0_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end0:
+	.size	get_sel, .Lfunc_end0-get_sel
+
+	.section	.text.get_same_sel,"ax",@progbits
+	.globl	get_same_sel
+	.p2align	4, 0x90
+	.type	get_same_sel,@function
+get_same_sel:
+	push	ebx
+	sub	esp, 8
+	call	.L1$pb
+.L1$pb:
+	pop	ebx
+.Ltmp1:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp1-.L1$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB1_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB1_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end1:
+	.size	get_same_sel, .Lfunc_end1-get_same_sel
+
+	.section	.text.get_common_twice,"ax",@progbits
+	.globl	get_common_twice
+	.p2align	4, 0x90
+	.type	get_common_twice,@function
+get_common_twice:
+	push	ebx
+	push	edi
+	push	esi
+	call	.L2$pb
+.L2$pb:
+	pop	ebx
+.Ltmp2:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp2-.L2$pb)
+	mov	edi, dword ptr [ebx + SYM(objc2::__macro_helpers::common_selectors::alloc_sel::CACHED_SEL::GENERATED_ID, 0)@GOT]
+	mov	esi, dword ptr [edi]
+	test	esi, esi
+	je	.LBB2_1
+	mov	edx, dword ptr [edi]
+	test	edx, edx
+	je	.LBB2_3
+.LBB2_4:
+	mov	eax, esi
+	pop	esi
+	pop	edi
+	pop	ebx
+	ret
+.LBB2_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].1@GOTOFF]
+	push	eax
+	push	edi
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	mov	esi, eax
+	mov	edx, dword ptr [edi]
+	test	edx, edx
+	jne	.LBB2_4
+.LBB2_3:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].1@GOTOFF]
+	push	eax
+	push	edi
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	mov	edx, eax
+	jmp	.LBB2_4
+.Lfunc_end2:
+	.size	get_common_twice, .Lfunc_end2-get_common_twice
+
+	.section	.text.get_different_sel,"ax",@progbits
+	.globl	get_different_sel
+	.p2align	4, 0x90
+	.type	get_different_sel,@function
+get_different_sel:
+	push	ebx
+	sub	esp, 8
+	call	.L3$pb
+.L3$pb:
+	pop	ebx
+.Ltmp3:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp3-.L3$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB3_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB3_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].2@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end3:
+	.size	get_different_sel, .Lfunc_end3-get_different_sel
+
+	.section	.text.unused_sel,"ax",@progbits
+	.globl	unused_sel
+	.p2align	4, 0x90
+	.type	unused_sel,@function
+unused_sel:
+	push	ebx
+	sub	esp, 8
+	call	.L4$pb
+.L4$pb:
+	pop	ebx
+.Ltmp4:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp4-.L4$pb)
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB4_1
+	add	esp, 8
+	pop	ebx
+	ret
+.LBB4_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].3@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 24
+	pop	ebx
+	ret
+.Lfunc_end4:
+	.size	unused_sel, .Lfunc_end4-unused_sel
+
+	.section	.text.use_fns,"ax",@progbits
+	.globl	use_fns
+	.p2align	4, 0x90
+	.type	use_fns,@function
+use_fns:
+	push	ebp
+	push	ebx
+	push	edi
+	push	esi
+	sub	esp, 12
+	call	.L5$pb
+.L5$pb:
+	pop	ebx
+.Ltmp5:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp5-.L5$pb)
+	mov	ecx, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	test	ecx, ecx
+	je	.LBB5_1
+	mov	edi, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)@GOTOFF]
+	test	edi, edi
+	je	.LBB5_3
+.LBB5_4:
+	mov	esi, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)@GOTOFF]
+	mov	ebp, dword ptr [esp + 32]
+	test	esi, esi
+	je	.LBB5_5
+.LBB5_6:
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB5_7
+.LBB5_8:
+	mov	dword ptr [ebp], ecx
+	mov	dword ptr [ebp + 4], edi
+	mov	dword ptr [ebp + 8], esi
+	mov	dword ptr [ebp + 12], eax
+	mov	eax, ebp
+	add	esp, 12
+	pop	esi
+	pop	edi
+	pop	ebx
+	pop	ebp
+	ret	4
+.LBB5_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	mov	ecx, eax
+	mov	edi, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)@GOTOFF]
+	test	edi, edi
+	jne	.LBB5_4
+.LBB5_3:
+	sub	esp, 8
+	mov	esi, ecx
+	lea	eax, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	mov	ecx, esi
+	add	esp, 16
+	mov	edi, eax
+	mov	esi, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)@GOTOFF]
+	mov	ebp, dword ptr [esp + 32]
+	test	esi, esi
+	jne	.LBB5_6
+.LBB5_5:
+	sub	esp, 8
+	mov	esi, ecx
+	lea	eax, [ebx + .Lanon.[ID].2@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	mov	ecx, esi
+	add	esp, 16
+	mov	esi, eax
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	jne	.LBB5_8
+.LBB5_7:
+	sub	esp, 8
+	mov	dword ptr [esp + 16], ecx
+	lea	eax, [ebx + .Lanon.[ID].4@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	mov	ecx, dword ptr [esp + 24]
+	add	esp, 16
+	jmp	.LBB5_8
+.Lfunc_end5:
+	.size	use_fns, .Lfunc_end5-use_fns
+
+	.section	.text.use_same_twice,"ax",@progbits
+	.globl	use_same_twice
+	.p2align	4, 0x90
+	.type	use_same_twice,@function
+use_same_twice:
+	push	ebx
+	push	edi
+	push	esi
+	call	.L6$pb
+.L6$pb:
+	pop	ebx
+	mov	esi, dword ptr [esp + 16]
+.Ltmp6:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp6-.L6$pb)
+	mov	edi, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	test	edi, edi
+	je	.LBB6_1
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	je	.LBB6_3
+.LBB6_4:
+	mov	dword ptr [esi], edi
+	mov	dword ptr [esi + 4], eax
+	mov	eax, esi
+	pop	esi
+	pop	edi
+	pop	ebx
+	ret	4
+.LBB6_1:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	mov	edi, eax
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	jne	.LBB6_4
+.LBB6_3:
+	sub	esp, 8
+	lea	eax, [ebx + .Lanon.[ID].0@GOTOFF]
+	lea	ecx, [ebx + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)@GOTOFF]
+	push	eax
+	push	ecx
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	jmp	.LBB6_4
+.Lfunc_end6:
+	.size	use_same_twice, .Lfunc_end6-use_same_twice
+
+	.section	.text.use_in_loop,"ax",@progbits
+	.globl	use_in_loop
+	.p2align	4, 0x90
+	.type	use_in_loop,@function
+use_in_loop:
+	push	ebp
+	push	ebx
+	push	edi
+	push	esi
+	sub	esp, 12
+	mov	esi, dword ptr [esp + 32]
+	call	.L7$pb
+.L7$pb:
+	pop	ebx
+.Ltmp7:
+	add	ebx, offset _GLOBAL_OFFSET_TABLE_+(.Ltmp7-.L7$pb)
+	test	esi, esi
+	je	.LBB7_5
+	lea	edi, [ebx + .Lanon.[ID].5@GOTOFF]
+	lea	ebp, [ebx + SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0)@GOTOFF]
+	jmp	.LBB7_2
+	.p2align	4, 0x90
+.LBB7_4:
+	dec	esi
+	je	.LBB7_5
+.LBB7_2:
+	mov	eax, dword ptr [ebx + SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0)@GOTOFF]
+	test	eax, eax
+	jne	.LBB7_4
+	sub	esp, 8
+	push	edi
+	push	ebp
+	call	SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@PLT
+	add	esp, 16
+	jmp	.LBB7_4
+.LBB7_5:
+	add	esp, 12
+	pop	esi
+	pop	edi
+	pop	ebx
+	pop	ebp
+	ret
+.Lfunc_end7:
+	.size	use_in_loop, .Lfunc_end7-use_in_loop
+
+	.type	.Lanon.[ID].0,@object
+	.section	.rodata..Lanon.[ID].0,"a",@progbits
+.Lanon.[ID].0:
+	.asciz	"simple"
+	.size	.Lanon.[ID].0, 7
+
+	.type	.Lanon.[ID].1,@object
+	.section	.rodata..Lanon.[ID].1,"a",@progbits
+.Lanon.[ID].1:
+	.asciz	"alloc"
+	.size	.Lanon.[ID].1, 6
+
+	.type	.Lanon.[ID].2,@object
+	.section	.rodata.cst16,"aM",@progbits,16
+.Lanon.[ID].2:
+	.asciz	"i:am:different:"
+	.size	.Lanon.[ID].2, 16
+
+	.type	.Lanon.[ID].3,@object
+	.section	.rodata..Lanon.[ID].3,"a",@progbits
+.Lanon.[ID].3:
+	.asciz	"unused"
+	.size	.Lanon.[ID].3, 7
+
+	.type	.Lanon.[ID].4,@object
+	.section	.rodata..Lanon.[ID].4,"a",@progbits
+.Lanon.[ID].4:
+	.asciz	"fourthSel"
+	.size	.Lanon.[ID].4, 10
+
+	.type	.Lanon.[ID].5,@object
+	.section	.rodata..Lanon.[ID].5,"a",@progbits
+.Lanon.[ID].5:
+	.asciz	"loopedSelector"
+	.size	.Lanon.[ID].5, 15
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0):
+	.zero	4
+	.size	SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0), 4
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0):
+	.zero	4
+	.size	SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0), 4
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0):
+	.zero	4
+	.size	SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0), 4
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0):
+	.zero	4
+	.size	SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0), 4
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0):
+	.zero	4
+	.size	SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0), 4
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0),"aw",@nobits
+	.p2align	2, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0):
+	.zero	4
+	.size	SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0), 4
+
 	.section	".note.GNU-stack","",@progbits
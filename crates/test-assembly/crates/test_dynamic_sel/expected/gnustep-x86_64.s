@@ -1,3 +1,330 @@
 	.text
 	.intel_syntax noprefix
+	.section	.text.get_sel,"ax",@progbits
+	.globl	get_sel
+	.p2align	4, 0x90
+	.type	get_sel,@function
+get_sel:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	test	rax, rax
+	je	.LBB0_2
+	ret
+.LBB0_2:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end0:
+	.size	get_sel, .Lfunc_end0-get_sel
+
+	.section	.text.get_same_sel,"ax",@progbits
+	.globl	get_same_sel
+	.p2align	4, 0x90
+	.type	get_same_sel,@function
+get_same_sel:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)]
+	test	rax, rax
+	je	.LBB1_2
+	ret
+.LBB1_2:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end1:
+	.size	get_same_sel, .Lfunc_end1-get_same_sel
+
+	.section	.text.get_common_twice,"ax",@progbits
+	.globl	get_common_twice
+	.p2align	4, 0x90
+	.type	get_common_twice,@function
+get_common_twice:
+	push	r14
+	push	rbx
+	push	rax
+	mov	r14, qword ptr [rip + SYM(objc2::__macro_helpers::common_selectors::alloc_sel::CACHED_SEL::GENERATED_ID, 0)@GOTPCREL]
+	mov	rbx, qword ptr [r14]
+	test	rbx, rbx
+	je	.LBB2_1
+	mov	rdx, qword ptr [r14]
+	test	rdx, rdx
+	je	.LBB2_3
+.LBB2_4:
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB2_1:
+	mov	rdi, qword ptr [rip + SYM(objc2::__macro_helpers::common_selectors::alloc_sel::CACHED_SEL::GENERATED_ID, 0)@GOTPCREL]
+	lea	rsi, [rip + .Lanon.[ID].1]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	rbx, rax
+	mov	rdx, qword ptr [r14]
+	test	rdx, rdx
+	jne	.LBB2_4
+.LBB2_3:
+	mov	rdi, qword ptr [rip + SYM(objc2::__macro_helpers::common_selectors::alloc_sel::CACHED_SEL::GENERATED_ID, 0)@GOTPCREL]
+	lea	rsi, [rip + .Lanon.[ID].1]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	rdx, rax
+	jmp	.LBB2_4
+.Lfunc_end2:
+	.size	get_common_twice, .Lfunc_end2-get_common_twice
+
+	.section	.text.get_different_sel,"ax",@progbits
+	.globl	get_different_sel
+	.p2align	4, 0x90
+	.type	get_different_sel,@function
+get_different_sel:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)]
+	test	rax, rax
+	je	.LBB3_2
+	ret
+.LBB3_2:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].2]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end3:
+	.size	get_different_sel, .Lfunc_end3-get_different_sel
+
+	.section	.text.unused_sel,"ax",@progbits
+	.globl	unused_sel
+	.p2align	4, 0x90
+	.type	unused_sel,@function
+unused_sel:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0)]
+	test	rax, rax
+	je	.LBB4_2
+	ret
+.LBB4_2:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].3]
+	jmp	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+.Lfunc_end4:
+	.size	unused_sel, .Lfunc_end4-unused_sel
+
+	.section	.text.use_fns,"ax",@progbits
+	.globl	use_fns
+	.p2align	4, 0x90
+	.type	use_fns,@function
+use_fns:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	mov	rbx, rdi
+	mov	r14, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	test	r14, r14
+	je	.LBB5_1
+	mov	r15, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)]
+	test	r15, r15
+	je	.LBB5_3
+.LBB5_4:
+	mov	r12, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)]
+	test	r12, r12
+	je	.LBB5_5
+.LBB5_6:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0)]
+	test	rax, rax
+	je	.LBB5_7
+.LBB5_8:
+	mov	qword ptr [rbx], r14
+	mov	qword ptr [rbx + 8], r15
+	mov	qword ptr [rbx + 16], r12
+	mov	qword ptr [rbx + 24], rax
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.LBB5_1:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r14, rax
+	mov	r15, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)]
+	test	r15, r15
+	jne	.LBB5_4
+.LBB5_3:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r15, rax
+	mov	r12, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)]
+	test	r12, r12
+	jne	.LBB5_6
+.LBB5_5:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].2]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r12, rax
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0)]
+	test	rax, rax
+	jne	.LBB5_8
+.LBB5_7:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].4]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	jmp	.LBB5_8
+.Lfunc_end5:
+	.size	use_fns, .Lfunc_end5-use_fns
+
+	.section	.text.use_same_twice,"ax",@progbits
+	.globl	use_same_twice
+	.p2align	4, 0x90
+	.type	use_same_twice,@function
+use_same_twice:
+	push	r14
+	push	rbx
+	push	rax
+	mov	rbx, rdi
+	mov	r14, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	test	r14, r14
+	je	.LBB6_1
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	test	rax, rax
+	je	.LBB6_3
+.LBB6_4:
+	mov	qword ptr [rbx], r14
+	mov	qword ptr [rbx + 8], rax
+	mov	rax, rbx
+	add	rsp, 8
+	pop	rbx
+	pop	r14
+	ret
+.LBB6_1:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	mov	r14, rax
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	test	rax, rax
+	jne	.LBB6_4
+.LBB6_3:
+	lea	rdi, [rip + SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0)]
+	lea	rsi, [rip + .Lanon.[ID].0]
+	call	qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	jmp	.LBB6_4
+.Lfunc_end6:
+	.size	use_same_twice, .Lfunc_end6-use_same_twice
+
+	.section	.text.use_in_loop,"ax",@progbits
+	.globl	use_in_loop
+	.p2align	4, 0x90
+	.type	use_in_loop,@function
+use_in_loop:
+	push	r15
+	push	r14
+	push	r12
+	push	rbx
+	push	rax
+	test	rdi, rdi
+	je	.LBB7_5
+	mov	rbx, rdi
+	lea	r14, [rip + SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0)]
+	lea	r15, [rip + .Lanon.[ID].5]
+	mov	r12, qword ptr [rip + SYM(objc2::__macro_helpers::cache::CachedSel::fetch::GENERATED_ID, 0)@GOTPCREL]
+	jmp	.LBB7_2
+	.p2align	4, 0x90
+.LBB7_4:
+	dec	rbx
+	je	.LBB7_5
+.LBB7_2:
+	mov	rax, qword ptr [rip + SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0)]
+	test	rax, rax
+	jne	.LBB7_4
+	mov	rdi, r14
+	mov	rsi, r15
+	call	r12
+	jmp	.LBB7_4
+.LBB7_5:
+	add	rsp, 8
+	pop	rbx
+	pop	r12
+	pop	r14
+	pop	r15
+	ret
+.Lfunc_end7:
+	.size	use_in_loop, .Lfunc_end7-use_in_loop
+
+	.type	.Lanon.[ID].0,@object
+	.section	.rodata..Lanon.[ID].0,"a",@progbits
+.Lanon.[ID].0:
+	.asciz	"simple"
+	.size	.Lanon.[ID].0, 7
+
+	.type	.Lanon.[ID].1,@object
+	.section	.rodata..Lanon.[ID].1,"a",@progbits
+.Lanon.[ID].1:
+	.asciz	"alloc"
+	.size	.Lanon.[ID].1, 6
+
+	.type	.Lanon.[ID].2,@object
+	.section	.rodata.cst16,"aM",@progbits,16
+.Lanon.[ID].2:
+	.asciz	"i:am:different:"
+	.size	.Lanon.[ID].2, 16
+
+	.type	.Lanon.[ID].3,@object
+	.section	.rodata..Lanon.[ID].3,"a",@progbits
+.Lanon.[ID].3:
+	.asciz	"unused"
+	.size	.Lanon.[ID].3, 7
+
+	.type	.Lanon.[ID].4,@object
+	.section	.rodata..Lanon.[ID].4,"a",@progbits
+.Lanon.[ID].4:
+	.asciz	"fourthSel"
+	.size	.Lanon.[ID].4, 10
+
+	.type	.Lanon.[ID].5,@object
+	.section	.rodata..Lanon.[ID].5,"a",@progbits
+.Lanon.[ID].5:
+	.asciz	"loopedSelector"
+	.size	.Lanon.[ID].5, 15
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0):
+	.zero	8
+	.size	SYM(test_dynamic_sel[CRATE_ID]::get_sel::CACHED_SEL, 0), 8
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0):
+	.zero	8
+	.size	SYM(test_dynamic_sel[CRATE_ID]::get_same_sel::CACHED_SEL, 0), 8
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0):
+	.zero	8
+	.size	SYM(test_dynamic_sel[CRATE_ID]::get_different_sel::CACHED_SEL, 0), 8
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0):
+	.zero	8
+	.size	SYM(test_dynamic_sel[CRATE_ID]::unused_sel::CACHED_SEL, 0), 8
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0):
+	.zero	8
+	.size	SYM(test_dynamic_sel[CRATE_ID]::use_fns::CACHED_SEL, 0), 8
+
+	.type	SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0),@object
+	.section	.bss.SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0),"aw",@nobits
+	.p2align	3, 0x0
+SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0):
+	.zero	8
+	.size	SYM(test_dynamic_sel[CRATE_ID]::use_in_loop::CACHED_SEL, 0), 8
+
 	.section	".note.GNU-stack","",@progbits
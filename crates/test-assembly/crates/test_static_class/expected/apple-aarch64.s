@@ -3,9 +3,9 @@
 	.p2align	2
 _get_class:
 Lloh0:
-	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777@PAGE
+	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d@PAGE
 Lloh1:
-	ldr	x0, [x8, L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777@PAGEOFF]
+	ldr	x0, [x8, L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d@PAGEOFF]
 	ret
 	.loh AdrpLdr	Lloh0, Lloh1
 
@@ -13,9 +13,9 @@ Lloh1:
 	.p2align	2
 _get_same_class:
 Lloh2:
-	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07@PAGE
+	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079@PAGE
 Lloh3:
-	ldr	x0, [x8, L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07@PAGEOFF]
+	ldr	x0, [x8, L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079@PAGEOFF]
 	ret
 	.loh AdrpLdr	Lloh2, Lloh3
 
@@ -23,9 +23,9 @@ Lloh3:
 	.p2align	2
 _get_different_class:
 Lloh4:
-	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b@PAGE
+	adrp	x8, L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d@PAGE
 Lloh5:
-	ldr	x0, [x8, L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b@PAGEOFF]
+	ldr	x0, [x8, L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d@PAGEOFF]
 	ret
 	.loh AdrpLdr	Lloh4, Lloh5
 
@@ -38,21 +38,21 @@ _unused_class:
 	.p2align	2
 _use_fns:
 Lloh6:
-	adrp	x9, L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777@PAGE
+	adrp	x9, L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d@PAGE
 Lloh7:
-	ldr	x9, [x9, L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777@PAGEOFF]
+	ldr	x9, [x9, L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d@PAGEOFF]
 Lloh8:
-	adrp	x10, L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07@PAGE
+	adrp	x10, L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079@PAGE
 Lloh9:
-	ldr	x10, [x10, L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07@PAGEOFF]
+	ldr	x10, [x10, L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079@PAGEOFF]
 Lloh10:
-	adrp	x11, L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b@PAGE
+	adrp	x11, L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d@PAGE
 Lloh11:
-	ldr	x11, [x11, L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b@PAGEOFF]
+	ldr	x11, [x11, L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d@PAGEOFF]
 Lloh12:
-	adrp	x12, L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063@PAGE
+	adrp	x12, L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7@PAGE
 Lloh13:
-	ldr	x12, [x12, L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063@PAGEOFF]
+	ldr	x12, [x12, L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7@PAGEOFF]
 	stp	x9, x10, [x8]
 	stp	x11, x12, [x8, #16]
 	ret
@@ -65,9 +65,9 @@ Lloh13:
 	.p2align	2
 _use_same_twice:
 Lloh14:
-	adrp	x9, L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777@PAGE
+	adrp	x9, L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d@PAGE
 Lloh15:
-	ldr	x9, [x9, L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777@PAGEOFF]
+	ldr	x9, [x9, L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d@PAGEOFF]
 	stp	x9, x9, [x8]
 	ret
 	.loh AdrpLdr	Lloh14, Lloh15
@@ -78,75 +78,75 @@ _use_in_loop:
 	ret
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_928cf03fcc497777
+	.globl	L_OBJC_IMAGE_INFO_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_928cf03fcc497777:
+L_OBJC_IMAGE_INFO_c32665b9ca7a707d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777:
+L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d:
 	.quad	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2fe1990982915f07
+	.globl	L_OBJC_IMAGE_INFO_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2fe1990982915f07:
+L_OBJC_IMAGE_INFO_2cd2086bcd2e4079:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07:
+L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079:
 	.quad	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_dfff3a06c0bf722b
+	.globl	L_OBJC_IMAGE_INFO_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_dfff3a06c0bf722b:
+L_OBJC_IMAGE_INFO_875901768358e77d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b:
+L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d:
 	.quad	_OBJC_CLASS_$_NSString
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_ea6fbcf172f7f513
+	.globl	L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_ea6fbcf172f7f513:
+L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513:
+L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1:
 	.quad	_OBJC_CLASS_$_NSData
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_97e6a8c6ed5db063
+	.globl	L_OBJC_IMAGE_INFO_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_97e6a8c6ed5db063:
+L_OBJC_IMAGE_INFO_07394a753317e1f7:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063:
+L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7:
 	.quad	_OBJC_CLASS_$_NSException
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_bb5b616899716c0d
+	.globl	L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_bb5b616899716c0d:
+L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d:
+L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a:
 	.quad	_OBJC_CLASS_$_NSLock
 
 .subsections_via_symbols
@@ -4,8 +4,8 @@
 	.p2align	2
 	.code	32
 _get_class:
-	movw	r0, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-(LPC0_0+8))
-	movt	r0, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-(LPC0_0+8))
+	movw	r0, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-(LPC0_0+8))
+	movt	r0, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-(LPC0_0+8))
 LPC0_0:
 	ldr	r0, [pc, r0]
 	bx	lr
@@ -14,8 +14,8 @@ LPC0_0:
 	.p2align	2
 	.code	32
 _get_same_class:
-	movw	r0, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07-(LPC1_0+8))
-	movt	r0, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07-(LPC1_0+8))
+	movw	r0, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079-(LPC1_0+8))
+	movt	r0, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079-(LPC1_0+8))
 LPC1_0:
 	ldr	r0, [pc, r0]
 	bx	lr
@@ -24,8 +24,8 @@ LPC1_0:
 	.p2align	2
 	.code	32
 _get_different_class:
-	movw	r0, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b-(LPC2_0+8))
-	movt	r0, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b-(LPC2_0+8))
+	movw	r0, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d-(LPC2_0+8))
+	movt	r0, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d-(LPC2_0+8))
 LPC2_0:
 	ldr	r0, [pc, r0]
 	bx	lr
@@ -40,20 +40,20 @@ _unused_class:
 	.p2align	2
 	.code	32
 _use_fns:
-	movw	r9, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063-(LPC4_0+8))
-	movt	r9, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063-(LPC4_0+8))
+	movw	r9, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7-(LPC4_0+8))
+	movt	r9, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7-(LPC4_0+8))
 LPC4_0:
 	ldr	r9, [pc, r9]
-	movw	r2, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b-(LPC4_1+8))
-	movt	r2, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b-(LPC4_1+8))
+	movw	r2, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d-(LPC4_1+8))
+	movt	r2, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d-(LPC4_1+8))
 LPC4_1:
 	ldr	r2, [pc, r2]
-	movw	r3, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07-(LPC4_2+8))
-	movt	r3, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07-(LPC4_2+8))
+	movw	r3, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079-(LPC4_2+8))
+	movt	r3, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079-(LPC4_2+8))
 LPC4_2:
 	ldr	r3, [pc, r3]
-	movw	r1, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-(LPC4_3+8))
-	movt	r1, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-(LPC4_3+8))
+	movw	r1, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-(LPC4_3+8))
+	movt	r1, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-(LPC4_3+8))
 	str	r2, [r0, #8]
 LPC4_3:
 	ldr	r1, [pc, r1]
@@ -65,8 +65,8 @@ LPC4_3:
 	.p2align	2
 	.code	32
 _use_same_twice:
-	movw	r1, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-(LPC5_0+8))
-	movt	r1, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-(LPC5_0+8))
+	movw	r1, :lower16:(L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-(LPC5_0+8))
+	movt	r1, :upper16:(L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-(LPC5_0+8))
 LPC5_0:
 	ldr	r1, [pc, r1]
 	str	r1, [r0]
@@ -80,75 +80,75 @@ _use_in_loop:
 	bx	lr
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_928cf03fcc497777
+	.globl	L_OBJC_IMAGE_INFO_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_928cf03fcc497777:
+L_OBJC_IMAGE_INFO_c32665b9ca7a707d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777:
+L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d:
 	.long	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2fe1990982915f07
+	.globl	L_OBJC_IMAGE_INFO_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2fe1990982915f07:
+L_OBJC_IMAGE_INFO_2cd2086bcd2e4079:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07:
+L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079:
 	.long	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_dfff3a06c0bf722b
+	.globl	L_OBJC_IMAGE_INFO_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_dfff3a06c0bf722b:
+L_OBJC_IMAGE_INFO_875901768358e77d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b:
+L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d:
 	.long	_OBJC_CLASS_$_NSString
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_ea6fbcf172f7f513
+	.globl	L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_ea6fbcf172f7f513:
+L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513:
+L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1:
 	.long	_OBJC_CLASS_$_NSData
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_97e6a8c6ed5db063
+	.globl	L_OBJC_IMAGE_INFO_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_97e6a8c6ed5db063:
+L_OBJC_IMAGE_INFO_07394a753317e1f7:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063:
+L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7:
 	.long	_OBJC_CLASS_$_NSException
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_bb5b616899716c0d
+	.globl	L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_bb5b616899716c0d:
+L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d:
+L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a:
 	.long	_OBJC_CLASS_$_NSLock
 
 .subsections_via_symbols
@@ -8,7 +8,7 @@ _get_class:
 	call	L0$pb
 L0$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_CLASS_REFERENCES_928cf03fcc497777-L0$pb]
+	mov	eax, dword ptr [eax + L_OBJC_CLASS_REFERENCES_c32665b9ca7a707d-L0$pb]
 	pop	ebp
 	ret
 
@@ -20,7 +20,7 @@ _get_same_class:
 	call	L1$pb
 L1$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_CLASS_REFERENCES_2fe1990982915f07-L1$pb]
+	mov	eax, dword ptr [eax + L_OBJC_CLASS_REFERENCES_2cd2086bcd2e4079-L1$pb]
 	pop	ebp
 	ret
 
@@ -32,7 +32,7 @@ _get_different_class:
 	call	L2$pb
 L2$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_CLASS_REFERENCES_dfff3a06c0bf722b-L2$pb]
+	mov	eax, dword ptr [eax + L_OBJC_CLASS_REFERENCES_875901768358e77d-L2$pb]
 	pop	ebp
 	ret
 
@@ -55,10 +55,10 @@ _use_fns:
 L4$pb:
 	pop	ecx
 	mov	eax, dword ptr [ebp + 8]
-	mov	edx, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_928cf03fcc497777-L4$pb]
-	mov	esi, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_2fe1990982915f07-L4$pb]
-	mov	edi, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_dfff3a06c0bf722b-L4$pb]
-	mov	ecx, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_97e6a8c6ed5db063-L4$pb]
+	mov	edx, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_c32665b9ca7a707d-L4$pb]
+	mov	esi, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_2cd2086bcd2e4079-L4$pb]
+	mov	edi, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_875901768358e77d-L4$pb]
+	mov	ecx, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_07394a753317e1f7-L4$pb]
 	mov	dword ptr [eax], edx
 	mov	dword ptr [eax + 4], esi
 	mov	dword ptr [eax + 8], edi
@@ -77,7 +77,7 @@ _use_same_twice:
 L5$pb:
 	pop	ecx
 	mov	eax, dword ptr [ebp + 8]
-	mov	ecx, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_928cf03fcc497777-L5$pb]
+	mov	ecx, dword ptr [ecx + L_OBJC_CLASS_REFERENCES_c32665b9ca7a707d-L5$pb]
 	mov	dword ptr [eax], ecx
 	mov	dword ptr [eax + 4], ecx
 	pop	ebp
@@ -92,183 +92,183 @@ _use_in_loop:
 	ret
 
 	.section	__OBJC,__image_info
-	.globl	L_OBJC_IMAGE_INFO_928cf03fcc497777
+	.globl	L_OBJC_IMAGE_INFO_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_928cf03fcc497777:
+L_OBJC_IMAGE_INFO_c32665b9ca7a707d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_928cf03fcc497777
-L_OBJC_CLASS_NAME_928cf03fcc497777:
+	.globl	L_OBJC_CLASS_NAME_c32665b9ca7a707d
+L_OBJC_CLASS_NAME_c32665b9ca7a707d:
 	.ascii	"NSObject"
 
 	.section	__OBJC,__cls_refs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_CLASS_REFERENCES_928cf03fcc497777
+	.globl	L_OBJC_CLASS_REFERENCES_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_CLASS_REFERENCES_928cf03fcc497777:
-	.long	L_OBJC_CLASS_NAME_928cf03fcc497777
+L_OBJC_CLASS_REFERENCES_c32665b9ca7a707d:
+	.long	L_OBJC_CLASS_NAME_c32665b9ca7a707d
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_928cf03fcc497777_MODULE_INFO
-L_OBJC_CLASS_NAME_928cf03fcc497777_MODULE_INFO:
+	.globl	L_OBJC_CLASS_NAME_c32665b9ca7a707d_MODULE_INFO
+L_OBJC_CLASS_NAME_c32665b9ca7a707d_MODULE_INFO:
 	.space	1
 
 	.section	__OBJC,__module_info,regular,no_dead_strip
-	.globl	L_OBJC_MODULES_928cf03fcc497777
+	.globl	L_OBJC_MODULES_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_MODULES_928cf03fcc497777:
+L_OBJC_MODULES_c32665b9ca7a707d:
 	.asciz	"\007\000\000\000\020\000\000"
-	.long	L_OBJC_CLASS_NAME_928cf03fcc497777_MODULE_INFO
+	.long	L_OBJC_CLASS_NAME_c32665b9ca7a707d_MODULE_INFO
 	.space	4
 
 	.section	__OBJC,__image_info
-	.globl	L_OBJC_IMAGE_INFO_2fe1990982915f07
+	.globl	L_OBJC_IMAGE_INFO_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2fe1990982915f07:
+L_OBJC_IMAGE_INFO_2cd2086bcd2e4079:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_2fe1990982915f07
-L_OBJC_CLASS_NAME_2fe1990982915f07:
+	.globl	L_OBJC_CLASS_NAME_2cd2086bcd2e4079
+L_OBJC_CLASS_NAME_2cd2086bcd2e4079:
 	.ascii	"NSObject"
 
 	.section	__OBJC,__cls_refs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_CLASS_REFERENCES_2fe1990982915f07
+	.globl	L_OBJC_CLASS_REFERENCES_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_CLASS_REFERENCES_2fe1990982915f07:
-	.long	L_OBJC_CLASS_NAME_2fe1990982915f07
+L_OBJC_CLASS_REFERENCES_2cd2086bcd2e4079:
+	.long	L_OBJC_CLASS_NAME_2cd2086bcd2e4079
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_2fe1990982915f07_MODULE_INFO
-L_OBJC_CLASS_NAME_2fe1990982915f07_MODULE_INFO:
+	.globl	L_OBJC_CLASS_NAME_2cd2086bcd2e4079_MODULE_INFO
+L_OBJC_CLASS_NAME_2cd2086bcd2e4079_MODULE_INFO:
 	.space	1
 
 	.section	__OBJC,__module_info,regular,no_dead_strip
-	.globl	L_OBJC_MODULES_2fe1990982915f07
+	.globl	L_OBJC_MODULES_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_MODULES_2fe1990982915f07:
+L_OBJC_MODULES_2cd2086bcd2e4079:
 	.asciz	"\007\000\000\000\020\000\000"
-	.long	L_OBJC_CLASS_NAME_2fe1990982915f07_MODULE_INFO
+	.long	L_OBJC_CLASS_NAME_2cd2086bcd2e4079_MODULE_INFO
 	.space	4
 
 	.section	__OBJC,__image_info
-	.globl	L_OBJC_IMAGE_INFO_dfff3a06c0bf722b
+	.globl	L_OBJC_IMAGE_INFO_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_dfff3a06c0bf722b:
+L_OBJC_IMAGE_INFO_875901768358e77d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_dfff3a06c0bf722b
-L_OBJC_CLASS_NAME_dfff3a06c0bf722b:
+	.globl	L_OBJC_CLASS_NAME_875901768358e77d
+L_OBJC_CLASS_NAME_875901768358e77d:
 	.ascii	"NSString"
 
 	.section	__OBJC,__cls_refs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_CLASS_REFERENCES_dfff3a06c0bf722b
+	.globl	L_OBJC_CLASS_REFERENCES_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_CLASS_REFERENCES_dfff3a06c0bf722b:
-	.long	L_OBJC_CLASS_NAME_dfff3a06c0bf722b
+L_OBJC_CLASS_REFERENCES_875901768358e77d:
+	.long	L_OBJC_CLASS_NAME_875901768358e77d
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_dfff3a06c0bf722b_MODULE_INFO
-L_OBJC_CLASS_NAME_dfff3a06c0bf722b_MODULE_INFO:
+	.globl	L_OBJC_CLASS_NAME_875901768358e77d_MODULE_INFO
+L_OBJC_CLASS_NAME_875901768358e77d_MODULE_INFO:
 	.space	1
 
 	.section	__OBJC,__module_info,regular,no_dead_strip
-	.globl	L_OBJC_MODULES_dfff3a06c0bf722b
+	.globl	L_OBJC_MODULES_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_MODULES_dfff3a06c0bf722b:
+L_OBJC_MODULES_875901768358e77d:
 	.asciz	"\007\000\000\000\020\000\000"
-	.long	L_OBJC_CLASS_NAME_dfff3a06c0bf722b_MODULE_INFO
+	.long	L_OBJC_CLASS_NAME_875901768358e77d_MODULE_INFO
 	.space	4
 
 	.section	__OBJC,__image_info
-	.globl	L_OBJC_IMAGE_INFO_ea6fbcf172f7f513
+	.globl	L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_ea6fbcf172f7f513:
+L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_ea6fbcf172f7f513
-L_OBJC_CLASS_NAME_ea6fbcf172f7f513:
+	.globl	L_OBJC_CLASS_NAME_56a9f1a4e2def0e1
+L_OBJC_CLASS_NAME_56a9f1a4e2def0e1:
 	.ascii	"NSData"
 
 	.section	__OBJC,__cls_refs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_CLASS_REFERENCES_ea6fbcf172f7f513
+	.globl	L_OBJC_CLASS_REFERENCES_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_CLASS_REFERENCES_ea6fbcf172f7f513:
-	.long	L_OBJC_CLASS_NAME_ea6fbcf172f7f513
+L_OBJC_CLASS_REFERENCES_56a9f1a4e2def0e1:
+	.long	L_OBJC_CLASS_NAME_56a9f1a4e2def0e1
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_ea6fbcf172f7f513_MODULE_INFO
-L_OBJC_CLASS_NAME_ea6fbcf172f7f513_MODULE_INFO:
+	.globl	L_OBJC_CLASS_NAME_56a9f1a4e2def0e1_MODULE_INFO
+L_OBJC_CLASS_NAME_56a9f1a4e2def0e1_MODULE_INFO:
 	.space	1
 
 	.section	__OBJC,__module_info,regular,no_dead_strip
-	.globl	L_OBJC_MODULES_ea6fbcf172f7f513
+	.globl	L_OBJC_MODULES_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_MODULES_ea6fbcf172f7f513:
+L_OBJC_MODULES_56a9f1a4e2def0e1:
 	.asciz	"\007\000\000\000\020\000\000"
-	.long	L_OBJC_CLASS_NAME_ea6fbcf172f7f513_MODULE_INFO
+	.long	L_OBJC_CLASS_NAME_56a9f1a4e2def0e1_MODULE_INFO
 	.space	4
 
 	.section	__OBJC,__image_info
-	.globl	L_OBJC_IMAGE_INFO_97e6a8c6ed5db063
+	.globl	L_OBJC_IMAGE_INFO_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_97e6a8c6ed5db063:
+L_OBJC_IMAGE_INFO_07394a753317e1f7:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_97e6a8c6ed5db063
-L_OBJC_CLASS_NAME_97e6a8c6ed5db063:
+	.globl	L_OBJC_CLASS_NAME_07394a753317e1f7
+L_OBJC_CLASS_NAME_07394a753317e1f7:
 	.ascii	"NSException"
 
 	.section	__OBJC,__cls_refs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_CLASS_REFERENCES_97e6a8c6ed5db063
+	.globl	L_OBJC_CLASS_REFERENCES_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_CLASS_REFERENCES_97e6a8c6ed5db063:
-	.long	L_OBJC_CLASS_NAME_97e6a8c6ed5db063
+L_OBJC_CLASS_REFERENCES_07394a753317e1f7:
+	.long	L_OBJC_CLASS_NAME_07394a753317e1f7
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_97e6a8c6ed5db063_MODULE_INFO
-L_OBJC_CLASS_NAME_97e6a8c6ed5db063_MODULE_INFO:
+	.globl	L_OBJC_CLASS_NAME_07394a753317e1f7_MODULE_INFO
+L_OBJC_CLASS_NAME_07394a753317e1f7_MODULE_INFO:
 	.space	1
 
 	.section	__OBJC,__module_info,regular,no_dead_strip
-	.globl	L_OBJC_MODULES_97e6a8c6ed5db063
+	.globl	L_OBJC_MODULES_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_MODULES_97e6a8c6ed5db063:
+L_OBJC_MODULES_07394a753317e1f7:
 	.asciz	"\007\000\000\000\020\000\000"
-	.long	L_OBJC_CLASS_NAME_97e6a8c6ed5db063_MODULE_INFO
+	.long	L_OBJC_CLASS_NAME_07394a753317e1f7_MODULE_INFO
 	.space	4
 
 	.section	__OBJC,__image_info
-	.globl	L_OBJC_IMAGE_INFO_bb5b616899716c0d
+	.globl	L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_bb5b616899716c0d:
+L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_bb5b616899716c0d
-L_OBJC_CLASS_NAME_bb5b616899716c0d:
+	.globl	L_OBJC_CLASS_NAME_9a6f46dd5fe9ad9a
+L_OBJC_CLASS_NAME_9a6f46dd5fe9ad9a:
 	.ascii	"NSLock"
 
 	.section	__OBJC,__cls_refs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_CLASS_REFERENCES_bb5b616899716c0d
+	.globl	L_OBJC_CLASS_REFERENCES_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_CLASS_REFERENCES_bb5b616899716c0d:
-	.long	L_OBJC_CLASS_NAME_bb5b616899716c0d
+L_OBJC_CLASS_REFERENCES_9a6f46dd5fe9ad9a:
+	.long	L_OBJC_CLASS_NAME_9a6f46dd5fe9ad9a
 
 	.section	__TEXT,__cstring,cstring_literals
-	.globl	L_OBJC_CLASS_NAME_bb5b616899716c0d_MODULE_INFO
-L_OBJC_CLASS_NAME_bb5b616899716c0d_MODULE_INFO:
+	.globl	L_OBJC_CLASS_NAME_9a6f46dd5fe9ad9a_MODULE_INFO
+L_OBJC_CLASS_NAME_9a6f46dd5fe9ad9a_MODULE_INFO:
 	.space	1
 
 	.section	__OBJC,__module_info,regular,no_dead_strip
-	.globl	L_OBJC_MODULES_bb5b616899716c0d
+	.globl	L_OBJC_MODULES_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_MODULES_bb5b616899716c0d:
+L_OBJC_MODULES_9a6f46dd5fe9ad9a:
 	.asciz	"\007\000\000\000\020\000\000"
-	.long	L_OBJC_CLASS_NAME_bb5b616899716c0d_MODULE_INFO
+	.long	L_OBJC_CLASS_NAME_9a6f46dd5fe9ad9a_MODULE_INFO
 	.space	4
 
 .subsections_via_symbols
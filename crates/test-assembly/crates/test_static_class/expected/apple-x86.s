@@ -8,7 +8,7 @@ _get_class:
 	call	L0$pb
 L0$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-L0$pb]
+	mov	eax, dword ptr [eax + L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-L0$pb]
 	pop	ebp
 	ret
 
@@ -20,7 +20,7 @@ _get_same_class:
 	call	L1$pb
 L1$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07-L1$pb]
+	mov	eax, dword ptr [eax + L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079-L1$pb]
 	pop	ebp
 	ret
 
@@ -32,7 +32,7 @@ _get_different_class:
 	call	L2$pb
 L2$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b-L2$pb]
+	mov	eax, dword ptr [eax + L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d-L2$pb]
 	pop	ebp
 	ret
 
@@ -55,10 +55,10 @@ _use_fns:
 L4$pb:
 	pop	ecx
 	mov	eax, dword ptr [ebp + 8]
-	mov	edx, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-L4$pb]
-	mov	esi, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07-L4$pb]
-	mov	edi, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b-L4$pb]
-	mov	ecx, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063-L4$pb]
+	mov	edx, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-L4$pb]
+	mov	esi, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079-L4$pb]
+	mov	edi, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d-L4$pb]
+	mov	ecx, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7-L4$pb]
 	mov	dword ptr [eax], edx
 	mov	dword ptr [eax + 4], esi
 	mov	dword ptr [eax + 8], edi
@@ -77,7 +77,7 @@ _use_same_twice:
 L5$pb:
 	pop	ecx
 	mov	eax, dword ptr [ebp + 8]
-	mov	ecx, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777-L5$pb]
+	mov	ecx, dword ptr [ecx + L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d-L5$pb]
 	mov	dword ptr [eax], ecx
 	mov	dword ptr [eax + 4], ecx
 	pop	ebp
@@ -92,75 +92,75 @@ _use_in_loop:
 	ret
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_928cf03fcc497777
+	.globl	L_OBJC_IMAGE_INFO_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_928cf03fcc497777:
+L_OBJC_IMAGE_INFO_c32665b9ca7a707d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777:
+L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d:
 	.long	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2fe1990982915f07
+	.globl	L_OBJC_IMAGE_INFO_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2fe1990982915f07:
+L_OBJC_IMAGE_INFO_2cd2086bcd2e4079:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07:
+L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079:
 	.long	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_dfff3a06c0bf722b
+	.globl	L_OBJC_IMAGE_INFO_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_dfff3a06c0bf722b:
+L_OBJC_IMAGE_INFO_875901768358e77d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b:
+L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d:
 	.long	_OBJC_CLASS_$_NSString
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_ea6fbcf172f7f513
+	.globl	L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_ea6fbcf172f7f513:
+L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513:
+L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1:
 	.long	_OBJC_CLASS_$_NSData
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_97e6a8c6ed5db063
+	.globl	L_OBJC_IMAGE_INFO_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_97e6a8c6ed5db063:
+L_OBJC_IMAGE_INFO_07394a753317e1f7:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063:
+L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7:
 	.long	_OBJC_CLASS_$_NSException
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_bb5b616899716c0d
+	.globl	L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_bb5b616899716c0d:
+L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d:
+L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a:
 	.long	_OBJC_CLASS_$_NSLock
 
 .subsections_via_symbols
@@ -5,7 +5,7 @@
 _get_class:
 	push	rbp
 	mov	rbp, rsp
-	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777]
+	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d]
 	pop	rbp
 	ret
 
@@ -14,7 +14,7 @@ _get_class:
 _get_same_class:
 	push	rbp
 	mov	rbp, rsp
-	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07]
+	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079]
 	pop	rbp
 	ret
 
@@ -23,7 +23,7 @@ _get_same_class:
 _get_different_class:
 	push	rbp
 	mov	rbp, rsp
-	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b]
+	mov	rax, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d]
 	pop	rbp
 	ret
 
@@ -41,10 +41,10 @@ _use_fns:
 	push	rbp
 	mov	rbp, rsp
 	mov	rax, rdi
-	mov	rcx, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777]
-	mov	rdx, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07]
-	mov	rsi, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b]
-	mov	rdi, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063]
+	mov	rcx, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d]
+	mov	rdx, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079]
+	mov	rsi, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d]
+	mov	rdi, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7]
 	mov	qword ptr [rax], rcx
 	mov	qword ptr [rax + 8], rdx
 	mov	qword ptr [rax + 16], rsi
@@ -58,7 +58,7 @@ _use_same_twice:
 	push	rbp
 	mov	rbp, rsp
 	mov	rax, rdi
-	mov	rcx, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777]
+	mov	rcx, qword ptr [rip + L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d]
 	mov	qword ptr [rdi], rcx
 	mov	qword ptr [rdi + 8], rcx
 	pop	rbp
@@ -73,75 +73,75 @@ _use_in_loop:
 	ret
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_928cf03fcc497777
+	.globl	L_OBJC_IMAGE_INFO_c32665b9ca7a707d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_928cf03fcc497777:
+L_OBJC_IMAGE_INFO_c32665b9ca7a707d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_928cf03fcc497777:
+L_OBJC_CLASSLIST_REFERENCES_$_c32665b9ca7a707d:
 	.quad	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2fe1990982915f07
+	.globl	L_OBJC_IMAGE_INFO_2cd2086bcd2e4079
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2fe1990982915f07:
+L_OBJC_IMAGE_INFO_2cd2086bcd2e4079:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_2fe1990982915f07:
+L_OBJC_CLASSLIST_REFERENCES_$_2cd2086bcd2e4079:
 	.quad	_OBJC_CLASS_$_NSObject
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_dfff3a06c0bf722b
+	.globl	L_OBJC_IMAGE_INFO_875901768358e77d
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_dfff3a06c0bf722b:
+L_OBJC_IMAGE_INFO_875901768358e77d:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_dfff3a06c0bf722b:
+L_OBJC_CLASSLIST_REFERENCES_$_875901768358e77d:
 	.quad	_OBJC_CLASS_$_NSString
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_ea6fbcf172f7f513
+	.globl	L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_ea6fbcf172f7f513:
+L_OBJC_IMAGE_INFO_56a9f1a4e2def0e1:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_ea6fbcf172f7f513:
+L_OBJC_CLASSLIST_REFERENCES_$_56a9f1a4e2def0e1:
 	.quad	_OBJC_CLASS_$_NSData
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_97e6a8c6ed5db063
+	.globl	L_OBJC_IMAGE_INFO_07394a753317e1f7
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_97e6a8c6ed5db063:
+L_OBJC_IMAGE_INFO_07394a753317e1f7:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_97e6a8c6ed5db063:
+L_OBJC_CLASSLIST_REFERENCES_$_07394a753317e1f7:
 	.quad	_OBJC_CLASS_$_NSException
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_bb5b616899716c0d
+	.globl	L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_bb5b616899716c0d:
+L_OBJC_IMAGE_INFO_9a6f46dd5fe9ad9a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__DATA,__objc_classrefs,regular,no_dead_strip
-	.globl	L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d
+	.globl	L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a
 	.p2align	3, 0x0
-L_OBJC_CLASSLIST_REFERENCES_$_bb5b616899716c0d:
+L_OBJC_CLASSLIST_REFERENCES_$_9a6f46dd5fe9ad9a:
 	.quad	_OBJC_CLASS_$_NSLock
 
 .subsections_via_symbols
@@ -1,5 +1,5 @@
 //! Test the output of the `class!` macro.
-#![cfg(feature = "apple")]
+#![cfg(any(feature = "apple", feature = "gnustep-2-0"))]
 use objc2::class;
 use objc2::runtime::AnyClass;
 
@@ -3,9 +3,9 @@
 	.p2align	2
 _get_sel:
 Lloh0:
-	adrp	x8, L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0@PAGE
+	adrp	x8, L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a@PAGE
 Lloh1:
-	ldr	x0, [x8, L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0@PAGEOFF]
+	ldr	x0, [x8, L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a@PAGEOFF]
 	ret
 	.loh AdrpLdr	Lloh0, Lloh1
 
@@ -13,9 +13,9 @@ Lloh1:
 	.p2align	2
 _get_same_sel:
 Lloh2:
-	adrp	x8, L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83@PAGE
+	adrp	x8, L_OBJC_SELECTOR_REFERENCES_477605c346714348@PAGE
 Lloh3:
-	ldr	x0, [x8, L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83@PAGEOFF]
+	ldr	x0, [x8, L_OBJC_SELECTOR_REFERENCES_477605c346714348@PAGEOFF]
 	ret
 	.loh AdrpLdr	Lloh2, Lloh3
 
@@ -36,9 +36,9 @@ Lloh6:
 	.p2align	2
 _get_different_sel:
 Lloh7:
-	adrp	x8, L_OBJC_SELECTOR_REFERENCES_25911857653c680c@PAGE
+	adrp	x8, L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9@PAGE
 Lloh8:
-	ldr	x0, [x8, L_OBJC_SELECTOR_REFERENCES_25911857653c680c@PAGEOFF]
+	ldr	x0, [x8, L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9@PAGEOFF]
 	ret
 	.loh AdrpLdr	Lloh7, Lloh8
 
@@ -51,21 +51,21 @@ _unused_sel:
 	.p2align	2
 _use_fns:
 Lloh9:
-	adrp	x9, L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0@PAGE
+	adrp	x9, L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a@PAGE
 Lloh10:
-	ldr	x9, [x9, L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0@PAGEOFF]
+	ldr	x9, [x9, L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a@PAGEOFF]
 Lloh11:
-	adrp	x10, L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83@PAGE
+	adrp	x10, L_OBJC_SELECTOR_REFERENCES_477605c346714348@PAGE
 Lloh12:
-	ldr	x10, [x10, L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83@PAGEOFF]
+	ldr	x10, [x10, L_OBJC_SELECTOR_REFERENCES_477605c346714348@PAGEOFF]
 Lloh13:
-	adrp	x11, L_OBJC_SELECTOR_REFERENCES_25911857653c680c@PAGE
+	adrp	x11, L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9@PAGE
 Lloh14:
-	ldr	x11, [x11, L_OBJC_SELECTOR_REFERENCES_25911857653c680c@PAGEOFF]
+	ldr	x11, [x11, L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9@PAGEOFF]
 Lloh15:
-	adrp	x12, L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534@PAGE
+	adrp	x12, L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb@PAGE
 Lloh16:
-	ldr	x12, [x12, L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534@PAGEOFF]
+	ldr	x12, [x12, L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb@PAGEOFF]
 	stp	x9, x10, [x8]
 	stp	x11, x12, [x8, #16]
 	ret
@@ -78,9 +78,9 @@ Lloh16:
 	.p2align	2
 _use_same_twice:
 Lloh17:
-	adrp	x9, L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0@PAGE
+	adrp	x9, L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a@PAGE
 Lloh18:
-	ldr	x9, [x9, L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0@PAGEOFF]
+	ldr	x9, [x9, L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a@PAGEOFF]
 	stp	x9, x9, [x8]
 	ret
 	.loh AdrpLdr	Lloh17, Lloh18
@@ -91,105 +91,105 @@ _use_in_loop:
 	ret
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0
+	.globl	L_OBJC_IMAGE_INFO_24f1c6d41a82f43a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0:
+L_OBJC_IMAGE_INFO_24f1c6d41a82f43a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
-L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0:
+	.globl	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
+L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0
+	.globl	L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0:
-	.quad	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
+L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a:
+	.quad	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83
+	.globl	L_OBJC_IMAGE_INFO_477605c346714348
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83:
+L_OBJC_IMAGE_INFO_477605c346714348:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
-L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83:
+	.globl	L_OBJC_METH_VAR_NAME_477605c346714348
+L_OBJC_METH_VAR_NAME_477605c346714348:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83
+	.globl	L_OBJC_SELECTOR_REFERENCES_477605c346714348
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83:
-	.quad	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
+L_OBJC_SELECTOR_REFERENCES_477605c346714348:
+	.quad	L_OBJC_METH_VAR_NAME_477605c346714348
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_25911857653c680c
+	.globl	L_OBJC_IMAGE_INFO_2d72c382bf0440a9
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_25911857653c680c:
+L_OBJC_IMAGE_INFO_2d72c382bf0440a9:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_25911857653c680c
-L_OBJC_METH_VAR_NAME_25911857653c680c:
+	.globl	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
+L_OBJC_METH_VAR_NAME_2d72c382bf0440a9:
 	.asciz	"i:am:different:"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_25911857653c680c
+	.globl	L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_25911857653c680c:
-	.quad	L_OBJC_METH_VAR_NAME_25911857653c680c
+L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9:
+	.quad	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_baa3c09478169afc
+	.globl	L_OBJC_IMAGE_INFO_511bc5ff61ef46ae
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_baa3c09478169afc:
+L_OBJC_IMAGE_INFO_511bc5ff61ef46ae:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_baa3c09478169afc
-L_OBJC_METH_VAR_NAME_baa3c09478169afc:
+	.globl	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
+L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae:
 	.asciz	"unused"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc
+	.globl	L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc:
-	.quad	L_OBJC_METH_VAR_NAME_baa3c09478169afc
+L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae:
+	.quad	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_acb291d82e56f534
+	.globl	L_OBJC_IMAGE_INFO_3537307b0aebfaeb
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_acb291d82e56f534:
+L_OBJC_IMAGE_INFO_3537307b0aebfaeb:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_acb291d82e56f534
-L_OBJC_METH_VAR_NAME_acb291d82e56f534:
+	.globl	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
+L_OBJC_METH_VAR_NAME_3537307b0aebfaeb:
 	.asciz	"fourthSel"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534
+	.globl	L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534:
-	.quad	L_OBJC_METH_VAR_NAME_acb291d82e56f534
+L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb:
+	.quad	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_c831c01ba82dcc2e
+	.globl	L_OBJC_IMAGE_INFO_b03637c51279269f
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_c831c01ba82dcc2e:
+L_OBJC_IMAGE_INFO_b03637c51279269f:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
-L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e:
+	.globl	L_OBJC_METH_VAR_NAME_b03637c51279269f
+L_OBJC_METH_VAR_NAME_b03637c51279269f:
 	.asciz	"loopedSelector"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e
+	.globl	L_OBJC_SELECTOR_REFERENCES_b03637c51279269f
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e:
-	.quad	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
+L_OBJC_SELECTOR_REFERENCES_b03637c51279269f:
+	.quad	L_OBJC_METH_VAR_NAME_b03637c51279269f
 
 .subsections_via_symbols
@@ -4,8 +4,8 @@
 	.p2align	2
 	.code	32
 _get_sel:
-	movw	r0, :lower16:(L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-(LPC0_0+8))
-	movt	r0, :upper16:(L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-(LPC0_0+8))
+	movw	r0, :lower16:(L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-(LPC0_0+8))
+	movt	r0, :upper16:(L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-(LPC0_0+8))
 LPC0_0:
 	ldr	r0, [pc, r0]
 	bx	lr
@@ -14,8 +14,8 @@ LPC0_0:
 	.p2align	2
 	.code	32
 _get_same_sel:
-	movw	r0, :lower16:(L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83-(LPC1_0+8))
-	movt	r0, :upper16:(L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83-(LPC1_0+8))
+	movw	r0, :lower16:(L_OBJC_SELECTOR_REFERENCES_477605c346714348-(LPC1_0+8))
+	movt	r0, :upper16:(L_OBJC_SELECTOR_REFERENCES_477605c346714348-(LPC1_0+8))
 LPC1_0:
 	ldr	r0, [pc, r0]
 	bx	lr
@@ -36,8 +36,8 @@ LPC2_0:
 	.p2align	2
 	.code	32
 _get_different_sel:
-	movw	r0, :lower16:(L_OBJC_SELECTOR_REFERENCES_25911857653c680c-(LPC3_0+8))
-	movt	r0, :upper16:(L_OBJC_SELECTOR_REFERENCES_25911857653c680c-(LPC3_0+8))
+	movw	r0, :lower16:(L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9-(LPC3_0+8))
+	movt	r0, :upper16:(L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9-(LPC3_0+8))
 LPC3_0:
 	ldr	r0, [pc, r0]
 	bx	lr
@@ -52,20 +52,20 @@ _unused_sel:
 	.p2align	2
 	.code	32
 _use_fns:
-	movw	r9, :lower16:(L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534-(LPC5_0+8))
-	movt	r9, :upper16:(L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534-(LPC5_0+8))
+	movw	r9, :lower16:(L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb-(LPC5_0+8))
+	movt	r9, :upper16:(L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb-(LPC5_0+8))
 LPC5_0:
 	ldr	r9, [pc, r9]
-	movw	r2, :lower16:(L_OBJC_SELECTOR_REFERENCES_25911857653c680c-(LPC5_1+8))
-	movt	r2, :upper16:(L_OBJC_SELECTOR_REFERENCES_25911857653c680c-(LPC5_1+8))
+	movw	r2, :lower16:(L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9-(LPC5_1+8))
+	movt	r2, :upper16:(L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9-(LPC5_1+8))
 LPC5_1:
 	ldr	r2, [pc, r2]
-	movw	r3, :lower16:(L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83-(LPC5_2+8))
-	movt	r3, :upper16:(L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83-(LPC5_2+8))
+	movw	r3, :lower16:(L_OBJC_SELECTOR_REFERENCES_477605c346714348-(LPC5_2+8))
+	movt	r3, :upper16:(L_OBJC_SELECTOR_REFERENCES_477605c346714348-(LPC5_2+8))
 LPC5_2:
 	ldr	r3, [pc, r3]
-	movw	r1, :lower16:(L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-(LPC5_3+8))
-	movt	r1, :upper16:(L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-(LPC5_3+8))
+	movw	r1, :lower16:(L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-(LPC5_3+8))
+	movt	r1, :upper16:(L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-(LPC5_3+8))
 	str	r2, [r0, #8]
 LPC5_3:
 	ldr	r1, [pc, r1]
@@ -77,8 +77,8 @@ LPC5_3:
 	.p2align	2
 	.code	32
 _use_same_twice:
-	movw	r1, :lower16:(L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-(LPC6_0+8))
-	movt	r1, :upper16:(L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-(LPC6_0+8))
+	movw	r1, :lower16:(L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-(LPC6_0+8))
+	movt	r1, :upper16:(L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-(LPC6_0+8))
 LPC6_0:
 	ldr	r1, [pc, r1]
 	str	r1, [r0]
@@ -92,106 +92,106 @@ _use_in_loop:
 	bx	lr
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0
+	.globl	L_OBJC_IMAGE_INFO_24f1c6d41a82f43a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0:
+L_OBJC_IMAGE_INFO_24f1c6d41a82f43a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
-L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0:
+	.globl	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
+L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0
+	.globl	L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0:
-	.long	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
+L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a:
+	.long	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83
+	.globl	L_OBJC_IMAGE_INFO_477605c346714348
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83:
+L_OBJC_IMAGE_INFO_477605c346714348:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
-L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83:
+	.globl	L_OBJC_METH_VAR_NAME_477605c346714348
+L_OBJC_METH_VAR_NAME_477605c346714348:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83
+	.globl	L_OBJC_SELECTOR_REFERENCES_477605c346714348
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83:
-	.long	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
+L_OBJC_SELECTOR_REFERENCES_477605c346714348:
+	.long	L_OBJC_METH_VAR_NAME_477605c346714348
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_25911857653c680c
+	.globl	L_OBJC_IMAGE_INFO_2d72c382bf0440a9
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_25911857653c680c:
+L_OBJC_IMAGE_INFO_2d72c382bf0440a9:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_25911857653c680c
-L_OBJC_METH_VAR_NAME_25911857653c680c:
+	.globl	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
+L_OBJC_METH_VAR_NAME_2d72c382bf0440a9:
 	.asciz	"i:am:different:"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_25911857653c680c
+	.globl	L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_25911857653c680c:
-	.long	L_OBJC_METH_VAR_NAME_25911857653c680c
+L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9:
+	.long	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_baa3c09478169afc
+	.globl	L_OBJC_IMAGE_INFO_511bc5ff61ef46ae
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_baa3c09478169afc:
+L_OBJC_IMAGE_INFO_511bc5ff61ef46ae:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_baa3c09478169afc
-L_OBJC_METH_VAR_NAME_baa3c09478169afc:
+	.globl	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
+L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae:
 	.asciz	"unused"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc
+	.globl	L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc:
-	.long	L_OBJC_METH_VAR_NAME_baa3c09478169afc
+L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae:
+	.long	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_acb291d82e56f534
+	.globl	L_OBJC_IMAGE_INFO_3537307b0aebfaeb
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_acb291d82e56f534:
+L_OBJC_IMAGE_INFO_3537307b0aebfaeb:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_acb291d82e56f534
-L_OBJC_METH_VAR_NAME_acb291d82e56f534:
+	.globl	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
+L_OBJC_METH_VAR_NAME_3537307b0aebfaeb:
 	.asciz	"fourthSel"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534
+	.globl	L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534:
-	.long	L_OBJC_METH_VAR_NAME_acb291d82e56f534
+L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb:
+	.long	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_c831c01ba82dcc2e
+	.globl	L_OBJC_IMAGE_INFO_b03637c51279269f
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_c831c01ba82dcc2e:
+L_OBJC_IMAGE_INFO_b03637c51279269f:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
-L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e:
+	.globl	L_OBJC_METH_VAR_NAME_b03637c51279269f
+L_OBJC_METH_VAR_NAME_b03637c51279269f:
 	.asciz	"loopedSelector"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e
+	.globl	L_OBJC_SELECTOR_REFERENCES_b03637c51279269f
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e:
-	.long	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
+L_OBJC_SELECTOR_REFERENCES_b03637c51279269f:
+	.long	L_OBJC_METH_VAR_NAME_b03637c51279269f
 
 	.section	__DATA,__nl_symbol_ptr,non_lazy_symbol_pointers
 	.p2align	2, 0x0
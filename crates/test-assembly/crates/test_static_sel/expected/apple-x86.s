@@ -8,7 +8,7 @@ _get_sel:
 	call	L0$pb
 L0$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-L0$pb]
+	mov	eax, dword ptr [eax + L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-L0$pb]
 	pop	ebp
 	ret
 
@@ -20,7 +20,7 @@ _get_same_sel:
 	call	L1$pb
 L1$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83-L1$pb]
+	mov	eax, dword ptr [eax + L_OBJC_SELECTOR_REFERENCES_477605c346714348-L1$pb]
 	pop	ebp
 	ret
 
@@ -46,7 +46,7 @@ _get_different_sel:
 	call	L3$pb
 L3$pb:
 	pop	eax
-	mov	eax, dword ptr [eax + L_OBJC_SELECTOR_REFERENCES_25911857653c680c-L3$pb]
+	mov	eax, dword ptr [eax + L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9-L3$pb]
 	pop	ebp
 	ret
 
@@ -69,10 +69,10 @@ _use_fns:
 L5$pb:
 	pop	ecx
 	mov	eax, dword ptr [ebp + 8]
-	mov	edx, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-L5$pb]
-	mov	esi, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83-L5$pb]
-	mov	edi, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_25911857653c680c-L5$pb]
-	mov	ecx, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534-L5$pb]
+	mov	edx, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-L5$pb]
+	mov	esi, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_477605c346714348-L5$pb]
+	mov	edi, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9-L5$pb]
+	mov	ecx, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb-L5$pb]
 	mov	dword ptr [eax], edx
 	mov	dword ptr [eax + 4], esi
 	mov	dword ptr [eax + 8], edi
@@ -91,7 +91,7 @@ _use_same_twice:
 L6$pb:
 	pop	ecx
 	mov	eax, dword ptr [ebp + 8]
-	mov	ecx, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0-L6$pb]
+	mov	ecx, dword ptr [ecx + L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a-L6$pb]
 	mov	dword ptr [eax], ecx
 	mov	dword ptr [eax + 4], ecx
 	pop	ebp
@@ -106,106 +106,106 @@ _use_in_loop:
 	ret
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0
+	.globl	L_OBJC_IMAGE_INFO_24f1c6d41a82f43a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0:
+L_OBJC_IMAGE_INFO_24f1c6d41a82f43a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
-L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0:
+	.globl	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
+L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0
+	.globl	L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0:
-	.long	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
+L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a:
+	.long	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83
+	.globl	L_OBJC_IMAGE_INFO_477605c346714348
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83:
+L_OBJC_IMAGE_INFO_477605c346714348:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
-L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83:
+	.globl	L_OBJC_METH_VAR_NAME_477605c346714348
+L_OBJC_METH_VAR_NAME_477605c346714348:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83
+	.globl	L_OBJC_SELECTOR_REFERENCES_477605c346714348
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83:
-	.long	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
+L_OBJC_SELECTOR_REFERENCES_477605c346714348:
+	.long	L_OBJC_METH_VAR_NAME_477605c346714348
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_25911857653c680c
+	.globl	L_OBJC_IMAGE_INFO_2d72c382bf0440a9
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_25911857653c680c:
+L_OBJC_IMAGE_INFO_2d72c382bf0440a9:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_25911857653c680c
-L_OBJC_METH_VAR_NAME_25911857653c680c:
+	.globl	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
+L_OBJC_METH_VAR_NAME_2d72c382bf0440a9:
 	.asciz	"i:am:different:"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_25911857653c680c
+	.globl	L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_25911857653c680c:
-	.long	L_OBJC_METH_VAR_NAME_25911857653c680c
+L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9:
+	.long	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_baa3c09478169afc
+	.globl	L_OBJC_IMAGE_INFO_511bc5ff61ef46ae
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_baa3c09478169afc:
+L_OBJC_IMAGE_INFO_511bc5ff61ef46ae:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_baa3c09478169afc
-L_OBJC_METH_VAR_NAME_baa3c09478169afc:
+	.globl	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
+L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae:
 	.asciz	"unused"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc
+	.globl	L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc:
-	.long	L_OBJC_METH_VAR_NAME_baa3c09478169afc
+L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae:
+	.long	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_acb291d82e56f534
+	.globl	L_OBJC_IMAGE_INFO_3537307b0aebfaeb
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_acb291d82e56f534:
+L_OBJC_IMAGE_INFO_3537307b0aebfaeb:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_acb291d82e56f534
-L_OBJC_METH_VAR_NAME_acb291d82e56f534:
+	.globl	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
+L_OBJC_METH_VAR_NAME_3537307b0aebfaeb:
 	.asciz	"fourthSel"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534
+	.globl	L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534:
-	.long	L_OBJC_METH_VAR_NAME_acb291d82e56f534
+L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb:
+	.long	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_c831c01ba82dcc2e
+	.globl	L_OBJC_IMAGE_INFO_b03637c51279269f
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_c831c01ba82dcc2e:
+L_OBJC_IMAGE_INFO_b03637c51279269f:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
-L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e:
+	.globl	L_OBJC_METH_VAR_NAME_b03637c51279269f
+L_OBJC_METH_VAR_NAME_b03637c51279269f:
 	.asciz	"loopedSelector"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e
+	.globl	L_OBJC_SELECTOR_REFERENCES_b03637c51279269f
 	.p2align	2, 0x0
-L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e:
-	.long	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
+L_OBJC_SELECTOR_REFERENCES_b03637c51279269f:
+	.long	L_OBJC_METH_VAR_NAME_b03637c51279269f
 
 	.section	__IMPORT,__pointers,non_lazy_symbol_pointers
 LL_OBJC_SELECTOR_REFERENCES_alloc$non_lazy_ptr:
@@ -5,7 +5,7 @@
 _get_sel:
 	push	rbp
 	mov	rbp, rsp
-	mov	rax, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0]
+	mov	rax, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a]
 	pop	rbp
 	ret
 
@@ -14,7 +14,7 @@ _get_sel:
 _get_same_sel:
 	push	rbp
 	mov	rbp, rsp
-	mov	rax, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83]
+	mov	rax, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_477605c346714348]
 	pop	rbp
 	ret
 
@@ -34,7 +34,7 @@ _get_common_twice:
 _get_different_sel:
 	push	rbp
 	mov	rbp, rsp
-	mov	rax, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_25911857653c680c]
+	mov	rax, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9]
 	pop	rbp
 	ret
 
@@ -52,10 +52,10 @@ _use_fns:
 	push	rbp
 	mov	rbp, rsp
 	mov	rax, rdi
-	mov	rcx, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0]
-	mov	rdx, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83]
-	mov	rsi, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_25911857653c680c]
-	mov	rdi, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534]
+	mov	rcx, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a]
+	mov	rdx, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_477605c346714348]
+	mov	rsi, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9]
+	mov	rdi, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb]
 	mov	qword ptr [rax], rcx
 	mov	qword ptr [rax + 8], rdx
 	mov	qword ptr [rax + 16], rsi
@@ -69,7 +69,7 @@ _use_same_twice:
 	push	rbp
 	mov	rbp, rsp
 	mov	rax, rdi
-	mov	rcx, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0]
+	mov	rcx, qword ptr [rip + L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a]
 	mov	qword ptr [rdi], rcx
 	mov	qword ptr [rdi + 8], rcx
 	pop	rbp
@@ -84,105 +84,105 @@ _use_in_loop:
 	ret
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0
+	.globl	L_OBJC_IMAGE_INFO_24f1c6d41a82f43a
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_2ff5c2d33acc98c0:
+L_OBJC_IMAGE_INFO_24f1c6d41a82f43a:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
-L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0:
+	.globl	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
+L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0
+	.globl	L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_2ff5c2d33acc98c0:
-	.quad	L_OBJC_METH_VAR_NAME_2ff5c2d33acc98c0
+L_OBJC_SELECTOR_REFERENCES_24f1c6d41a82f43a:
+	.quad	L_OBJC_METH_VAR_NAME_24f1c6d41a82f43a
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83
+	.globl	L_OBJC_IMAGE_INFO_477605c346714348
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_6e17eb9d3fa7fa83:
+L_OBJC_IMAGE_INFO_477605c346714348:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
-L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83:
+	.globl	L_OBJC_METH_VAR_NAME_477605c346714348
+L_OBJC_METH_VAR_NAME_477605c346714348:
 	.asciz	"simple"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83
+	.globl	L_OBJC_SELECTOR_REFERENCES_477605c346714348
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_6e17eb9d3fa7fa83:
-	.quad	L_OBJC_METH_VAR_NAME_6e17eb9d3fa7fa83
+L_OBJC_SELECTOR_REFERENCES_477605c346714348:
+	.quad	L_OBJC_METH_VAR_NAME_477605c346714348
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_25911857653c680c
+	.globl	L_OBJC_IMAGE_INFO_2d72c382bf0440a9
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_25911857653c680c:
+L_OBJC_IMAGE_INFO_2d72c382bf0440a9:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_25911857653c680c
-L_OBJC_METH_VAR_NAME_25911857653c680c:
+	.globl	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
+L_OBJC_METH_VAR_NAME_2d72c382bf0440a9:
 	.asciz	"i:am:different:"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_25911857653c680c
+	.globl	L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_25911857653c680c:
-	.quad	L_OBJC_METH_VAR_NAME_25911857653c680c
+L_OBJC_SELECTOR_REFERENCES_2d72c382bf0440a9:
+	.quad	L_OBJC_METH_VAR_NAME_2d72c382bf0440a9
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_baa3c09478169afc
+	.globl	L_OBJC_IMAGE_INFO_511bc5ff61ef46ae
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_baa3c09478169afc:
+L_OBJC_IMAGE_INFO_511bc5ff61ef46ae:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_baa3c09478169afc
-L_OBJC_METH_VAR_NAME_baa3c09478169afc:
+	.globl	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
+L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae:
 	.asciz	"unused"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc
+	.globl	L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_baa3c09478169afc:
-	.quad	L_OBJC_METH_VAR_NAME_baa3c09478169afc
+L_OBJC_SELECTOR_REFERENCES_511bc5ff61ef46ae:
+	.quad	L_OBJC_METH_VAR_NAME_511bc5ff61ef46ae
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_acb291d82e56f534
+	.globl	L_OBJC_IMAGE_INFO_3537307b0aebfaeb
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_acb291d82e56f534:
+L_OBJC_IMAGE_INFO_3537307b0aebfaeb:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_acb291d82e56f534
-L_OBJC_METH_VAR_NAME_acb291d82e56f534:
+	.globl	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
+L_OBJC_METH_VAR_NAME_3537307b0aebfaeb:
 	.asciz	"fourthSel"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534
+	.globl	L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_acb291d82e56f534:
-	.quad	L_OBJC_METH_VAR_NAME_acb291d82e56f534
+L_OBJC_SELECTOR_REFERENCES_3537307b0aebfaeb:
+	.quad	L_OBJC_METH_VAR_NAME_3537307b0aebfaeb
 
 	.section	__DATA,__objc_imageinfo,regular,no_dead_strip
-	.globl	L_OBJC_IMAGE_INFO_c831c01ba82dcc2e
+	.globl	L_OBJC_IMAGE_INFO_b03637c51279269f
 	.p2align	2, 0x0
-L_OBJC_IMAGE_INFO_c831c01ba82dcc2e:
+L_OBJC_IMAGE_INFO_b03637c51279269f:
 	.asciz	"\000\000\000\000@\000\000"
 
 	.section	__TEXT,__objc_methname,cstring_literals
-	.globl	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
-L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e:
+	.globl	L_OBJC_METH_VAR_NAME_b03637c51279269f
+L_OBJC_METH_VAR_NAME_b03637c51279269f:
 	.asciz	"loopedSelector"
 
 	.section	__DATA,__objc_selrefs,literal_pointers,no_dead_strip
-	.globl	L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e
+	.globl	L_OBJC_SELECTOR_REFERENCES_b03637c51279269f
 	.p2align	3, 0x0
-L_OBJC_SELECTOR_REFERENCES_c831c01ba82dcc2e:
-	.quad	L_OBJC_METH_VAR_NAME_c831c01ba82dcc2e
+L_OBJC_SELECTOR_REFERENCES_b03637c51279269f:
+	.quad	L_OBJC_METH_VAR_NAME_b03637c51279269f
 
 .subsections_via_symbols
@@ -0,0 +1,3 @@
+	.text
+	.intel_syntax noprefix
+	.section	".note.GNU-stack","",@progbits
@@ -1,3 +1,198 @@
 	.text
 	.intel_syntax noprefix
+	.section	.text.get_sel,"ax",@progbits
+	.globl	get_sel
+	.p2align	4, 0x90
+	.type	get_sel,@function
+get_sel:
+	lea	rax, [rip + SYM(test_static_sel[CRATE_ID]::get_sel::SEL, 0)]
+	ret
+.Lfunc_end0:
+	.size	get_sel, .Lfunc_end0-get_sel
+
+	.section	.text.get_same_sel,"ax",@progbits
+	.globl	get_same_sel
+	.p2align	4, 0x90
+	.type	get_same_sel,@function
+get_same_sel:
+	lea	rax, [rip + SYM(test_static_sel[CRATE_ID]::get_same_sel::SEL, 0)]
+	ret
+.Lfunc_end1:
+	.size	get_same_sel, .Lfunc_end1-get_same_sel
+
+	.section	.text.get_common_twice,"ax",@progbits
+	.globl	get_common_twice
+	.p2align	4, 0x90
+	.type	get_common_twice,@function
+get_common_twice:
+	mov	rax, qword ptr [rip + SYM(objc2::__macro_helpers::common_selectors::alloc_sel::REF::GENERATED_ID, 0)@GOTPCREL]
+	mov	rax, qword ptr [rax]
+	mov	rdx, rax
+	ret
+.Lfunc_end2:
+	.size	get_common_twice, .Lfunc_end2-get_common_twice
+
+	.section	.text.get_different_sel,"ax",@progbits
+	.globl	get_different_sel
+	.p2align	4, 0x90
+	.type	get_different_sel,@function
+get_different_sel:
+	lea	rax, [rip + SYM(test_static_sel[CRATE_ID]::get_different_sel::SEL, 0)]
+	ret
+.Lfunc_end3:
+	.size	get_different_sel, .Lfunc_end3-get_different_sel
+
+	.section	.text.unused_sel,"ax",@progbits
+	.globl	unused_sel
+	.p2align	4, 0x90
+	.type	unused_sel,@function
+unused_sel:
+	ret
+.Lfunc_end4:
+	.size	unused_sel, .Lfunc_end4-unused_sel
+
+	.section	.text.use_fns,"ax",@progbits
+	.globl	use_fns
+	.p2align	4, 0x90
+	.type	use_fns,@function
+use_fns:
+	mov	rax, rdi
+	lea	rcx, [rip + SYM(test_static_sel[CRATE_ID]::get_sel::SEL, 0)]
+	mov	qword ptr [rdi], rcx
+	lea	rcx, [rip + SYM(test_static_sel[CRATE_ID]::get_same_sel::SEL, 0)]
+	mov	qword ptr [rdi + 8], rcx
+	lea	rcx, [rip + SYM(test_static_sel[CRATE_ID]::get_different_sel::SEL, 0)]
+	mov	qword ptr [rdi + 16], rcx
+	lea	rcx, [rip + SYM(test_static_sel[CRATE_ID]::use_fns::SEL, 0)]
+	mov	qword ptr [rdi + 24], rcx
+	ret
+.Lfunc_end5:
+	.size	use_fns, .Lfunc_end5-use_fns
+
+	.section	.text.use_same_twice,"ax",@progbits
+	.globl	use_same_twice
+	.p2align	4, 0x90
+	.type	use_same_twice,@function
+use_same_twice:
+	mov	rax, rdi
+	lea	rcx, [rip + SYM(test_static_sel[CRATE_ID]::get_sel::SEL, 0)]
+	mov	qword ptr [rdi], rcx
+	mov	qword ptr [rdi + 8], rcx
+	ret
+.Lfunc_end6:
+	.size	use_same_twice, .Lfunc_end6-use_same_twice
+
+	.section	.text.use_in_loop,"ax",@progbits
+	.globl	use_in_loop
+	.p2align	4, 0x90
+	.type	use_in_loop,@function
+use_in_loop:
+	ret
+.Lfunc_end7:
+	.size	use_in_loop, .Lfunc_end7-use_in_loop
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_sel::_OBJC_LOAD, 0),@object
+	.section	.data.rel.ro.SYM(test_static_sel[CRATE_ID]::get_sel::_OBJC_LOAD, 0),"aw",@progbits
+	.globl	SYM(test_static_sel[CRATE_ID]::get_sel::_OBJC_LOAD, 0)
+	.p2align	3, 0x0
+SYM(test_static_sel[CRATE_ID]::get_sel::_OBJC_LOAD, 0):
+	.quad	SYM(objc2::__macro_helpers::static_gnustep::__OBJC_LOAD::GENERATED_ID, 0)
+	.size	SYM(test_static_sel[CRATE_ID]::get_sel::_OBJC_LOAD, 0), 8
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_sel::NAME_DATA, 0),@object
+	.section	.rodata.SYM(test_static_sel[CRATE_ID]::get_sel::NAME_DATA, 0),"a",@progbits
+SYM(test_static_sel[CRATE_ID]::get_sel::NAME_DATA, 0):
+	.asciz	"simple"
+	.size	SYM(test_static_sel[CRATE_ID]::get_sel::NAME_DATA, 0), 7
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_sel::SEL, 0),@object
+	.section	__objc_selectors,"aw",@progbits
+	.p2align	3, 0x90
+SYM(test_static_sel[CRATE_ID]::get_sel::SEL, 0):
+	.quad	SYM(test_static_sel[CRATE_ID]::get_sel::NAME_DATA, 0)
+	.zero	8
+	.size	SYM(test_static_sel[CRATE_ID]::get_sel::SEL, 0), 16
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_same_sel::_OBJC_LOAD, 0),@object
+	.section	.data.rel.ro.SYM(test_static_sel[CRATE_ID]::get_same_sel::_OBJC_LOAD, 0),"aw",@progbits
+	.globl	SYM(test_static_sel[CRATE_ID]::get_same_sel::_OBJC_LOAD, 0)
+	.p2align	3, 0x0
+SYM(test_static_sel[CRATE_ID]::get_same_sel::_OBJC_LOAD, 0):
+	.quad	SYM(objc2::__macro_helpers::static_gnustep::__OBJC_LOAD::GENERATED_ID, 0)
+	.size	SYM(test_static_sel[CRATE_ID]::get_same_sel::_OBJC_LOAD, 0), 8
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_same_sel::NAME_DATA, 0),@object
+	.section	.rodata.SYM(test_static_sel[CRATE_ID]::get_same_sel::NAME_DATA, 0),"a",@progbits
+SYM(test_static_sel[CRATE_ID]::get_same_sel::NAME_DATA, 0):
+	.asciz	"simple"
+	.size	SYM(test_static_sel[CRATE_ID]::get_same_sel::NAME_DATA, 0), 7
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_same_sel::SEL, 0),@object
+	.section	__objc_selectors,"aw",@progbits
+	.p2align	3, 0x90
+SYM(test_static_sel[CRATE_ID]::get_same_sel::SEL, 0):
+	.quad	SYM(test_static_sel[CRATE_ID]::get_same_sel::NAME_DATA, 0)
+	.zero	8
+	.size	SYM(test_static_sel[CRATE_ID]::get_same_sel::SEL, 0), 16
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_different_sel::_OBJC_LOAD, 0),@object
+	.section	.data.rel.ro.SYM(test_static_sel[CRATE_ID]::get_different_sel::_OBJC_LOAD, 0),"aw",@progbits
+	.globl	SYM(test_static_sel[CRATE_ID]::get_different_sel::_OBJC_LOAD, 0)
+	.p2align	3, 0x0
+SYM(test_static_sel[CRATE_ID]::get_different_sel::_OBJC_LOAD, 0):
+	.quad	SYM(objc2::__macro_helpers::static_gnustep::__OBJC_LOAD::GENERATED_ID, 0)
+	.size	SYM(test_static_sel[CRATE_ID]::get_different_sel::_OBJC_LOAD, 0), 8
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_different_sel::NAME_DATA, 0),@object
+	.section	.rodata.SYM(test_static_sel[CRATE_ID]::get_different_sel::NAME_DATA, 0),"a",@progbits
+SYM(test_static_sel[CRATE_ID]::get_different_sel::NAME_DATA, 0):
+	.asciz	"i:am:different:"
+	.size	SYM(test_static_sel[CRATE_ID]::get_different_sel::NAME_DATA, 0), 16
+
+	.type	SYM(test_static_sel[CRATE_ID]::get_different_sel::SEL, 0),@object
+	.section	__objc_selectors,"aw",@progbits
+	.p2align	3, 0x90
+SYM(test_static_sel[CRATE_ID]::get_different_sel::SEL, 0):
+	.quad	SYM(test_static_sel[CRATE_ID]::get_different_sel::NAME_DATA, 0)
+	.zero	8
+	.size	SYM(test_static_sel[CRATE_ID]::get_different_sel::SEL, 0), 16
+
+	.type	SYM(test_static_sel[CRATE_ID]::unused_sel::_OBJC_LOAD, 0),@object
+	.section	.data.rel.ro.SYM(test_static_sel[CRATE_ID]::unused_sel::_OBJC_LOAD, 0),"aw",@progbits
+	.globl	SYM(test_static_sel[CRATE_ID]::unused_sel::_OBJC_LOAD, 0)
+	.p2align	3, 0x0
+SYM(test_static_sel[CRATE_ID]::unused_sel::_OBJC_LOAD, 0):
+	.quad	SYM(objc2::__macro_helpers::static_gnustep::__OBJC_LOAD::GENERATED_ID, 0)
+	.size	SYM(test_static_sel[CRATE_ID]::unused_sel::_OBJC_LOAD, 0), 8
+
+	.type	SYM(test_static_sel[CRATE_ID]::use_fns::_OBJC_LOAD, 0),@object
+	.section	.data.rel.ro.SYM(test_static_sel[CRATE_ID]::use_fns::_OBJC_LOAD, 0),"aw",@progbits
+	.globl	SYM(test_static_sel[CRATE_ID]::use_fns::_OBJC_LOAD, 0)
+	.p2align	3, 0x0
+SYM(test_static_sel[CRATE_ID]::use_fns::_OBJC_LOAD, 0):
+	.quad	SYM(objc2::__macro_helpers::static_gnustep::__OBJC_LOAD::GENERATED_ID, 0)
+	.size	SYM(test_static_sel[CRATE_ID]::use_fns::_OBJC_LOAD, 0), 8
+
+	.type	SYM(test_static_sel[CRATE_ID]::use_fns::NAME_DATA, 0),@object
+	.section	.rodata.SYM(test_static_sel[CRATE_ID]::use_fns::NAME_DATA, 0),"a",@progbits
+SYM(test_static_sel[CRATE_ID]::use_fns::NAME_DATA, 0):
+	.asciz	"fourthSel"
+	.size	SYM(test_static_sel[CRATE_ID]::use_fns::NAME_DATA, 0), 10
+
+	.type	SYM(test_static_sel[CRATE_ID]::use_fns::SEL, 0),@object
+	.section	__objc_selectors,"aw",@progbits
+	.p2align	3, 0x90
+SYM(test_static_sel[CRATE_ID]::use_fns::SEL, 0):
+	.quad	SYM(test_static_sel[CRATE_ID]::use_fns::NAME_DATA, 0)
+	.zero	8
+	.size	SYM(test_static_sel[CRATE_ID]::use_fns::SEL, 0), 16
+
+	.type	SYM(test_static_sel[CRATE_ID]::use_in_loop::_OBJC_LOAD, 0),@object
+	.section	.data.rel.ro.SYM(test_static_sel[CRATE_ID]::use_in_loop::_OBJC_LOAD, 0),"aw",@progbits
+	.globl	SYM(test_static_sel[CRATE_ID]::use_in_loop::_OBJC_LOAD, 0)
+	.p2align	3, 0x0
+SYM(test_static_sel[CRATE_ID]::use_in_loop::_OBJC_LOAD, 0):
+	.quad	SYM(objc2::__macro_helpers::static_gnustep::__OBJC_LOAD::GENERATED_ID, 0)
+	.size	SYM(test_static_sel[CRATE_ID]::use_in_loop::_OBJC_LOAD, 0), 8
+
 	.section	".note.GNU-stack","",@progbits
@@ -54,6 +54,51 @@ LargeStructBlock get_large_struct_block_with(LargeStruct a) {
 LargeStruct invoke_large_struct_block(LargeStructBlock block, LargeStruct s) {
     return block(s);
 }
+typedef struct {
+    int32_t a;
+    int32_t b;
+} SmallStruct;
+
+typedef SmallStruct (^SmallStructBlock)(SmallStruct);
+
+SmallStructBlock get_small_struct_block() {
+    return ^(SmallStruct s) {
+        s.a += 1;
+        s.b *= 2;
+        return s;
+    };
+}
+
+SmallStruct invoke_small_struct_block(SmallStructBlock block, SmallStruct s) {
+    return block(s);
+}
+
+typedef int64_t (^ManyArgsBlock)(
+    int8_t, int16_t, int32_t, int64_t,
+    uint8_t, uint16_t, uint32_t, uint64_t,
+    float, double, int32_t, int32_t,
+    int32_t, int32_t, int32_t, int32_t
+);
+
+ManyArgsBlock get_many_args_block() {
+    return ^(
+        int8_t a, int16_t b, int32_t c, int64_t d,
+        uint8_t e, uint16_t f, uint32_t g, uint64_t h,
+        float i, double j, int32_t k, int32_t l,
+        int32_t m, int32_t n, int32_t o, int32_t p
+    ) {
+        return (int64_t)(
+            a + 2 * b + 3 * c + 4 * d
+            + 5 * e + 6 * f + 7 * g + 8 * h
+            + 9 * i + 10 * j + 11 * k + 12 * l
+            + 13 * m + 14 * n + 15 * o + 16 * p
+        );
+    };
+}
+
+int64_t invoke_many_args_block(ManyArgsBlock block) {
+    return block(1, 2, 3, 4, 5, 6, 7, 8, 9.0, 10.0, 11, 12, 13, 14, 15, 16);
+}
 
 
 typedef int32_t (^ABlock)(void);
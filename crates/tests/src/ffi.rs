@@ -45,6 +45,39 @@ pub struct LargeStructBlock {
     _priv: [u8; 0],
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SmallStruct {
+    pub a: i32,
+    pub b: i32,
+}
+
+impl SmallStruct {
+    pub fn mutate(&mut self) {
+        self.a += 1;
+        self.b *= 2;
+    }
+}
+
+unsafe impl Encode for SmallStruct {
+    const ENCODING: Encoding = Encoding::Struct("SmallStruct", &[i32::ENCODING, i32::ENCODING]);
+}
+
+#[repr(C)]
+pub struct SmallStructBlock {
+    _priv: [u8; 0],
+}
+
+/// A block that takes 16 arguments of differing types:
+/// `int64_t (^)(int8_t, int16_t, int32_t, int64_t, uint8_t, uint16_t,
+/// uint32_t, uint64_t, float, double, int32_t, int32_t, int32_t, int32_t,
+/// int32_t, int32_t)`, and returns the sum of each argument multiplied by
+/// its position.
+#[repr(C)]
+pub struct ManyArgsBlock {
+    _priv: [u8; 0],
+}
+
 extern "C" {
     /// Returns a pointer to a global `IntBlock` that returns 7.
     pub fn get_int_block() -> *mut IntBlock;
@@ -63,6 +96,14 @@ extern "C" {
     pub fn get_large_struct_block_with(i: LargeStruct) -> *mut LargeStructBlock;
     pub fn invoke_large_struct_block(block: *mut LargeStructBlock, s: LargeStruct) -> LargeStruct;
 
+    pub fn get_small_struct_block() -> *mut SmallStructBlock;
+    pub fn invoke_small_struct_block(block: *mut SmallStructBlock, s: SmallStruct) -> SmallStruct;
+
+    /// Returns a pointer to a global `ManyArgsBlock`.
+    pub fn get_many_args_block() -> *mut ManyArgsBlock;
+    /// Invokes a `ManyArgsBlock` with the arguments `1` to `16`.
+    pub fn invoke_many_args_block(block: *mut ManyArgsBlock) -> i64;
+
     pub fn try_block_debugging(x: i32);
 }
 
@@ -115,4 +156,25 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn test_small_struct_block() {
+        let data = SmallStruct { a: 3, b: 4 };
+        let mut expected = data;
+        expected.mutate();
+
+        assert_eq!(
+            unsafe { invoke_small_struct_block(get_small_struct_block(), data) },
+            expected
+        );
+    }
+
+    #[test]
+    fn test_many_args_block() {
+        let expected = (1..=16).map(|i| i * i).sum::<i64>();
+        assert_eq!(
+            unsafe { invoke_many_args_block(get_many_args_block()) },
+            expected
+        );
+    }
 }
@@ -15,7 +15,7 @@ mod test_encode_utils;
 #[cfg(test)]
 mod test_object;
 
-use crate::ffi::LargeStruct;
+use crate::ffi::{LargeStruct, SmallStruct};
 
 pub fn get_int_block_with(i: i32) -> RcBlock<(), i32> {
     unsafe {
@@ -49,11 +49,50 @@ pub fn invoke_large_struct_block(
     unsafe { ffi::invoke_large_struct_block(ptr as *mut _, x) }
 }
 
+pub fn invoke_small_struct_block(
+    block: &Block<(SmallStruct,), SmallStruct>,
+    x: SmallStruct,
+) -> SmallStruct {
+    let ptr = block as *const _;
+    unsafe { ffi::invoke_small_struct_block(ptr as *mut _, x) }
+}
+
+type ManyArgs = (
+    i8,
+    i16,
+    i32,
+    i64,
+    u8,
+    u16,
+    u32,
+    u64,
+    f32,
+    f64,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+);
+
+pub fn invoke_many_args_block(block: &Block<ManyArgs, i64>) -> i64 {
+    let ptr = block as *const _;
+    unsafe { ffi::invoke_many_args_block(ptr as *mut _) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use alloc::string::ToString;
-    use block2::{global_block, ConcreteBlock, RcBlock};
+    use block2::{global_block, ByRef, ConcreteBlock, RcBlock};
+    use core::cell::Cell;
+    use core::ptr;
+    use objc2::encode::EncodingBox;
+    use objc2::msg_send_id;
+    use objc2::rc::Id;
+    use objc2::runtime::AnyObject;
+    use objc2::Encoding;
 
     global_block! {
         /// Test `global_block` in an external crate
@@ -145,4 +184,246 @@ mod tests {
         let block = block.copy();
         assert_eq!(invoke_large_struct_block(&block, data), new_data);
     }
+
+    #[test]
+    fn test_byref_shared() {
+        let x = ByRef::new(5);
+        let add = ConcreteBlock::new({
+            let x = x.clone();
+            move |a: i32| {
+                x.set(x.get() + a);
+                x.get()
+            }
+        });
+        let get = ConcreteBlock::new({
+            let x = x.clone();
+            move || x.get()
+        });
+
+        assert_eq!(invoke_add_block(&add, 2), 7);
+        assert_eq!(invoke_int_block(&get), 7);
+
+        let add = add.copy();
+        let get = get.copy();
+        assert_eq!(invoke_add_block(&add, 3), 10);
+        assert_eq!(invoke_int_block(&get), 10);
+        assert_eq!(x.get(), 10);
+
+        x.set(42);
+        assert_eq!(invoke_int_block(&get), 42);
+        assert_eq!(x.as_ptr(), x.clone().as_ptr());
+    }
+
+    #[test]
+    fn test_byref_replace_take_swap() {
+        let a = ByRef::new("a".to_string());
+        let b = ByRef::new("b".to_string());
+        assert_eq!(a.replace("c".to_string()), "a");
+        a.swap(&b);
+        assert_eq!(a.take(), "b");
+        assert_eq!(b.take(), "c");
+        assert_eq!(a.take(), "");
+    }
+
+    #[test]
+    fn test_byref_drop() {
+        struct DropCounter<'a>(&'a Cell<usize>);
+
+        impl Drop for DropCounter<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let dropped = Cell::new(0);
+        let x = ByRef::new(DropCounter(&dropped));
+        let block = ConcreteBlock::new({
+            let x = x.clone();
+            move || {
+                let _x = &x;
+                13
+            }
+        });
+        assert_eq!(dropped.get(), 0);
+
+        x.set(DropCounter(&dropped));
+        assert_eq!(dropped.get(), 1);
+
+        drop(x);
+        assert_eq!(invoke_int_block(&block), 13);
+        assert_eq!(dropped.get(), 1);
+
+        drop(block);
+        assert_eq!(dropped.get(), 2);
+    }
+
+    #[test]
+    fn test_rc_block_id_roundtrip() {
+        let block = ConcreteBlock::new(|a: i32| a + 3).copy();
+        let obj: Id<Block<(i32,), i32>> = RcBlock::into_id(block);
+        assert_eq!(invoke_add_block(&obj, 4), 7);
+
+        let block: RcBlock<(i32,), i32> = RcBlock::from_id(obj);
+        assert_eq!(invoke_add_block(&block, 5), 8);
+
+        let obj: Id<Block<(i32,), i32>> = block.into();
+        let retained = unsafe { Id::retain(Id::as_ptr(&obj) as *mut Block<(i32,), i32>) }.unwrap();
+        drop(obj);
+        let block: RcBlock<(i32,), i32> = retained.into();
+        assert_eq!(invoke_add_block(&block, 6), 9);
+    }
+
+    #[test]
+    fn test_rc_block_retain_object() {
+        let s = "Hello!".to_string();
+        let expected_len = s.len() as i32;
+        let block = ConcreteBlock::new(move || s.len() as i32).copy();
+
+        let retained: RcBlock<(), i32> =
+            unsafe { RcBlock::retain_object(RcBlock::as_object(&block)) };
+        drop(block);
+        assert_eq!(invoke_int_block(&retained), expected_len);
+
+        let obj: &AnyObject = retained.as_ref();
+        let retained: RcBlock<(), i32> = unsafe { RcBlock::retain_object(obj) };
+        assert_eq!(invoke_int_block(&retained), expected_len);
+    }
+
+    #[test]
+    fn test_block_message() {
+        let obj = RcBlock::into_id(ConcreteBlock::new(|a: i32| a + 3).copy());
+
+        // Copying a heap block retains it
+        let copied: Id<Block<(i32,), i32>> = unsafe { msg_send_id![&obj, copy] };
+        assert!(ptr::eq(&*copied, &*obj));
+        drop(obj);
+        assert_eq!(invoke_add_block(&copied, 1), 4);
+
+        let obj: Id<AnyObject> = unsafe { Id::cast(copied) };
+        let block: RcBlock<(i32,), i32> = unsafe { RcBlock::retain_object(&obj) };
+        assert_eq!(invoke_add_block(&block, 2), 5);
+    }
+
+    #[test]
+    // Clang never sets `BLOCK_USE_STRET` on AArch64.
+    #[cfg(not(target_arch = "aarch64"))]
+    fn test_stret_flags() {
+        fn flags<A, R>(block: &Block<A, R>) -> block2::ffi::block_flags {
+            let layout: *const block2::ffi::Block_layout = (block as *const Block<A, R>).cast();
+            unsafe { (*layout).flags }
+        }
+
+        let block: &Block<(LargeStruct,), LargeStruct> =
+            unsafe { &*(ffi::get_large_struct_block() as *const _) };
+        assert_ne!(flags(block) & block2::ffi::BLOCK_HAS_SIGNATURE, 0);
+        assert_ne!(flags(block) & block2::ffi::BLOCK_USE_STRET, 0);
+
+        let block: &Block<(i32,), i32> = unsafe { &*(ffi::get_add_block() as *const _) };
+        assert_ne!(flags(block) & block2::ffi::BLOCK_HAS_SIGNATURE, 0);
+        assert_eq!(flags(block) & block2::ffi::BLOCK_USE_STRET, 0);
+    }
+
+    #[test]
+    fn test_call_struct_return_blocks() {
+        // `Block::call` verifies the `BLOCK_USE_STRET` flag that the C
+        // compiler set against the return type, on the current architecture.
+        let data = LargeStruct::get();
+        let mut expected = data;
+        expected.mutate();
+        let block: &Block<(LargeStruct,), LargeStruct> =
+            unsafe { &*(ffi::get_large_struct_block() as *const _) };
+        assert_eq!(unsafe { block.call((data,)) }, expected);
+
+        let data = SmallStruct { a: 3, b: 4 };
+        let mut expected = data;
+        expected.mutate();
+        let block: &Block<(SmallStruct,), SmallStruct> =
+            unsafe { &*(ffi::get_small_struct_block() as *const _) };
+        assert_eq!(unsafe { block.call((data,)) }, expected);
+
+        let block = ConcreteBlock::new(|mut x: SmallStruct| {
+            x.mutate();
+            x
+        });
+        assert_eq!(invoke_small_struct_block(&block, data), expected);
+        let block = block.copy();
+        assert_eq!(invoke_small_struct_block(&block, data), expected);
+    }
+
+    #[test]
+    fn test_many_args() {
+        let expected = (1..=16).map(|i| i * i).sum::<i64>();
+
+        let block: &Block<ManyArgs, i64> = unsafe { &*(ffi::get_many_args_block() as *const _) };
+        let res =
+            unsafe { block.call((1, 2, 3, 4, 5, 6, 7, 8, 9.0, 10.0, 11, 12, 13, 14, 15, 16)) };
+        assert_eq!(res, expected);
+
+        let block = ConcreteBlock::new(
+            |a: i8,
+             b: i16,
+             c: i32,
+             d: i64,
+             e: u8,
+             f: u16,
+             g: u32,
+             h: u64,
+             i: f32,
+             j: f64,
+             k: i32,
+             l: i32,
+             m: i32,
+             n: i32,
+             o: i32,
+             p: i32| {
+                a as i64
+                    + 2 * b as i64
+                    + 3 * c as i64
+                    + 4 * d
+                    + 5 * e as i64
+                    + 6 * f as i64
+                    + 7 * g as i64
+                    + 8 * h as i64
+                    + 9 * i as i64
+                    + 10 * j as i64
+                    + 11 * k as i64
+                    + 12 * l as i64
+                    + 13 * m as i64
+                    + 14 * n as i64
+                    + 15 * o as i64
+                    + 16 * p as i64
+            },
+        );
+        assert_eq!(invoke_many_args_block(&block), expected);
+        let block = block.copy();
+        assert_eq!(invoke_many_args_block(&block), expected);
+    }
+
+    #[test]
+    fn test_block_info_signature() {
+        let block: &Block<(i32,), i32> = unsafe { &*(ffi::get_add_block() as *const _) };
+        let info = block.info();
+        assert!(info.is_global());
+        assert!(info.has_signature());
+        assert!(!info.use_stret());
+        assert_eq!(info.class_name(), Some("_NSConcreteGlobalBlock"));
+        let signature = info.signature().unwrap().unwrap();
+        assert_eq!(signature.return_type, Encoding::Int);
+        assert_eq!(signature.arguments.len(), 1);
+        assert_eq!(signature.arguments[0], Encoding::Int);
+
+        let block = get_add_block_with(3);
+        let info = block.info();
+        assert!(!info.is_global());
+        assert!(info.has_signature());
+        assert!(info.size() > 0);
+
+        let block: &Block<(LargeStruct,), LargeStruct> =
+            unsafe { &*(ffi::get_large_struct_block() as *const _) };
+        let info = block.info();
+        assert!(info.use_stret());
+        let signature = info.signature().unwrap().unwrap();
+        assert_eq!(signature.return_type, signature.arguments[0]);
+        assert!(matches!(signature.return_type, EncodingBox::Struct(..)));
+    }
 }